@@ -49,6 +49,7 @@ function loadCookieData() {
     let english_on_top = Util.toBoolean(Cookies.get("show_english_on_top"));
     let example_sentences = Util.toBoolean(Cookies.get("show_sentences"), true);
     let sentence_furigana = Util.toBoolean(Cookies.get("sentence_furigana"), true);
+    let show_romaji = Util.toBoolean(Cookies.get("show_romaji"));
     let focus_searchbar = Util.toBoolean(Cookies.get("focus_searchbar"));
     let select_searchbar_content = Util.toBoolean(Cookies.get("select_searchbar_content"));
     let items_per_page = Cookies.get("items_per_page");
@@ -69,7 +70,7 @@ function loadCookieData() {
 
     // Execute 
     setLanguageSettings(search_lang, page_lang);
-    setSearchSettings(english_always, english_on_top, example_sentences, sentence_furigana, focus_searchbar, select_searchbar_content, items_per_page, kanji_per_page);
+    setSearchSettings(english_always, english_on_top, example_sentences, sentence_furigana, show_romaji, focus_searchbar, select_searchbar_content, items_per_page, kanji_per_page);
     setDisplaySettings(theme, kanji_speed);
     setOtherSettings(dbl_click_copy, cookies_allowed);
 
@@ -103,12 +104,13 @@ async function setLanguageSettings(search_lang, page_lang) {
 }
 
 // Prepare the search tab
-async function setSearchSettings(english_always, english_on_top, example_sentences, sentence_furigana, focus_searchbar, select_searchbar_content, items_per_page, kanji_per_page) {
+async function setSearchSettings(english_always, english_on_top, example_sentences, sentence_furigana, show_romaji, focus_searchbar, select_searchbar_content, items_per_page, kanji_per_page) {
     // Set checkboxes
     Util.setMdlCheckboxState("show_eng_settings", english_always);
     Util.setMdlCheckboxState("show_eng_on_top_settings", english_on_top);
     Util.setMdlCheckboxState("show_example_sentences_settings", example_sentences);
     Util.setMdlCheckboxState("show_sentence_furigana_settings", sentence_furigana);
+    Util.setMdlCheckboxState("show_romaji_settings", show_romaji);
     Util.setMdlCheckboxState("focus_search_bar_settings", focus_searchbar);
     Util.setMdlCheckboxState("select_searchbar_content_settings", select_searchbar_content);
 
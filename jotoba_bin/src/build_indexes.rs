@@ -0,0 +1,24 @@
+use std::time::Instant;
+
+use config::Config;
+
+use crate::{cli::BuildIndexesOptions, webserver};
+
+/// Builds all search indexes out of the configured resources
+pub(super) fn run(options: BuildIndexesOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::new(None)?;
+
+    let output = options
+        .output
+        .unwrap_or_else(|| config.get_indexes_source().to_owned());
+
+    let start = Instant::now();
+    let storage = webserver::load_resources(&config);
+    println!("Loaded resources in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    search::engine::build_indexes(&storage, &output)?;
+    println!("Wrote indexes to {} in {:?}", output, start.elapsed());
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    time::Instant,
+};
+
+use config::Config;
+use resources::builder::{self, BuildSources};
+
+use crate::cli::BuildResourcesOptions;
+
+/// Builds the storage_data file out of the raw dictionary sources passed in `options`
+pub(super) fn run(options: BuildResourcesOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::new(None)?;
+
+    let output = match options.output {
+        Some(ref output) => output.clone(),
+        None => config.get_storage_data_path(),
+    };
+
+    let sentences_output = match options.sentences_output {
+        Some(ref output) => output.clone(),
+        None => config.get_sentences_path(),
+    };
+
+    let stroke_paths_output = match options.stroke_paths_output {
+        Some(ref output) => output.clone(),
+        None => config.get_stroke_paths_path(),
+    };
+
+    let stroke_data_output = match options.stroke_data_output {
+        Some(ref output) => output.clone(),
+        None => config.get_stroke_data_path(),
+    };
+
+    let sources = BuildSources {
+        jmdict: PathBuf::from(options.jmdict),
+        jmnedict: PathBuf::from(options.jmnedict),
+        kanjidict: PathBuf::from(options.kanjidict),
+        accents: options.accents.map(PathBuf::from),
+        radicals: options.radicals.map(PathBuf::from),
+        kanji_elements: options.kanji_elements.map(PathBuf::from),
+        word_jlpt: options.word_jlpt.map(PathBuf::from),
+        kanji_genki: options.kanji_genki.map(PathBuf::from),
+        tatoeba_sentences: options.tatoeba_sentences.map(PathBuf::from),
+        tatoeba_links: options.tatoeba_links.map(PathBuf::from),
+        kanjivg: options.kanjivg.map(PathBuf::from),
+    };
+
+    let start = Instant::now();
+    let mut resources = builder::build(&sources)?;
+    println!(
+        "Parsed {} words, {} kanji, {} names and {} radicals in {:?}",
+        resources.words.len(),
+        resources.kanji.len(),
+        resources.names.len(),
+        resources.radicals.len(),
+        start.elapsed()
+    );
+
+    // Sentences have to be assigned before writing the resources since they update the words
+    let start = Instant::now();
+    if let Some(sentences) = builder::build_sentences(&sources, &mut resources)? {
+        println!(
+            "Parsed {} sentences in {:?}",
+            sentences.sentences.len(),
+            start.elapsed()
+        );
+
+        let mut out = BufWriter::new(File::create(&sentences_output)?);
+        sentences.build(&mut out)?;
+        println!("Wrote sentences to {}", sentences_output);
+    }
+
+    let start = Instant::now();
+    if let Some(stroke_paths) = builder::build_stroke_paths(&sources)? {
+        println!(
+            "Parsed stroke paths of {} kanji in {:?}",
+            stroke_paths.len(),
+            start.elapsed()
+        );
+
+        let mut out = BufWriter::new(File::create(&stroke_paths_output)?);
+        stroke_paths.build(&mut out)?;
+        println!("Wrote stroke paths to {}", stroke_paths_output);
+
+        let start = Instant::now();
+        let stroke_data = builder::build_stroke_data(&stroke_paths);
+        println!(
+            "Derived stroke data of {} kanji in {:?}",
+            stroke_data.len(),
+            start.elapsed()
+        );
+
+        let mut out = BufWriter::new(File::create(&stroke_data_output)?);
+        stroke_data.write(&mut out)?;
+        println!("Wrote stroke data to {}", stroke_data_output);
+    }
+
+    // A running server might have the old file mapped into memory so it must not be overwritten
+    let tmp_output = format!("{}.tmp", output);
+    let mut out = BufWriter::new(File::create(&tmp_output)?);
+    if options.no_mmap {
+        resources.build(&mut out)?;
+    } else {
+        resources.build_mapped(&mut out)?;
+    }
+    out.flush()?;
+    drop(out);
+    std::fs::rename(&tmp_output, &output)?;
+    println!("Wrote resources to {}", output);
+
+    Ok(())
+}
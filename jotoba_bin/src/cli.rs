@@ -1,10 +1,49 @@
-use argparse::{ArgumentParser, Print, StoreTrue};
+use std::io::{stderr, stdout};
+
+use argparse::{ArgumentParser, List, Print, Store, StoreOption, StoreTrue};
 
 /// Command line arguments
 #[derive(Default)]
 pub struct Options {
     /// Start the server
     pub start: bool,
+    /// Subcommand to run
+    pub command: String,
+    /// Arguments of the subcommand
+    pub args: Vec<String>,
+}
+
+/// Arguments of the `build-resources` subcommand
+#[derive(Default)]
+pub struct BuildResourcesOptions {
+    pub jmdict: String,
+    pub jmnedict: String,
+    pub kanjidict: String,
+    pub accents: Option<String>,
+    pub radicals: Option<String>,
+    pub kanji_elements: Option<String>,
+    pub word_jlpt: Option<String>,
+    pub kanji_genki: Option<String>,
+    pub tatoeba_sentences: Option<String>,
+    pub tatoeba_links: Option<String>,
+    pub kanjivg: Option<String>,
+    /// Output file. Defaults to the configured storage_data path
+    pub output: Option<String>,
+    /// Output file for sentences. Defaults to the configured sentences path
+    pub sentences_output: Option<String>,
+    /// Output file for kanji stroke paths. Defaults to the configured stroke_paths path
+    pub stroke_paths_output: Option<String>,
+    /// Output file for handwriting stroke data. Defaults to the configured stroke_data path
+    pub stroke_data_output: Option<String>,
+    /// Write the plain format which gets fully loaded into memory instead of the memory mapped one
+    pub no_mmap: bool,
+}
+
+/// Arguments of the `build-indexes` subcommand
+#[derive(Default)]
+pub struct BuildIndexesOptions {
+    /// Output folder. Defaults to the configured indexes source
+    pub output: Option<String>,
 }
 
 // Parse CLI args
@@ -23,8 +62,144 @@ pub fn parse() -> Options {
         ap.refer(&mut options.start)
             .add_option(&["--start", "-s"], StoreTrue, "Start the server");
 
+        ap.refer(&mut options.command).add_argument(
+            "command",
+            Store,
+            "Command to run. Available: build-resources, build-indexes",
+        );
+
+        ap.refer(&mut options.args)
+            .add_argument("arguments", List, "Arguments for command");
+
+        ap.stop_on_first_argument(true);
         ap.parse_args_or_exit();
     }
 
     options
 }
+
+/// Parse the arguments of the `build-resources` subcommand
+pub fn parse_build_resources(args: Vec<String>) -> BuildResourcesOptions {
+    let mut options = BuildResourcesOptions::default();
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Build the storage_data file from raw dictionary sources");
+
+        ap.refer(&mut options.jmdict)
+            .add_option(&["--jmdict"], Store, "JMdict xml file")
+            .required();
+
+        ap.refer(&mut options.jmnedict)
+            .add_option(&["--jmnedict"], Store, "JMnedict xml file")
+            .required();
+
+        ap.refer(&mut options.kanjidict)
+            .add_option(&["--kanjidict"], Store, "KANJIDIC2 xml file")
+            .required();
+
+        ap.refer(&mut options.accents)
+            .add_option(&["--accents"], StoreOption, "Pitch accent file");
+
+        ap.refer(&mut options.radicals)
+            .add_option(&["--radicals"], StoreOption, "Radical info file");
+
+        ap.refer(&mut options.kanji_elements).add_option(
+            &["--kanji-elements"],
+            StoreOption,
+            "Kanji elements file",
+        );
+
+        ap.refer(&mut options.word_jlpt).add_option(
+            &["--word-jlpt"],
+            StoreOption,
+            "JLPT levels of words (sequence<TAB>level)",
+        );
+
+        ap.refer(&mut options.kanji_genki).add_option(
+            &["--kanji-genki"],
+            StoreOption,
+            "Genki lessons of kanji (literal<TAB>lesson)",
+        );
+
+        ap.refer(&mut options.tatoeba_sentences).add_option(
+            &["--tatoeba-sentences"],
+            StoreOption,
+            "Tatoeba sentences export (id<TAB>lang<TAB>text)",
+        );
+
+        ap.refer(&mut options.tatoeba_links).add_option(
+            &["--tatoeba-links"],
+            StoreOption,
+            "Tatoeba links export (id<TAB>translation_id)",
+        );
+
+        ap.refer(&mut options.kanjivg).add_option(
+            &["--kanjivg"],
+            StoreOption,
+            "KanjiVG xml file or folder containing the KanjiVG SVG files",
+        );
+
+        ap.refer(&mut options.output).add_option(
+            &["--output", "-o"],
+            StoreOption,
+            "Output file. Defaults to the configured storage_data path",
+        );
+
+        ap.refer(&mut options.sentences_output).add_option(
+            &["--sentences-output"],
+            StoreOption,
+            "Output file for sentences. Defaults to the configured sentences path",
+        );
+
+        ap.refer(&mut options.stroke_paths_output).add_option(
+            &["--stroke-paths-output"],
+            StoreOption,
+            "Output file for kanji stroke paths. Defaults to the configured stroke_paths path",
+        );
+
+        ap.refer(&mut options.stroke_data_output).add_option(
+            &["--stroke-data-output"],
+            StoreOption,
+            "Output file for handwriting stroke data. Defaults to the configured stroke_data path",
+        );
+
+        ap.refer(&mut options.no_mmap).add_option(
+            &["--no-mmap"],
+            StoreTrue,
+            "Write the plain format which gets fully loaded into memory",
+        );
+
+        let mut args = args;
+        args.insert(0, String::from("build-resources"));
+
+        if let Err(code) = ap.parse(args, &mut stdout(), &mut stderr()) {
+            std::process::exit(code);
+        }
+    }
+
+    options
+}
+
+/// Parse the arguments of the `build-indexes` subcommand
+pub fn parse_build_indexes(args: Vec<String>) -> BuildIndexesOptions {
+    let mut options = BuildIndexesOptions::default();
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Build all search indexes from the configured resources");
+
+        ap.refer(&mut options.output).add_option(
+            &["--output", "-o"],
+            StoreOption,
+            "Output folder. Defaults to the configured indexes source",
+        );
+
+        let mut args = args;
+        args.insert(0, String::from("build-indexes"));
+
+        if let Err(code) = ap.parse(args, &mut stdout(), &mut stderr()) {
+            std::process::exit(code);
+        }
+    }
+
+    options
+}
@@ -1,5 +1,7 @@
 #![allow(irrefutable_let_patterns)]
 
+mod build_indexes;
+mod build_resources;
 mod cli;
 mod webserver;
 
@@ -13,6 +15,30 @@ pub async fn main() {
         return;
     }
 
+    match options.command.as_str() {
+        "build-resources" => {
+            let build_options = cli::parse_build_resources(options.args);
+            if let Err(err) = build_resources::run(build_options) {
+                eprintln!("Failed to build resources: {}", err);
+                std::process::exit(1);
+            }
+            return;
+        }
+        "build-indexes" => {
+            let build_options = cli::parse_build_indexes(options.args);
+            if let Err(err) = build_indexes::run(build_options) {
+                eprintln!("Failed to build indexes: {}", err);
+                std::process::exit(1);
+            }
+            return;
+        }
+        "" => (),
+        command => {
+            eprintln!("Unknown command: {}", command);
+            std::process::exit(1);
+        }
+    }
+
     // User didn't read the docs
     println!("Nothing to do. Use `-s` to start the dictionary");
 }
@@ -8,7 +8,9 @@ use actix_web::{
     App, HttpRequest, HttpServer,
 };
 use config::Config;
-use log::{debug, warn};
+use log::{debug, info, warn};
+use resources::models::storage::ResourceStorage;
+use search::generation::Generation;
 use std::{path::Path, sync::Arc, time::Instant};
 
 /// How long frontend assets are going to be cached by the clients. Currently 1 week
@@ -33,6 +35,9 @@ pub(super) async fn start() -> std::io::Result<()> {
 
     debug!("Resource loading took {:?}", start.elapsed());
 
+    #[cfg(unix)]
+    actix_web::rt::spawn(reload_on_sighup(config.clone()));
+
     HttpServer::new(move || {
         let app = App::new()
             // Data
@@ -119,6 +124,36 @@ pub(super) async fn start() -> std::io::Result<()> {
                         "/kanji/by_radical",
                         actixweb::post().to(api::radical::kanji_by_radicals),
                     )
+                    .route(
+                        "/kanji/by_component",
+                        actixweb::post().to(api::kanji::kanji_by_component),
+                    )
+                    .route(
+                        "/kanji/{literal}/decomposition",
+                        actixweb::get().to(api::kanji::kanji_decomposition),
+                    )
+                    .route(
+                        "/kanji/{literal}/strokes",
+                        actixweb::get().to(api::kanji::kanji_strokes),
+                    )
+                    .route(
+                        "/kanji/{literal}/strokes.svg",
+                        actixweb::get().to(api::kanji::kanji_strokes_svg),
+                    )
+                    .route(
+                        "/kanji/handwriting",
+                        actixweb::post().to(api::kanji::kanji_by_handwriting),
+                    )
+                    .route("/word/{seq}", actixweb::get().to(api::direct::word_by_seq))
+                    .route("/name/{seq}", actixweb::get().to(api::direct::name_by_seq))
+                    .route(
+                        "/sentence/{id}",
+                        actixweb::get().to(api::direct::sentence_by_id),
+                    )
+                    .route(
+                        "/kanji/{literal}",
+                        actixweb::get().to(api::direct::kanji_by_literal),
+                    )
                     .route(
                         "/radical/search",
                         actixweb::post().to(api::radical::search::search_radical),
@@ -127,12 +162,16 @@ pub(super) async fn start() -> std::io::Result<()> {
                         "/suggestion",
                         actixweb::post().to(api::completions::suggestion_ep),
                     )
+                    .route("/analyze", actixweb::post().to(api::analyze::analyze_ep))
+                    .route("/pitch", actixweb::get().to(api::pitch::pitch_by_kana))
+                    .route("/pitch/{seq}", actixweb::get().to(api::pitch::pitch_by_seq))
                     .route("/img_scan", actixweb::post().to(api::img::scan_ep))
                     .route("/news/short", actixweb::post().to(api::news::short::news))
                     .route(
                         "/news/detailed",
                         actixweb::post().to(api::news::detailed::news),
-                    ),
+                    )
+                    .route("/admin/reload", actixweb::post().to(api::admin::reload_ep)),
             )
             // Static files
             .service(
@@ -197,7 +236,7 @@ fn prepare_data(ccf: &Config) {
     rayon::scope(move |s| {
         let cf = ccf.clone();
         s.spawn(move |_| {
-            load_resources(&cf);
+            load_generation(&cf);
         });
 
         let cf = ccf.clone();
@@ -206,11 +245,7 @@ fn prepare_data(ccf: &Config) {
         });
 
         let cf = ccf.clone();
-        s.spawn(move |_| {
-            load_indexes(&cf);
-        });
-
-        s.spawn(|_| load_tokenizer());
+        s.spawn(move |_| load_tokenizer(&cf));
 
         let cf = ccf.clone();
         s.spawn(move |_| clean_img_scan_dir(&cf));
@@ -224,17 +259,44 @@ fn prepare_data(ccf: &Config) {
     });
 }
 
+/// Reloads all resources and indexes each time the process receives a SIGHUP
+#[cfg(unix)]
+async fn reload_on_sighup(config: Config) {
+    use actix_web::rt::signal::unix::{signal, SignalKind};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(err) => {
+            warn!("Failed to listen for SIGHUP: {}", err);
+            return;
+        }
+    };
+
+    while hangup.recv().await.is_some() {
+        if !api::admin::reload(config.clone()) {
+            warn!("Ignoring SIGHUP: Reload already running");
+        }
+    }
+}
+
 fn setup_logger() {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("debug"));
 }
 
-pub fn load_tokenizer() {
+pub fn load_tokenizer(config: &Config) {
     use sentence_reader::{JA_NL_PARSER, NL_PARSER_PATH};
 
     if !Path::new(NL_PARSER_PATH).exists() {
         panic!("No NL dict was found! Place the following folder in he binaries root dir: ./unidic-mecab");
     }
 
+    if let Some(grammar_rules) = config.server.get_grammar_rules() {
+        if let Err(err) = sentence_reader::load_grammar(grammar_rules) {
+            panic!("Failed to load grammar rules from {}: {}", grammar_rules, err);
+        }
+        info!("Loaded grammar rules from {}", grammar_rules);
+    }
+
     // Force parser to parse something to
     // prevent 1. search after launch taking up several seconds
     JA_NL_PARSER.parse("");
@@ -250,14 +312,22 @@ fn clean_img_scan_dir(config: &Config) {
     std::fs::remove_dir_all(&path).expect("Failed to clear img scan director");
 }
 
-pub fn load_resources(config: &Config) {
-    resources::initialize_resources(
+pub fn load_resources(config: &Config) -> ResourceStorage {
+    resources::load(
         config.get_storage_data_path().as_str(),
         config.get_suggestion_sources(),
         config.get_radical_map_path().as_str(),
         config.get_sentences_path().as_str(),
+        config.get_stroke_data_path().as_str(),
+        config.get_stroke_paths_path().as_str(),
     )
-    .expect("Failed to load resources");
+    .expect("Failed to load resources")
+}
+
+/// Loads all resources and indexes and makes them available to searches
+fn load_generation(config: &Config) {
+    let generation = Generation::load(config).expect("Failed to load resources");
+    search::generation::set(generation);
 }
 
 fn load_suggestions(config: &Config) {
@@ -276,10 +346,6 @@ fn load_translations(config: &Config) -> Arc<TranslationDict> {
     Arc::new(locale_dict)
 }
 
-pub fn load_indexes(config: &Config) {
-    search::engine::load_indexes(config).expect("Failed to load v2 index files");
-}
-
 #[cfg(feature = "sentry_error")]
 fn setup_sentry(config: &Config) {
     if let Some(ref sentry_config) = config.sentry {
@@ -0,0 +1,83 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+use actix_web::{http::header::AUTHORIZATION, web::Data, HttpRequest, HttpResponse};
+use config::Config;
+use error::api_error::RestError;
+use log::{error, info};
+use search::generation::{self, Generation};
+
+/// Whether a reload is currently running
+static RELOADING: AtomicBool = AtomicBool::new(false);
+
+/// Reload resources and indexes endpoint. Requires the configured admin token to be passed as
+/// bearer token
+pub async fn reload_ep(
+    config: Data<Config>,
+    request: HttpRequest,
+) -> Result<HttpResponse, actix_web::Error> {
+    let token = match config.server.get_admin_token() {
+        Some(token) => token,
+        // Pretend the endpoint doesn't exist if no admin token is set
+        None => return Err(RestError::NotFound.into()),
+    };
+
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|i| i.to_str().ok())
+        .and_then(|i| i.strip_prefix("Bearer "))
+        .map(|i| i.trim() == token)
+        .unwrap_or(false);
+
+    if !authorized {
+        return Err(RestError::Unauthorized.into());
+    }
+
+    if !reload(config.get_ref().clone()) {
+        return Err(RestError::Conflict.into());
+    }
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+/// Loads all resources and indexes in the background and replaces the currently used ones once
+/// everything has been loaded successfully. Searches which are already running finish using the
+/// old data. Returns `false` if a reload is already running
+pub fn reload(config: Config) -> bool {
+    if RELOADING.swap(true, Ordering::AcqRel) {
+        return false;
+    }
+
+    std::thread::spawn(move || {
+        let start = Instant::now();
+        info!("Reloading resources and indexes");
+
+        match load_generation(&config) {
+            Ok(new_generation) => {
+                // Resources and indexes get replaced by a single swap, so searches always see
+                // data of the same generation. The old one gets dropped once its last search is done
+                generation::set(new_generation);
+                info!("Reloaded resources and indexes in {:?}", start.elapsed());
+            }
+            Err(err) => error!("Failed to reload resources: {}", err),
+        }
+
+        RELOADING.store(false, Ordering::Release);
+    });
+
+    true
+}
+
+/// Loads a new generation of resources and indexes without publishing it
+fn load_generation(config: &Config) -> Result<Generation, String> {
+    // Index loaders panic on invalid files. Those panics must not take down the server
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        Generation::load(config).map_err(|err| err.to_string())
+    }));
+
+    res.unwrap_or_else(|_| Err(String::from("loading panicked")))
+}
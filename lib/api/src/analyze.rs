@@ -0,0 +1,154 @@
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use localization::traits::Translatable;
+use search::generation::{self, Generation};
+use sentence_reader::{igo_unidic::WordClass, Parser};
+use types::api::analyze::{Part, Request, Response, Sentence};
+use utils::real_string_len;
+
+use crate::search::Result;
+
+/// Max amount of characters which can be analyzed in one request
+const MAX_TEXT_LEN: usize = 2000;
+
+/// Characters ending a sentence
+const SENTENCE_END: &[char] = &['。', '！', '？', '!', '?', '\n'];
+
+/// Analyze japanese text endpoint
+pub async fn analyze_ep(payload: Json<Request>) -> Result<Json<Response>> {
+    let text = payload.text.trim();
+    if text.is_empty() || real_string_len(text) > MAX_TEXT_LEN {
+        return Err(RestError::BadRequest);
+    }
+
+    let text = text.to_string();
+    let response = web::block(move || analyze(&text)).await?;
+    Ok(Json(response))
+}
+
+/// Splits `text` into sentences and analyzes each of them
+fn analyze(text: &str) -> Response {
+    let generation = generation::get();
+    let sentences = split_sentences(text)
+        .map(|sentence| Sentence {
+            text: sentence.to_string(),
+            parts: analyze_sentence(&generation, sentence),
+        })
+        .collect();
+
+    Response { sentences }
+}
+
+/// Returns all parts of the given sentence
+#[inline]
+pub(crate) fn analyze_sentence(generation: &Generation, sentence: &str) -> Vec<Part> {
+    analyze_parts(generation, Parser::new(sentence))
+}
+
+/// Returns all parts `parser` splits its input into along with their dictionary entries
+fn analyze_parts(generation: &Generation, parser: Parser) -> Vec<Part> {
+    parser
+        .parts()
+        .into_iter()
+        .filter(|part| !matches!(part.word_class_raw(), WordClass::Space))
+        .map(|mut part| {
+            // Symbols don't have dictionary entries
+            let word = (!matches!(part.word_class_raw(), WordClass::Symbol))
+                .then(|| search::word::find_by_part(generation, &part))
+                .flatten();
+
+            if let Some(furigana) = word.as_ref().and_then(|word| word.furigana.clone()) {
+                part.set_furigana(|_| Some(furigana.clone()));
+            }
+
+            Part {
+                surface: part.get_inflected(),
+                lemma: part.get_normalized(),
+                word_class: part.word_class().map(|i| i.to_string()),
+                inflections: part
+                    .inflections()
+                    .iter()
+                    .map(|i| i.get_id().to_string())
+                    .collect(),
+                furigana: part.furigana().map(|i| i.to_string()),
+                sequence: word.map(|word| word.sequence),
+            }
+        })
+        .collect()
+}
+
+/// Returns an iterator over all non empty sentences in `text`. Sentence ending punctuation stays
+/// part of the sentence
+fn split_sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.split_inclusive(SENTENCE_END)
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use config::{Config, SearchConfig, ServerConfig};
+    use once_cell::sync::Lazy;
+    use sentence_reader::igo_unidic;
+
+    use super::*;
+
+    static NL_PARSER: Lazy<igo_unidic::Parser> =
+        Lazy::new(|| igo_unidic::Parser::new("../../unidic-mecab").unwrap());
+
+    #[test]
+    fn test_split_sentences() {
+        let text = "今日は暑い。明日は？\n\nそうですね！まだ";
+        let sentences: Vec<_> = split_sentences(text).collect();
+        assert_eq!(
+            sentences,
+            vec!["今日は暑い。", "明日は？", "そうですね！", "まだ"]
+        );
+    }
+
+    /// Needs the resources, indexes and the unidic dictionary within the repository root. Run
+    /// with `cargo test -p api -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_analyze_sentence() {
+        let generation = load_data();
+
+        let parser = Parser::with_nl_parser("猫が魚を食べた。", &NL_PARSER);
+        let parts = analyze_parts(&generation, parser);
+        let surfaces: Vec<_> = parts.iter().map(|i| i.surface.as_str()).collect();
+        assert_eq!(surfaces, vec!["猫", "が", "魚", "を", "食べた", "。"]);
+
+        let eat = &parts[4];
+        assert_eq!(eat.lemma, "食べる");
+        assert_eq!(eat.inflections, vec!["Past"]);
+        assert!(eat.sequence.is_some());
+        assert_eq!(eat.furigana.as_deref(), Some("[食|た]べた"));
+
+        // Symbols don't have dictionary entries
+        assert_eq!(parts[5].sequence, None);
+    }
+
+    fn load_data() -> Generation {
+        let config = Config {
+            search: Some(SearchConfig {
+                indexes_source: Some(String::from("../../indexes")),
+                search_timeout: None,
+                suggestion_timeout: None,
+                suggestion_sources: Some(String::from("../../suggestions")),
+                report_queries_after: None,
+                api_max_page_size: None,
+            }),
+            server: ServerConfig {
+                storage_data: Some(String::from("../../resources/storage_data")),
+                sentences: Some(String::from("../../resources/sentences.bin")),
+                radical_map: Some(String::from("../../resources/radical_map")),
+                stroke_data: Some(String::from("../../resources/stroke_data")),
+                stroke_paths: Some(String::from("../../resources/stroke_paths")),
+                ..ServerConfig::default()
+            },
+            ..Config::default()
+        };
+
+        Generation::load(&config).expect("Failed to load resources")
+    }
+}
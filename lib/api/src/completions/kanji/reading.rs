@@ -1,13 +1,17 @@
 use std::cmp::Ordering;
 
 use error::api_error::RestError;
+use search::generation::Generation;
 use types::{api::completions::SuggestionType, jotoba::kanji};
 
 use types::api::completions::{Response, WordPair};
 
 /// Gets suggestions for kanji reading search eg: "痛 いた.い"
-pub async fn suggestions(kanji_reading: kanji::ReadingSearch) -> Result<Response, RestError> {
-    let kanji_storage = resources::get().kanji();
+pub async fn suggestions(
+    generation: &Generation,
+    kanji_reading: kanji::ReadingSearch,
+) -> Result<Response, RestError> {
+    let kanji_storage = generation.resources().kanji();
 
     let literal = kanji_reading.literal;
     let reading = kanji_reading.reading.replace("。", "").replace(".", "");
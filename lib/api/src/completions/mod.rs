@@ -45,12 +45,14 @@ pub async fn suggestion_ep(
 
 /// Returns best matching suggestions for the given query
 async fn get_suggestions(query: Query, radicals: Vec<char>) -> Result<Response, RestError> {
+    let generation = search::generation::get();
+
     match query.type_ {
         QueryType::Sentences | QueryType::Words => {
             if let Some(kanji_reading) = as_kanji_reading(&query) {
-                kanji::reading::suggestions(kanji_reading).await
+                kanji::reading::suggestions(&generation, kanji_reading).await
             } else {
-                Ok(words::suggestions(query, &radicals)
+                Ok(words::suggestions(&generation, query, &radicals)
                     .await
                     .unwrap_or_default())
             }
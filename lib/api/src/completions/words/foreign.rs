@@ -1,25 +1,35 @@
 use japanese::guessing::could_be_romaji;
 use resources::models::suggestions::foreign_words::ForeignSuggestion;
+use search::generation::Generation;
 use utils::{binary_search::BinarySearchable, real_string_len};
 
 use super::super::*;
 
 /// Returns suggestions based on non japanese input
-pub async fn suggestions(query: &Query, query_str: &str) -> Option<Vec<WordPair>> {
+pub async fn suggestions(
+    generation: &Generation,
+    query: &Query,
+    query_str: &str,
+) -> Option<Vec<WordPair>> {
     let lang = query.settings.user_lang;
 
     // Check if suggestions are available for the given language
-    if !resources::get().suggestions().foreign_words(lang).is_some() {
+    if !generation
+        .resources()
+        .suggestions()
+        .foreign_words(lang)
+        .is_some()
+    {
         return None;
     }
 
     let query_str = query_str.trim().to_owned();
 
-    Some(search(lang, &query_str))
+    Some(search(generation, lang, &query_str))
 }
 
-fn search<'a>(main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
-    let mut res: Vec<_> = search_by_lang(main_lang, query_str, true)
+fn search<'a>(generation: &Generation, main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
+    let mut res: Vec<_> = search_by_lang(generation, main_lang, query_str, true)
         .map(|i| {
             let similarity =
                 (strsim::jaro(&i.text.to_lowercase(), &query_str.to_lowercase()) * 100f64) as i32;
@@ -27,14 +37,7 @@ fn search<'a>(main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
         })
         .take(50)
         .chain(
-            search_by_lang(main_lang, &query_str.to_lowercase(), true).filter_map(|i| {
-                let similarity = (strsim::jaro(&i.text.to_lowercase(), &query_str.to_lowercase())
-                    * 90f64) as i32;
-                Some((i, main_lang, similarity))
-            }),
-        )
-        .chain(
-            search_by_lang(main_lang, &utils::first_letter_upper(query_str), true).filter_map(
+            search_by_lang(generation, main_lang, &query_str.to_lowercase(), true).filter_map(
                 |i| {
                     let similarity =
                         (strsim::jaro(&i.text.to_lowercase(), &query_str.to_lowercase()) * 90f64)
@@ -43,11 +46,24 @@ fn search<'a>(main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
                 },
             ),
         )
+        .chain(
+            search_by_lang(
+                generation,
+                main_lang,
+                &utils::first_letter_upper(query_str),
+                true,
+            )
+            .filter_map(|i| {
+                let similarity = (strsim::jaro(&i.text.to_lowercase(), &query_str.to_lowercase())
+                    * 90f64) as i32;
+                Some((i, main_lang, similarity))
+            }),
+        )
         .collect();
 
     if main_lang != Language::English {
         res.extend(
-            search_by_lang(Language::English, query_str, false)
+            search_by_lang(generation, Language::English, query_str, false)
                 .take(100)
                 .map(|i| {
                     let similarity =
@@ -70,7 +86,7 @@ fn search<'a>(main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
             }
 
             let hira_query = utils::format_romaji_nn(query).to_hiragana();
-            if let Some(hira_res) = super::native::suggest_words(&[&hira_query], &[]) {
+            if let Some(hira_res) = super::native::suggest_words(generation, &[&hira_query], &[]) {
                 hira_res.into_iter().for_each(|i| {
                     let exact_match = i.0.primary == hira_query;
 
@@ -120,11 +136,12 @@ fn search<'a>(main_lang: Language, query_str: &'a str) -> Vec<WordPair> {
 }
 
 fn search_by_lang<'a>(
+    generation: &'a Generation,
     lang: Language,
     query_str: &'a str,
     tryhard: bool,
 ) -> impl Iterator<Item = ForeignSuggestion> + 'a {
-    let suggestion_provider = resources::get().suggestions();
+    let suggestion_provider = generation.resources().suggestions();
     let dict = suggestion_provider.foreign_words(lang).unwrap();
 
     let find = move |e: &ForeignSuggestion| beg_match(e, query_str);
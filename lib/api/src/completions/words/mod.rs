@@ -4,17 +4,24 @@ pub mod native;
 use std::cmp::Ordering;
 
 use japanese::JapaneseExt;
-use search::query::{Query, QueryLang};
+use search::{
+    generation::Generation,
+    query::{Query, QueryLang},
+};
 use types::api::completions::{Response, WordPair};
 use utils::bool_ord;
 
 /// Returns word suggestions based on the query. Applies various approaches to give better results
-pub(crate) async fn suggestions(query: Query, radicals: &[char]) -> Option<Response> {
-    let response = try_word_suggestions(&query, radicals).await?;
+pub(crate) async fn suggestions(
+    generation: &Generation,
+    query: Query,
+    radicals: &[char],
+) -> Option<Response> {
+    let response = try_word_suggestions(generation, &query, radicals).await?;
 
     // Tries to do a katakana search if nothing was found
     let result = if response.is_empty() && query.query.is_hiragana() {
-        try_word_suggestions(&get_katakana_query(&query), radicals).await?
+        try_word_suggestions(generation, &get_katakana_query(&query), radicals).await?
     } else {
         response
     };
@@ -26,12 +33,16 @@ pub(crate) async fn suggestions(query: Query, radicals: &[char]) -> Option<Respo
 }
 
 /// Returns Ok(suggestions) for the given query ordered and ready to display
-async fn try_word_suggestions(query: &Query, radicals: &[char]) -> Option<Vec<WordPair>> {
+async fn try_word_suggestions(
+    generation: &Generation,
+    query: &Query,
+    radicals: &[char],
+) -> Option<Vec<WordPair>> {
     // Get sugesstions for matching language
     let word_pairs = match query.language {
-        QueryLang::Japanese => native::suggestions(&query, radicals)?,
+        QueryLang::Japanese => native::suggestions(generation, &query, radicals)?,
         QueryLang::Foreign | QueryLang::Undetected | QueryLang::Korean => {
-            let mut res = foreign::suggestions(&query, &query.query)
+            let mut res = foreign::suggestions(generation, &query, &query.query)
                 .await
                 .unwrap_or_default();
 
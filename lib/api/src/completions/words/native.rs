@@ -2,29 +2,34 @@ use std::{cmp::min, collections::BinaryHeap, time::Instant};
 
 use itertools::Itertools;
 use resources::models::suggestions::native_words::NativeSuggestion;
+use search::generation::Generation;
 use types::jotoba::words::Word;
 use utils::binary_search::BinarySearchable;
 
 use super::super::*;
 
 /// Get suggestions for foreign search input
-pub fn suggestions(query: &Query, radicals: &[char]) -> Option<Vec<WordPair>> {
+pub fn suggestions(
+    generation: &Generation,
+    query: &Query,
+    radicals: &[char],
+) -> Option<Vec<WordPair>> {
     let query_str = query.query.as_str();
     let start = Instant::now();
 
     // parsing query
     let query_str_aligned = align_query_str(query_str).unwrap_or_else(|| query_str.to_string());
 
-    let mut items = suggest_words(&[&query_str, &query_str_aligned], &radicals)?;
+    let mut items = suggest_words(generation, &[&query_str, &query_str_aligned], &radicals)?;
     if items.len() <= 4 && !query_str.is_katakana() {
-        if let Some(other) = suggest_words(&[&romaji::RomajiExt::to_katakana(query_str)], &radicals)
-        {
+        let katakana = romaji::RomajiExt::to_katakana(query_str);
+        if let Some(other) = suggest_words(generation, &[&katakana], &radicals) {
             items.extend(other);
         }
     }
 
     if items.len() < 50 {
-        if let Some(aligned) = k_reading_align(query_str) {
+        if let Some(aligned) = k_reading_align(generation, query_str) {
             items.extend(aligned);
         }
     }
@@ -47,12 +52,12 @@ fn align_query_str(query_str: &str) -> Option<String> {
 }
 
 /// Finds suggestions for all kanji componunds which can be built of the given query
-fn k_reading_align(query: &str) -> Option<Vec<(WordPair, u32)>> {
+fn k_reading_align(generation: &Generation, query: &str) -> Option<Vec<(WordPair, u32)>> {
     if !query.is_kana() {
         return None;
     }
 
-    let words = resources::get().words();
+    let words = generation.resources().words();
     let align = storage::K_READING_ALIGN.get()?;
 
     let res = align
@@ -60,7 +65,7 @@ fn k_reading_align(query: &str) -> Option<Vec<(WordPair, u32)>> {
         .iter()
         .filter_map(|i| {
             let word = words.by_sequence(*i)?;
-            let wp: WordPair = word.into();
+            let wp = WordPair::from(&*word);
             Some((wp, 0))
         })
         .collect::<Vec<_>>();
@@ -72,12 +77,13 @@ fn k_reading_align(query: &str) -> Option<Vec<(WordPair, u32)>> {
 struct WordPairOrder((WordPair, u32));
 
 pub(super) fn suggest_words(
+    generation: &Generation,
     queries: &[&str],
     filter_radicals: &[char],
 ) -> Option<Vec<(WordPair, u32)>> {
-    let suggestion_provider = resources::get().suggestions();
+    let suggestion_provider = generation.resources().suggestions();
     let dict = suggestion_provider.japanese_words()?;
-    let word_storage = resources::get().words();
+    let word_storage = generation.resources().words();
 
     let mut heap: BinaryHeap<WordPairOrder> = BinaryHeap::with_capacity(50);
 
@@ -94,13 +100,13 @@ pub(super) fn suggest_words(
 
                     // Filter out non radical matching words if radicals are given
                     if !filter_radicals.is_empty()
-                        && !word_rad_filter(&query, &word, filter_radicals)
+                        && !word_rad_filter(generation, &query, &word, filter_radicals)
                     {
                         return None;
                     }
 
-                    let score = score(word, &sugg_item, query, &query_romaji);
-                    Some(WordPairOrder((word.into(), score)))
+                    let score = score(&word, &sugg_item, query, &query_romaji);
+                    Some(WordPairOrder((WordPair::from(&*word), score)))
                 })
                 .take(500),
         );
@@ -115,13 +121,13 @@ pub(super) fn suggest_words(
     Some(items)
 }
 
-fn word_rad_filter(query: &str, word: &Word, radicals: &[char]) -> bool {
+fn word_rad_filter(generation: &Generation, query: &str, word: &Word, radicals: &[char]) -> bool {
     let kanji = match word.reading.kanji.as_ref() {
         Some(k) => &k.reading,
         None => return false,
     };
 
-    let retrieve = resources::get().kanji();
+    let retrieve = generation.resources().kanji();
 
     let query_kanji = query.chars().filter(|i| i.is_kanji()).collect::<Vec<_>>();
 
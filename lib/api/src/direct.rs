@@ -0,0 +1,95 @@
+use actix_web::web::{Json, Path, Query};
+use error::api_error::RestError;
+use japanese::JapaneseExt;
+use search::{generation, sentence::result::Sentence, word::kanji::load_word_kanji_info};
+use types::{
+    api::search::{kanji, name, sentence, word, LookupRequest, PageInfo},
+    jotoba::words::filter_languages,
+};
+
+use crate::search::{sentence::search_to_sentence, Result};
+
+/// Get a single word by its sequence id
+pub async fn word_by_seq(seq: Path<u32>, payload: Query<LookupRequest>) -> Result<Json<word::Response>> {
+    let generation = generation::get();
+    let word = generation
+        .resources()
+        .words()
+        .by_sequence(*seq)
+        .ok_or(RestError::NotFound)?
+        .into_owned();
+
+    let mut words = vec![word.clone()];
+
+    // Show english if the word isn't available in the requested language at all
+    let show_english = !payload.no_english || !word.has_language(payload.language, false);
+    filter_languages(words.iter_mut(), payload.language, show_english);
+
+    let kanji = load_word_kanji_info(&generation, &words);
+    let words: Vec<_> = words.iter().collect();
+    let kanji: Vec<_> = kanji.iter().collect();
+    let mut response = word::Response::from((words, kanji));
+    response.page_info = single_page();
+    Ok(Json(response))
+}
+
+/// Get a single name by its sequence id
+pub async fn name_by_seq(seq: Path<u32>) -> Result<Json<name::Response>> {
+    let generation = generation::get();
+    let name = generation
+        .resources()
+        .names()
+        .by_sequence(*seq)
+        .ok_or(RestError::NotFound)?;
+
+    let mut response: name::Response = vec![name.as_ref()].into();
+    response.page_info = single_page();
+    Ok(Json(response))
+}
+
+/// Get a single sentence by its id
+pub async fn sentence_by_id(
+    id: Path<u32>,
+    payload: Query<LookupRequest>,
+) -> Result<Json<sentence::Response>> {
+    let sentence = generation::get()
+        .resources()
+        .sentences()
+        .by_id(*id)
+        .ok_or(RestError::NotFound)?
+        .clone();
+
+    let sentence = Sentence::from_m_sentence(sentence, payload.language, !payload.no_english)
+        .ok_or(RestError::NotFound)?;
+
+    let mut response: sentence::Response = vec![search_to_sentence(sentence)].into();
+    response.page_info = single_page();
+    Ok(Json(response))
+}
+
+/// Get a single kanji by its literal
+pub async fn kanji_by_literal(literal: Path<String>) -> Result<Json<kanji::Response>> {
+    let mut chars = literal.chars();
+    let literal = match (chars.next(), chars.next()) {
+        (Some(literal), None) if literal.is_kanji() => literal,
+        _ => return Err(RestError::BadRequest),
+    };
+
+    let generation = generation::get();
+    let kanji = generation
+        .resources()
+        .kanji()
+        .by_literal(literal)
+        .ok_or(RestError::NotFound)?;
+
+    Ok(Json(kanji::Response {
+        kanji: vec![kanji.as_ref().into()],
+        page_info: single_page(),
+    }))
+}
+
+/// `PageInfo` of a response containing a single entry
+#[inline]
+fn single_page() -> PageInfo {
+    PageInfo::new(1, 1, 1)
+}
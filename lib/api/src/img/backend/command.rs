@@ -0,0 +1,113 @@
+use std::{
+    fs::File,
+    io::Read,
+    path::Path,
+    process::{self, Child, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use config::Config;
+use error::api_error::RestError;
+use log::error;
+use types::api::image::{Line, Request};
+
+use super::OcrBackend;
+
+/// Interval in which a running command gets checked for having exited
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// OCR backend running an external command. The image gets passed via stdin and the recognized
+/// text is read from stdout, one line of text per line
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl Command {
+    /// Returns the command configured in `config` or `None` if there is none. The command gets
+    /// split at whitespace, so quoted arguments aren't supported
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let mut parts = config.server.get_ocr_command()?.split_whitespace();
+        let program = parts.next()?.to_string();
+        let args = parts.map(|i| i.to_string()).collect();
+        let timeout = config.server.get_ocr_timeout();
+        Some(Self {
+            program,
+            args,
+            timeout,
+        })
+    }
+}
+
+impl OcrBackend for Command {
+    fn scan(&self, file: &Path, _req: &Request) -> Result<Vec<Line>, RestError> {
+        let mut child = process::Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::from(File::open(file)?))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // Read stdout while waiting so the command can't block on a full pipe
+        let mut stdout = child.stdout.take().ok_or(RestError::Internal)?;
+        let reader = thread::spawn(move || {
+            let mut text = String::new();
+            stdout.read_to_string(&mut text).map(|_| text)
+        });
+
+        let status = match wait_timeout(&mut child, self.timeout)? {
+            Some(status) => status,
+            None => {
+                child.kill().ok();
+                child.wait().ok();
+                error!("OCR command '{}' timed out", self.program);
+                return Err(RestError::Timeout);
+            }
+        };
+
+        if !status.success() {
+            error!("OCR command '{}' failed: {}", self.program, status);
+            return Err(RestError::Internal);
+        }
+
+        let text = reader
+            .join()
+            .map_err(|_| RestError::Internal)?
+            .map_err(|_| RestError::Internal)?;
+        Ok(parse_lines(&text))
+    }
+}
+
+/// Waits for `child` to exit. Returns `None` if it's still running after `timeout`
+fn wait_timeout(child: &mut Child, timeout: Duration) -> Result<Option<ExitStatus>, RestError> {
+    let start = Instant::now();
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if start.elapsed() >= timeout {
+            return Ok(None);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Builds lines out of the commands output. Non japanese text gets removed and empty lines are
+/// dropped
+fn parse_lines(text: &str) -> Vec<Line> {
+    text.lines()
+        .filter_map(|line| crate::img::format_text(line.to_string()))
+        .map(|text| Line {
+            text,
+            confidence: None,
+            bbox: None,
+            words: vec![],
+            parts: None,
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+mod command;
+#[cfg(feature = "img_scan")]
+mod tesseract;
+
+use std::path::Path;
+
+use config::Config;
+use error::api_error::RestError;
+use types::api::image::{Line, Request};
+
+/// A backend recognizing japanese text within images
+pub trait OcrBackend: Send {
+    /// Scans the image stored at `file` and returns all recognized lines in reading order
+    fn scan(&self, file: &Path, req: &Request) -> Result<Vec<Line>, RestError>;
+}
+
+/// Returns the OCR backend configured in `config` or `None` if image scanning is disabled. An
+/// external OCR command takes precedence over tesseract
+pub fn from_config(config: &Config) -> Option<Box<dyn OcrBackend>> {
+    if let Some(command) = command::Command::from_config(config) {
+        return Some(Box::new(command));
+    }
+
+    tesseract_backend(config)
+}
+
+#[cfg(feature = "img_scan")]
+#[inline]
+fn tesseract_backend(config: &Config) -> Option<Box<dyn OcrBackend>> {
+    Some(Box::new(tesseract::Tesseract::new(config)))
+}
+
+#[cfg(not(feature = "img_scan"))]
+#[inline]
+fn tesseract_backend(_config: &Config) -> Option<Box<dyn OcrBackend>> {
+    None
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use config::Config;
+use error::api_error::RestError;
+use leptess::LepTess;
+use types::api::image::{Line, Request};
+
+use super::OcrBackend;
+use crate::img::tsv;
+
+/// OCR backend using tesseract
+pub struct Tesseract {
+    tess_data: Option<String>,
+}
+
+impl Tesseract {
+    #[inline]
+    pub fn new(config: &Config) -> Self {
+        Self {
+            tess_data: config.server.tess_data.clone(),
+        }
+    }
+}
+
+impl OcrBackend for Tesseract {
+    fn scan(&self, file: &Path, req: &Request) -> Result<Vec<Line>, RestError> {
+        let lang = if req.vertical { "jpn_vert" } else { "jpn" };
+        let mut lt =
+            LepTess::new(self.tess_data.as_deref(), lang).map_err(|_| RestError::Internal)?;
+        lt.set_image(file).map_err(|_| RestError::NoTextFound)?;
+
+        if lt.get_source_y_resolution() <= 0 {
+            lt.set_source_resolution(70)
+        }
+
+        if lt.mean_text_conf() < req.threshold {
+            return Err(RestError::NoTextFound);
+        }
+
+        let tsv = lt.get_tsv_text(0).map_err(|_| RestError::Internal)?;
+        Ok(tsv::parse_lines(&tsv))
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
+pub mod backend;
 pub mod request;
+mod tsv;
 
 use std::path::Path;
 
@@ -12,6 +14,8 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use types::api::image::{Request, Response};
 
+use self::backend::OcrBackend;
+
 // MAX 2MB
 const MAX_UPLOAD_SIZE: usize = 2 * 1024 * 1024;
 
@@ -25,12 +29,14 @@ pub async fn scan_ep(
     args: web::Query<Request>,
     config: web::Data<Config>,
 ) -> Result<Json<Response>, actix_web::Error> {
+    let backend = backend::from_config(&config).ok_or(RestError::ScanUnavailable)?;
+
     // Load payload
     let local_file = request::read_payload(&config, payload).await?;
 
     // Scan image
     let local_file_cloned = local_file.clone();
-    let res = web::block(move || scan_image(local_file_cloned, &args, &config)).await;
+    let res = web::block(move || scan_image(backend.as_ref(), &local_file_cloned, &args)).await;
 
     // Cleanup file
     web::block(move || std::fs::remove_file(local_file)).await??;
@@ -39,36 +45,27 @@ pub async fn scan_ep(
     Ok(Json(res??))
 }
 
-/// Scans an image and returns a `Response` with the recognized text or an error
-#[cfg(feature = "img_scan")]
-fn scan_image<P: AsRef<Path>>(
-    file: P,
-    req: &Request,
-    config: &Config,
-) -> Result<Response, RestError> {
-    let tess_data = config.server.tess_data.as_ref().map(|i| i.as_str());
-    let mut lt = leptess::LepTess::new(tess_data, "jpn").map_err(|_| RestError::Internal)?;
-    lt.set_image(file).map_err(|_| RestError::NoTextFound)?;
-
-    if lt.get_source_y_resolution() <= 0 {
-        lt.set_source_resolution(70)
+/// Scans an image using `backend` and returns a `Response` with the recognized text or an error
+fn scan_image(backend: &dyn OcrBackend, file: &Path, req: &Request) -> Result<Response, RestError> {
+    let mut lines = backend.scan(file, req)?;
+    if lines.is_empty() {
+        return Err(RestError::NoTextFound);
     }
 
-    if lt.mean_text_conf() < req.threshold {
-        return Err(RestError::NoTextFound);
+    if req.analyze {
+        let generation = search::generation::get();
+        for line in lines.iter_mut() {
+            line.parts = Some(crate::analyze::analyze_sentence(&generation, &line.text));
+        }
     }
 
-    let text = lt
-        .get_utf8_text()
-        .ok()
-        .and_then(|text| format_text(text))
-        .ok_or(RestError::NoTextFound)?;
+    let text = lines.iter().map(|i| i.text.as_str()).join("");
 
-    Ok(Response { text })
+    Ok(Response { text, lines })
 }
 
 /// Format non-japanese characters from scanned result
-fn format_text(text: String) -> Option<String> {
+pub(crate) fn format_text(text: String) -> Option<String> {
     let modded_text = FILTER_JP_REGEX
         .captures_iter(&text)
         .into_iter()
@@ -82,15 +79,3 @@ fn format_text(text: String) -> Option<String> {
 
     (!modded_text.is_empty()).then(|| modded_text)
 }
-
-/// Scans an image and returns a `Response` with the recognized text or an error
-#[cfg(not(feature = "img_scan"))]
-fn scan_image<P: AsRef<Path>>(
-    _file: P,
-    _req: &Request,
-    _config: &Config,
-) -> Result<Response, RestError> {
-    Ok(Response {
-        text: String::from("unsupported"),
-    })
-}
@@ -0,0 +1,131 @@
+use types::api::image::{BoundingBox, Line, Word};
+
+/// Tesseract page iterator level of lines
+const LEVEL_LINE: u8 = 4;
+
+/// Tesseract page iterator level of words
+const LEVEL_WORD: u8 = 5;
+
+/// A single row of tesseracts TSV output
+struct Row<'a> {
+    level: u8,
+    /// Block, paragraph and line number. Unique for each line of a page
+    line_id: (u32, u32, u32),
+    bbox: BoundingBox,
+    confidence: f32,
+    text: &'a str,
+}
+
+/// Builds lines and their words out of tesseracts TSV output. Non japanese text gets removed
+/// from the words and lines without any remaining word are dropped
+pub(crate) fn parse_lines(tsv: &str) -> Vec<Line> {
+    let mut lines: Vec<(Row, Vec<Word>)> = vec![];
+
+    for row in tsv.lines().filter_map(parse_row) {
+        match row.level {
+            LEVEL_LINE => lines.push((row, vec![])),
+            LEVEL_WORD => {
+                let words = match lines.last_mut() {
+                    Some((line, words)) if line.line_id == row.line_id => words,
+                    _ => continue,
+                };
+
+                if let Some(text) = super::format_text(row.text.to_string()) {
+                    words.push(Word {
+                        text,
+                        confidence: row.confidence,
+                        bbox: row.bbox,
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    lines
+        .into_iter()
+        .filter(|(_, words)| !words.is_empty())
+        .map(|(row, words)| {
+            let confidence = words.iter().map(|i| i.confidence).sum::<f32>() / words.len() as f32;
+            Line {
+                text: words.iter().map(|i| i.text.as_str()).collect(),
+                confidence: Some(confidence),
+                bbox: Some(row.bbox),
+                words,
+                parts: None,
+            }
+        })
+        .collect()
+}
+
+/// Parses a single row of the TSV output. Returns `None` for the header and invalid rows
+fn parse_row(row: &str) -> Option<Row<'_>> {
+    // level, page_num, block_num, par_num, line_num, word_num, left, top, width, height, conf, text
+    let mut cols = row.split('\t');
+    let mut next = || cols.next();
+
+    let level = next()?.parse().ok()?;
+    next()?;
+    let line_id = (
+        next()?.parse().ok()?,
+        next()?.parse().ok()?,
+        next()?.parse().ok()?,
+    );
+    next()?;
+
+    let bbox = BoundingBox {
+        x: next()?.parse().ok()?,
+        y: next()?.parse().ok()?,
+        width: next()?.parse().ok()?,
+        height: next()?.parse().ok()?,
+    };
+
+    let confidence = next()?.parse().ok()?;
+    let text = next().unwrap_or_default().trim();
+
+    Some(Row {
+        level,
+        line_id,
+        bbox,
+        confidence,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TSV: &str = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext
+1\t1\t0\t0\t0\t0\t0\t0\t400\t200\t-1\t
+2\t1\t1\t0\t0\t0\t10\t10\t300\t90\t-1\t
+3\t1\t1\t1\t0\t0\t10\t10\t300\t90\t-1\t
+4\t1\t1\t1\t1\t0\t10\t10\t300\t40\t-1\t
+5\t1\t1\t1\t1\t1\t10\t10\t120\t40\t95.5\t今日は
+5\t1\t1\t1\t1\t2\t130\t10\t180\t40\t84.5\t暑い
+4\t1\t1\t1\t2\t0\t10\t60\t300\t40\t-1\t
+5\t1\t1\t1\t2\t1\t10\t60\t300\t40\t30\t|~
+";
+
+    #[test]
+    fn test_parse_lines() {
+        let lines = parse_lines(TSV);
+        assert_eq!(lines.len(), 1);
+
+        let line = &lines[0];
+        assert_eq!(line.text, "今日は暑い");
+        assert_eq!(line.confidence, Some(90.0));
+        assert_eq!(
+            line.bbox,
+            Some(BoundingBox {
+                x: 10,
+                y: 10,
+                width: 300,
+                height: 40
+            })
+        );
+        assert_eq!(line.words.len(), 2);
+        assert_eq!(line.words[1].text, "暑い");
+        assert_eq!(line.words[1].bbox.x, 130);
+    }
+}
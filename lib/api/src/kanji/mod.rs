@@ -0,0 +1,133 @@
+mod svg;
+
+use actix_web::{
+    web::{Data, Json, Path},
+    HttpResponse,
+};
+use config::Config;
+use error::api_error::RestError;
+use japanese::JapaneseExt;
+use search::generation;
+use types::api::{
+    kanji::{by_component, decomposition, handwriting, strokes},
+    search::PageInfo,
+};
+
+use crate::search::Result;
+
+/// Max amount of strokes accepted by the handwriting recognition
+const MAX_STROKES: usize = 64;
+
+/// Max amount of points a single stroke may consist of
+const MAX_STROKE_POINTS: usize = 1024;
+
+/// Max amount of candidates returned by the handwriting recognition
+const MAX_CANDIDATES: usize = 100;
+
+/// Get the full decomposition tree of a kanji
+pub async fn kanji_decomposition(literal: Path<String>) -> Result<Json<decomposition::Response>> {
+    let literal = parse_literal(&literal)?;
+
+    let decomposition = generation::get()
+        .resources()
+        .kanji()
+        .decomposition(literal)
+        .ok_or(RestError::NotFound)?;
+
+    Ok(Json((&decomposition).into()))
+}
+
+/// Get all kanji containing a given component
+pub async fn kanji_by_component(
+    payload: Json<by_component::Request>,
+    config: Data<Config>,
+) -> Result<Json<by_component::Response>> {
+    let max_limit = config.get_api_max_page_size();
+    let limit = payload.limit.unwrap_or(max_limit).clamp(1, max_limit);
+    let page = payload.page.unwrap_or(1).max(1);
+
+    let kanji = generation::get()
+        .resources()
+        .kanji()
+        .by_component(payload.component);
+
+    let page_info = PageInfo::new(kanji.len() as u32, page, limit);
+
+    let kanji = kanji
+        .into_iter()
+        .skip((page - 1) as usize * limit as usize)
+        .take(limit as usize)
+        .collect();
+
+    Ok(Json(by_component::Response { kanji, page_info }))
+}
+
+/// Recognize a handwritten kanji by its strokes
+pub async fn kanji_by_handwriting(
+    payload: Json<handwriting::Request>,
+) -> Result<Json<handwriting::Response>> {
+    let strokes = &payload.strokes;
+    if strokes.is_empty()
+        || strokes.len() > MAX_STROKES
+        || strokes
+            .iter()
+            .any(|i| i.is_empty() || i.len() > MAX_STROKE_POINTS)
+        || strokes.iter().flatten().flatten().any(|i| !i.is_finite())
+    {
+        return Err(RestError::BadRequest);
+    }
+
+    let candidates = generation::get()
+        .resources()
+        .kanji()
+        .by_handwriting(strokes)
+        .into_iter()
+        .take(payload.limit.min(MAX_CANDIDATES))
+        .map(|(kanji, score)| handwriting::Candidate {
+            literal: kanji.literal,
+            stroke_count: kanji.stroke_count,
+            score,
+        })
+        .collect();
+
+    Ok(Json(handwriting::Response { candidates }))
+}
+
+/// Get the stroke paths of a kanji in stroke order
+pub async fn kanji_strokes(literal: Path<String>) -> Result<Json<strokes::Response>> {
+    let literal = parse_literal(&literal)?;
+
+    let strokes = generation::get()
+        .resources()
+        .kanji()
+        .stroke_paths(literal)
+        .ok_or(RestError::NotFound)?
+        .to_vec();
+
+    Ok(Json(strokes::Response { literal, strokes }))
+}
+
+/// Get a numbered stroke-order SVG of a kanji
+pub async fn kanji_strokes_svg(literal: Path<String>) -> Result<HttpResponse> {
+    let literal = parse_literal(&literal)?;
+
+    let generation = generation::get();
+    let strokes = generation
+        .resources()
+        .kanji()
+        .stroke_paths(literal)
+        .ok_or(RestError::NotFound)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("image/svg+xml")
+        .body(svg::stroke_order(strokes)))
+}
+
+/// Parses a kanji literal passed in the url. Returns `BadRequest` if it's not a single kanji
+fn parse_literal(literal: &str) -> Result<char> {
+    let mut chars = literal.chars();
+    match (chars.next(), chars.next()) {
+        (Some(literal), None) if literal.is_kanji() => Ok(literal),
+        _ => Err(RestError::BadRequest),
+    }
+}
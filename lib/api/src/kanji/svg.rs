@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+/// Size of the viewbox stroke paths are based on
+const VIEWBOX_SIZE: f32 = 109.0;
+
+/// Font size of the stroke numbers
+const FONT_SIZE: f32 = 8.0;
+
+/// Renders a static stroke-order SVG of the given stroke paths. Each stroke gets labeled with its
+/// number next to the point it starts at
+pub(crate) fn stroke_order(strokes: &[String]) -> String {
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+        VIEWBOX_SIZE
+    );
+
+    svg.push_str(r#"<g style="fill:none;stroke:#000000;stroke-width:3;stroke-linecap:round;stroke-linejoin:round">"#);
+    for stroke in strokes {
+        write!(svg, r#"<path d="{}"/>"#, stroke).unwrap();
+    }
+    svg.push_str("</g>");
+
+    write!(svg, r#"<g style="font-size:{}px;fill:#808080">"#, FONT_SIZE).unwrap();
+    for (nr, stroke) in strokes.iter().enumerate() {
+        let (x, y) = match start_point(stroke) {
+            Some(point) => point,
+            None => continue,
+        };
+
+        // Place the number at the top left of the start point while keeping it visible
+        let x = (x - FONT_SIZE).clamp(0.0, VIEWBOX_SIZE - FONT_SIZE);
+        let y = (y - 2.0).clamp(FONT_SIZE, VIEWBOX_SIZE);
+        write!(svg, r#"<text x="{:.1}" y="{:.1}">{}</text>"#, x, y, nr + 1).unwrap();
+    }
+    svg.push_str("</g></svg>");
+
+    svg
+}
+
+/// Returns the start point of an SVG path. Paths always start with a move command
+fn start_point(path: &str) -> Option<(f32, f32)> {
+    let rest = path.trim_start().strip_prefix(|c| c == 'M' || c == 'm')?;
+    let (x, rest) = next_number(rest)?;
+    let (y, _) = next_number(rest)?;
+    Some((x, y))
+}
+
+/// Parses the number at the beginning of `s`, ignoring leading separators. Returns the number and
+/// the remaining text
+fn next_number(s: &str) -> Option<(f32, &str)> {
+    let s = s.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+
+    let mut seen_dot = false;
+    let end = s
+        .char_indices()
+        .find(|(pos, c)| match c {
+            '-' | '+' => *pos > 0,
+            '.' if !seen_dot => {
+                seen_dot = true;
+                false
+            }
+            c => !c.is_ascii_digit(),
+        })
+        .map(|i| i.0)
+        .unwrap_or(s.len());
+
+    Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_start_point() {
+        assert_eq!(start_point("M13.25,52.55c3,0.7"), Some((13.25, 52.55)));
+        assert_eq!(start_point("M 53.5 13.5 c0.88"), Some((53.5, 13.5)));
+        assert_eq!(start_point("M31.5-4c1,1"), Some((31.5, -4.0)));
+        assert_eq!(start_point("c1,1"), None);
+    }
+
+    #[test]
+    fn test_stroke_order() {
+        let svg = stroke_order(&[String::from("M13.25,52.55c3,0.7,6.38,0.63,9.4,0.29")]);
+        assert!(svg.contains(r#"<path d="M13.25,52.55c3,0.7,6.38,0.63,9.4,0.29"/>"#));
+        assert!(svg.contains(r#"<text x="5.2" y="50.5">1</text>"#));
+    }
+}
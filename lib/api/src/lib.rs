@@ -1,5 +1,10 @@
+pub mod admin;
+pub mod analyze;
 pub mod completions;
+pub mod direct;
 pub mod img;
+pub mod kanji;
 pub mod news;
+pub mod pitch;
 pub mod radical;
 pub mod search;
@@ -0,0 +1,59 @@
+mod svg;
+
+use actix_web::{
+    web::{Path, Query},
+    HttpResponse,
+};
+use error::api_error::RestError;
+use itertools::Itertools;
+use japanese::{accent, JapaneseExt};
+use types::api::pitch::{Request, WordRequest};
+
+use crate::search::Result;
+
+/// Max amount of characters of kana passed to the pitch endpoint
+const MAX_KANA_LEN: usize = 30;
+
+/// Get a pitch diagram of arbitrary kana
+pub async fn pitch_by_kana(payload: Query<Request>) -> Result<HttpResponse> {
+    let kana = payload.kana.trim();
+    if kana.is_empty() || kana.chars().count() > MAX_KANA_LEN || !kana.is_kana() {
+        return Err(RestError::BadRequest);
+    }
+
+    let pattern = accent::pitch_pattern(kana, payload.drop).ok_or(RestError::BadRequest)?;
+
+    Ok(svg_response(svg::pitch_diagram(&[pattern])))
+}
+
+/// Get a pitch diagram of a word. Renders all accent variants of the word if requested
+pub async fn pitch_by_seq(seq: Path<u32>, payload: Query<WordRequest>) -> Result<HttpResponse> {
+    let generation = search::generation::get();
+    let word = generation
+        .resources()
+        .words()
+        .by_sequence(*seq)
+        .ok_or(RestError::NotFound)?;
+
+    let accents = word.accents.as_ref().ok_or(RestError::NotFound)?;
+    let take = if payload.all { accents.len() } else { 1 };
+
+    let kana = &word.reading.kana.reading;
+    let patterns: Vec<_> = accents
+        .iter()
+        .unique()
+        .take(take)
+        .filter_map(|drop| accent::pitch_pattern(kana, *drop))
+        .collect();
+
+    if patterns.is_empty() {
+        return Err(RestError::NotFound);
+    }
+
+    Ok(svg_response(svg::pitch_diagram(&patterns)))
+}
+
+#[inline]
+fn svg_response(svg: String) -> HttpResponse {
+    HttpResponse::Ok().content_type("image/svg+xml").body(svg)
+}
@@ -0,0 +1,135 @@
+use std::fmt::Write;
+
+use japanese::accent::PitchPattern;
+
+/// Horizontal space of a single mora
+const MORA_WIDTH: f32 = 24.0;
+
+/// Height of a single pitch pattern
+const ROW_HEIGHT: f32 = 60.0;
+
+/// Vertical position of high pitched morae
+const HIGH_Y: f32 = 10.0;
+
+/// Vertical position of low pitched morae
+const LOW_Y: f32 = 30.0;
+
+/// Vertical position of the kana
+const TEXT_Y: f32 = 52.0;
+
+const DOT_RADIUS: f32 = 3.5;
+
+/// Renders a pitch diagram of all `patterns` below each other. The particle following the word
+/// is drawn as hollow dot
+pub(crate) fn pitch_diagram(patterns: &[PitchPattern]) -> String {
+    let max_morae = patterns
+        .iter()
+        .map(|i| i.morae.len())
+        .max()
+        .unwrap_or_default();
+    let width = (max_morae + 1) as f32 * MORA_WIDTH;
+    let height = patterns.len() as f32 * ROW_HEIGHT;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        width, height
+    );
+
+    for (row, pattern) in patterns.iter().enumerate() {
+        write_pattern(&mut svg, pattern, row as f32 * ROW_HEIGHT);
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Writes a single pitch pattern into `svg`, starting at `offset_y`
+fn write_pattern(svg: &mut String, pattern: &PitchPattern, offset_y: f32) {
+    let points: Vec<(f32, f32)> = pattern
+        .morae
+        .iter()
+        .map(|i| i.1)
+        .chain(std::iter::once(pattern.particle))
+        .enumerate()
+        .map(|(pos, high)| {
+            let x = pos as f32 * MORA_WIDTH + MORA_WIDTH / 2.0;
+            let y = offset_y + if high { HIGH_Y } else { LOW_Y };
+            (x, y)
+        })
+        .collect();
+
+    let (particle, morae) = points.split_last().unwrap();
+
+    svg.push_str(r#"<g style="fill:none;stroke:#000000;stroke-width:1.5">"#);
+    svg.push_str(r#"<polyline points=""#);
+    for (pos, (x, y)) in morae.iter().enumerate() {
+        if pos > 0 {
+            svg.push(' ');
+        }
+        write!(svg, "{},{}", x, y).unwrap();
+    }
+    svg.push_str(r#""/>"#);
+
+    let last = morae.last().unwrap();
+    write!(
+        svg,
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke-dasharray="2,2"/>"#,
+        last.0, last.1, particle.0, particle.1
+    )
+    .unwrap();
+    svg.push_str("</g>");
+
+    for (x, y) in morae {
+        write!(
+            svg,
+            r##"<circle cx="{}" cy="{}" r="{}" fill="#000000"/>"##,
+            x, y, DOT_RADIUS
+        )
+        .unwrap();
+    }
+    write!(
+        svg,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="#ffffff" stroke="#000000" stroke-width="1.5"/>"##,
+        particle.0, particle.1, DOT_RADIUS
+    )
+    .unwrap();
+
+    svg.push_str(r#"<g style="font-size:14px;text-anchor:middle">"#);
+    for ((mora, _), (x, _)) in pattern.morae.iter().zip(morae) {
+        write!(
+            svg,
+            r#"<text x="{}" y="{}">{}</text>"#,
+            x,
+            offset_y + TEXT_Y,
+            escape(mora)
+        )
+        .unwrap();
+    }
+    svg.push_str("</g>");
+}
+
+/// Escapes characters which aren't allowed in xml text
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pitch_diagram() {
+        let pattern = japanese::accent::pitch_pattern("はし", 2).unwrap();
+        let svg = pitch_diagram(&[pattern]);
+
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="72" height="60""#)
+        );
+        assert!(svg.contains(r#"<polyline points="12,30 36,10"/>"#));
+        // Odaka: the pitch drops on the particle
+        assert!(svg.contains(r#"<line x1="36" y1="10" x2="60" y2="30" stroke-dasharray="2,2"/>"#));
+        assert!(svg.contains(r#"<text x="36" y="52">し</text>"#));
+    }
+}
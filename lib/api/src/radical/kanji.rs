@@ -6,7 +6,8 @@ use types::api::radical::find_kanji::{Request, Response};
 pub async fn kanji_by_radicals(
     payload: Json<Request>,
 ) -> Result<Json<Response>, actix_web::Error> {
-    let kanji_retr = resources::get().kanji();
+    let generation = search::generation::get();
+    let kanji_retr = generation.resources().kanji();
 
     let mut possible_radicals: HashSet<char> = HashSet::new();
     let mut kanji_res: HashMap<i32, Vec<char>> = HashMap::new();
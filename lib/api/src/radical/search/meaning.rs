@@ -2,32 +2,39 @@ use error::Error;
 use itertools::Itertools;
 use japanese::JapaneseExt;
 use types::jotoba::languages::Language;
-use search::engine::{self, SearchTask};
+use search::{
+    engine::{self, SearchTask},
+    generation::Generation,
+};
 
-pub fn search(query: &str, language: Language) -> Vec<char> {
+pub fn search(generation: &Generation, query: &str, language: Language) -> Vec<char> {
     if query.len() < 2 {
         return vec![];
     }
 
-    let mut res = search::radical::search(query);
+    let mut res = search::radical::search(generation, query);
 
     if res.len() > 4 {
         return res;
     }
 
     if japanese::guessing::could_be_romaji(query) {
-        res.extend(super::jp_search::search(&query.to_hiragana()));
+        res.extend(super::jp_search::search(generation, &query.to_hiragana()));
     } else {
-        res.extend(word_search(query, language).unwrap_or_default());
+        res.extend(word_search(generation, query, language).unwrap_or_default());
     }
 
     res
 }
 
 /// Does a kana word-search and returns some likely radicals for the given query
-fn word_search(query: &str, language: Language) -> Result<Vec<char>, Error> {
+fn word_search(
+    generation: &Generation,
+    query: &str,
+    language: Language,
+) -> Result<Vec<char>, Error> {
     let mut search_task: SearchTask<engine::words::foreign::Engine> =
-        SearchTask::with_language(&query, language)
+        SearchTask::with_language(generation, query, language)
             .limit(3)
             .threshold(0.8f32);
 
@@ -35,7 +42,7 @@ fn word_search(query: &str, language: Language) -> Result<Vec<char>, Error> {
         search::word::order::foreign_search_order(word, rel, q_str, lang.unwrap(), language)
     });
 
-    let kanji_retr = resources::get().kanji();
+    let kanji_retr = generation.resources().kanji();
     let res = search_task
         .find()?
         .item_iter()
@@ -50,10 +57,9 @@ fn word_search(query: &str, language: Language) -> Result<Vec<char>, Error> {
         })
         .flatten()
         .unique()
-        .filter_map(|kanji| kanji_retr.by_literal(kanji).and_then(|i| i.parts.as_ref()))
+        .filter_map(|kanji| kanji_retr.by_literal(kanji).and_then(|i| i.parts.clone()))
         .flatten()
         .unique()
-        .copied()
         .take(10)
         .collect::<Vec<_>>();
 
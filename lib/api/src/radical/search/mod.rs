@@ -26,10 +26,11 @@ pub async fn search_radical(
         .and_then(|i| Language::from_str(i.value()).ok())
         .unwrap_or_default();
 
+    let generation = search::generation::get();
     let res = if !payload.query.is_japanese() {
-        meaning::search(&payload.query, user_lang)
+        meaning::search(&generation, &payload.query, user_lang)
     } else {
-        jp_search::search(&payload.query)
+        jp_search::search(&generation, &payload.query)
     };
 
     if res.is_empty() {
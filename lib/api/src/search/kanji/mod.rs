@@ -1,4 +1,5 @@
-use actix_web::web::{self, Json};
+use actix_web::web::{self, Data, Json};
+use config::Config;
 use types::{
     api::search::kanji::{Kanji, Response},
     jotoba::search::QueryType,
@@ -7,16 +8,26 @@ use types::{
 use super::{Result, SearchRequest};
 
 /// Do a kanji search via API
-pub async fn kanji_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
-    let query = super::parse_query(payload, QueryType::Kanji)?;
-    let result = web::block(move || search::kanji::search(&query))
-        .await??
-        .items;
-    Ok(Json(to_response(result)))
+pub async fn kanji_search(
+    payload: Json<SearchRequest>,
+    config: Data<Config>,
+) -> Result<Json<Response>> {
+    let query = super::parse_query(payload, QueryType::Kanji, &config)?;
+    let page_size = query.settings.kanji_page_size;
+    let q = query.clone();
+    let generation = search::generation::get();
+    let result = web::block(move || search::kanji::search(&generation, &q)).await??;
+
+    let mut response = to_response(result.items);
+    response.page_info = super::page_info(&query, result.total_items, page_size);
+    Ok(Json(response))
 }
 
 #[inline]
 fn to_response(items: Vec<search::kanji::result::Item>) -> Response {
     let kanji = items.into_iter().map(|i| Kanji::from(&i.kanji)).collect();
-    Response { kanji }
+    Response {
+        kanji,
+        page_info: Default::default(),
+    }
 }
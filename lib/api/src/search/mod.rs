@@ -4,26 +4,48 @@ pub mod sentence;
 pub mod word;
 
 use actix_web::web::Json;
+use config::Config;
 use error::api_error::RestError;
 use search::{
     query::{Query, UserSettings},
     query_parser::QueryParser,
 };
-use types::{api::search::SearchRequest, jotoba::search::QueryType};
+use types::{
+    api::search::{PageInfo, SearchRequest},
+    jotoba::search::QueryType,
+};
 
 pub type Result<T> = std::result::Result<T, RestError>;
 
-pub(crate) fn parse_query(payload: Json<SearchRequest>, q_type: QueryType) -> Result<Query> {
-    let settings = UserSettings {
+pub(crate) fn parse_query(
+    payload: Json<SearchRequest>,
+    q_type: QueryType,
+    config: &Config,
+) -> Result<Query> {
+    let mut settings = UserSettings {
         user_lang: payload.language,
         show_english: !payload.no_english,
         ..UserSettings::default()
     };
 
+    if let Some(page_size) = payload.page_size {
+        let page_size = page_size.clamp(1, config.get_api_max_page_size());
+        settings.page_size = page_size;
+        settings.kanji_page_size = page_size;
+    }
+
+    let page = payload.page.unwrap_or(1).max(1) as usize;
+
     let q_str = payload.query_str.clone();
-    let query = QueryParser::new(q_str, q_type, settings, 0, 0, true, None)
+    let query = QueryParser::new(q_str, q_type, settings, page, 0, true, None)
         .parse()
         .ok_or(RestError::BadRequest)?;
 
     Ok(query)
 }
+
+/// Returns the `PageInfo` of a search result with `total` items for `query`
+#[inline]
+pub(crate) fn page_info(query: &Query, total: usize, page_size: u32) -> PageInfo {
+    PageInfo::new(total as u32, query.page as u32, page_size)
+}
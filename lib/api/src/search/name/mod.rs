@@ -1,11 +1,22 @@
-use actix_web::web::{self, Json};
+use actix_web::web::{self, Data, Json};
+use config::Config;
 use types::{api::search::name::Response, jotoba::search::QueryType};
 
 use super::{Result, SearchRequest};
 
 /// Do a name search via API
-pub async fn name_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
-    let query = super::parse_query(payload, QueryType::Kanji)?;
-    let result = web::block(move || search::name::search(&query)).await??;
-    Ok(Json(result.items.into()))
+pub async fn name_search(
+    payload: Json<SearchRequest>,
+    config: Data<Config>,
+) -> Result<Json<Response>> {
+    let query = super::parse_query(payload, QueryType::Kanji, &config)?;
+    let page_size = query.settings.page_size;
+    let q = query.clone();
+    let generation = search::generation::get();
+    let result = web::block(move || search::name::search(&generation, &q)).await??;
+
+    let total = result.total_count as usize;
+    let mut response: Response = result.items.iter().collect::<Vec<_>>().into();
+    response.page_info = super::page_info(&query, total, page_size);
+    Ok(Json(response))
 }
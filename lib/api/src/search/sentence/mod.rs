@@ -1,4 +1,5 @@
-use actix_web::web::{self, Json};
+use actix_web::web::{self, Data, Json};
+use config::Config;
 use types::{
     api::search::sentence::{Response, Sentence},
     jotoba::search::QueryType,
@@ -7,23 +8,33 @@ use types::{
 use super::{Result, SearchRequest};
 
 /// Do a Sentence search via API
-pub async fn sentence_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
-    let query = super::parse_query(payload, QueryType::Kanji)?;
+pub async fn sentence_search(
+    payload: Json<SearchRequest>,
+    config: Data<Config>,
+) -> Result<Json<Response>> {
+    let query = super::parse_query(payload, QueryType::Kanji, &config)?;
+    let page_size = query.settings.page_size;
+    let q = query.clone();
+    let generation = search::generation::get();
+    let result = web::block(move || search::sentence::search(&generation, &q)).await??;
 
-    let result = web::block(move || search::sentence::search(&query))
-        .await??
+    let total = result.len;
+    let sentences = result
         .items
         .into_iter()
         .map(|i| search_to_sentence(i.sentence))
         .collect::<Vec<_>>();
 
-    Ok(Json(result.into()))
+    let mut response: Response = sentences.into();
+    response.page_info = super::page_info(&query, total, page_size);
+    Ok(Json(response))
 }
 
 #[inline]
-fn search_to_sentence(sentence: search::sentence::result::Sentence) -> Sentence {
+pub(crate) fn search_to_sentence(sentence: search::sentence::result::Sentence) -> Sentence {
     Sentence {
         eng: sentence.get_english().map(|i| i.to_owned()),
+        romaji: Some(sentence.get_romaji()),
         content: sentence.content,
         furigana: sentence.furigana,
         translation: sentence.translation,
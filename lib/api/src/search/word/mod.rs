@@ -1,11 +1,21 @@
 use super::{Result, SearchRequest};
-use actix_web::web::{self, Json};
+use actix_web::web::{self, Data, Json};
+use config::Config;
 use types::{api::search::word::Response, jotoba::search::QueryType};
 
 /// Do a word search via API
-pub async fn word_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
-    let query = super::parse_query(payload, QueryType::Words)?;
-    let result = web::block(move || search::word::search(&query)).await??;
-    let response: Response = result.get_items().into();
+pub async fn word_search(
+    payload: Json<SearchRequest>,
+    config: Data<Config>,
+) -> Result<Json<Response>> {
+    let query = super::parse_query(payload, QueryType::Words, &config)?;
+    let page_size = query.settings.page_size;
+    let q = query.clone();
+    let generation = search::generation::get();
+    let result = web::block(move || search::word::search(&generation, &q)).await??;
+
+    let mut response: Response = result.get_items().into();
+    response.page_info = super::page_info(&query, result.count, page_size);
+    response.corrections = result.corrections;
     Ok(Json(response))
 }
@@ -28,11 +28,23 @@ pub struct ServerConfig {
     pub listen_address: String,
     pub storage_data: Option<String>,
     pub radical_map: Option<String>,
+    pub stroke_data: Option<String>,
+    pub stroke_paths: Option<String>,
     pub sentences: Option<String>,
     pub img_upload_dir: Option<String>,
     pub tess_data: Option<String>,
+    /// External OCR command. Gets split at whitespace into the program and its arguments, so
+    /// arguments can't contain whitespace or quotes. Use a wrapper script for those
+    pub ocr_command: Option<String>,
+    /// Seconds an external OCR command can run before it gets killed
+    pub ocr_timeout: Option<u64>,
     pub news_folder: Option<String>,
     pub debug_mode: Option<bool>,
+    /// Token required for admin endpoints. Admin endpoints are disabled if not set
+    pub admin_token: Option<String>,
+    /// Folder containing custom grammar rules for the sentence reader. The built-in rules are
+    /// used if not set
+    pub grammar_rules: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -47,6 +59,7 @@ pub struct SearchConfig {
     pub indexes_source: Option<String>,
     pub report_queries_after: Option<u64>,
     pub search_timeout: Option<u64>,
+    pub api_max_page_size: Option<u32>,
 }
 
 impl Config {
@@ -87,6 +100,14 @@ impl Config {
             .unwrap_or("./suggestions")
     }
 
+    /// Returns the configured maximum page size for API searches or its default value `100`
+    pub fn get_api_max_page_size(&self) -> u32 {
+        self.search
+            .as_ref()
+            .and_then(|i| i.api_max_page_size)
+            .unwrap_or(100)
+    }
+
     /// Returns the configured query report timeout
     pub fn get_query_report_timeout(&self) -> Duration {
         let timeout = self
@@ -125,6 +146,25 @@ impl Config {
             .unwrap_or_else(|| ServerConfig::default().radical_map.unwrap())
     }
 
+    /// Returns the configured (or default) path for the stroke data used by handwriting
+    /// recognition
+    pub fn get_stroke_data_path(&self) -> String {
+        self.server
+            .stroke_data
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| ServerConfig::default().stroke_data.unwrap())
+    }
+
+    /// Returns the configured (or default) path for the kanji stroke paths
+    pub fn get_stroke_paths_path(&self) -> String {
+        self.server
+            .stroke_paths
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| ServerConfig::default().stroke_paths.unwrap())
+    }
+
     /// Returns the configured (or default) path for the radical map
     pub fn get_img_scan_upload_path(&self) -> String {
         self.server
@@ -150,10 +190,16 @@ impl Default for ServerConfig {
             sentences: Some(String::from("./resources/sentences.bin")),
             storage_data: Some(String::from("./resources/storage_data")),
             radical_map: Some(String::from("./resources/radical_map")),
+            stroke_data: Some(String::from("./resources/stroke_data")),
+            stroke_paths: Some(String::from("./resources/stroke_paths")),
             img_upload_dir: Some(String::from("./img_scan_tmp")),
             tess_data: None,
+            ocr_command: None,
+            ocr_timeout: None,
             news_folder: Some(String::from("./news")),
             debug_mode: Some(false),
+            admin_token: None,
+            grammar_rules: None,
         }
     }
 }
@@ -174,6 +220,27 @@ impl ServerConfig {
     pub fn get_news_folder(&self) -> &str {
         self.news_folder.as_deref().unwrap_or("./news")
     }
+
+    /// Returns the configured admin token or `None` if admin endpoints are disabled
+    pub fn get_admin_token(&self) -> Option<&str> {
+        self.admin_token.as_deref().filter(|i| !i.is_empty())
+    }
+
+    /// Returns the configured external OCR command or `None` if there is none. The command gets
+    /// the image passed via stdin and has to write the recognized text to stdout
+    pub fn get_ocr_command(&self) -> Option<&str> {
+        self.ocr_command.as_deref().filter(|i| !i.trim().is_empty())
+    }
+
+    /// Returns the configured timeout for the external OCR command or the default value `10s`
+    pub fn get_ocr_timeout(&self) -> Duration {
+        Duration::from_secs(self.ocr_timeout.unwrap_or(10))
+    }
+
+    /// Returns the configured folder containing custom grammar rules
+    pub fn get_grammar_rules(&self) -> Option<&str> {
+        self.grammar_rules.as_deref().filter(|i| !i.is_empty())
+    }
 }
 
 impl Config {
@@ -48,6 +48,15 @@ pub enum RestError {
     #[error("No text found")]
     NoTextFound,
 
+    #[error("Image scanning is not available")]
+    ScanUnavailable,
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Conflict")]
+    Conflict,
+
     #[error("missing {0:?}")]
     Missing(Origin),
 }
@@ -69,7 +78,10 @@ impl RestError {
             Self::Timeout => "Timeout".to_string(),
             Self::IoError => "IoError".to_string(),
             Self::NoTextFound => "NoTextFound".to_string(),
+            Self::ScanUnavailable => "ScanUnavailable".to_string(),
             Self::FormatNotSupported => "FormatNotSupported".to_string(),
+            Self::Unauthorized => "Unauthorized".to_string(),
+            Self::Conflict => "Conflict".to_string(),
             _ => "InternalError".to_string(),
         }
     }
@@ -85,6 +97,9 @@ impl ResponseError for RestError {
             Self::Timeout => StatusCode::REQUEST_TIMEOUT,
             Self::FormatNotSupported => StatusCode::BAD_REQUEST,
             Self::NoTextFound => StatusCode::SEE_OTHER,
+            Self::ScanUnavailable => StatusCode::NOT_IMPLEMENTED,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Conflict => StatusCode::CONFLICT,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -4,6 +4,7 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use config::Config;
 use localization::TranslationDict;
 use search::{
+    generation::{self, Generation},
     query::{Query, UserSettings},
     sentence::{self, result::SentenceResult},
     word::{self, result::WordResult},
@@ -30,10 +31,12 @@ pub async fn direct_ep(
     let (stype, id) = h_query.into_inner();
     let query_type = QueryType::try_from(stype).map_err(|_| Error::BadRequest)?;
 
+    let generation = generation::get();
+
     let result_data = match query_type {
-        QueryType::Words => find_direct_word(&id, &settings).await,
-        QueryType::Names => find_direct_name(&id).await,
-        QueryType::Sentences => find_direct_sentence(&id, &settings).await,
+        QueryType::Words => find_direct_word(&generation, &id, &settings).await,
+        QueryType::Names => find_direct_name(&generation, &id).await,
+        QueryType::Sentences => find_direct_sentence(&generation, &id, &settings).await,
         QueryType::Kanji => return Ok(redirect_home()),
     };
 
@@ -46,7 +49,7 @@ pub async fn direct_ep(
 
     let query = Query::default();
     let mut base_data = BaseData::new(&locale_dict, settings, &config.asset_hash, &config)
-        .with_search_result(&query, result_data.unwrap(), None);
+        .with_search_result(&query, result_data.unwrap(), None, generation);
 
     set_og_tag(&mut base_data, query_type);
 
@@ -84,14 +87,19 @@ fn search_res_val(res: &SearchResult) -> Option<String> {
 }
 
 /// Find direct word
-pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<ResultData, Error> {
+pub async fn find_direct_word(
+    generation: &Generation,
+    id: &str,
+    settings: &UserSettings,
+) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_name = resources::get()
+    let res_name = generation
+        .resources()
         .words()
         .by_sequence(sequence_id)
         .ok_or(web_error::Error::NotFound)?
-        .clone();
+        .into_owned();
 
     let mut results = vec![res_name];
 
@@ -99,7 +107,7 @@ pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<Resul
     let show_english = !results[0].has_language(settings.user_lang, false) || settings.show_english;
     filter_languages(results.iter_mut(), settings.user_lang, show_english);
 
-    let kanji = search::word::kanji::load_word_kanji_info(&results)
+    let kanji = search::word::kanji::load_word_kanji_info(generation, &results)
         .into_iter()
         .map(|k| word::result::Item::Kanji(k));
 
@@ -117,26 +125,35 @@ pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<Resul
         sentence_parts: None,
         sentence_index: 0,
         searched_query: String::new(),
+        romaji_kana: None,
+        corrections: vec![],
     }))
 }
 
 /// Find direct name
-pub async fn find_direct_name(id: &str) -> Result<ResultData, Error> {
+pub async fn find_direct_name(generation: &Generation, id: &str) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_word = resources::get()
+    let res_word = generation
+        .resources()
         .names()
         .by_sequence(sequence_id)
-        .ok_or(web_error::Error::NotFound)?;
+        .ok_or(web_error::Error::NotFound)?
+        .into_owned();
 
     Ok(ResultData::Name(vec![res_word]))
 }
 
 /// Find direct sentence
-pub async fn find_direct_sentence(id: &str, settings: &UserSettings) -> Result<ResultData, Error> {
+pub async fn find_direct_sentence(
+    generation: &Generation,
+    id: &str,
+    settings: &UserSettings,
+) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_sentence = resources::get()
+    let res_sentence = generation
+        .resources()
         .sentences()
         .by_id(sequence_id)
         .ok_or(web_error::Error::NotFound)?
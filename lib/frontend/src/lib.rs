@@ -19,7 +19,7 @@ mod url_query;
 pub mod user_settings;
 pub mod web_error;
 
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use config::Config;
 use localization::{
@@ -30,7 +30,7 @@ use localization::{
 use og_tags::TagKeyName;
 use pagination::Pagination;
 use resources::news::NewsEntry;
-use search::{query::Query, sentence::result::SentenceResult};
+use search::{generation::Generation, query::Query, sentence::result::SentenceResult};
 
 use search::{kanji::result::Item as KanjiItem, query::UserSettings, word::result::WordResult};
 use search_help::SearchHelp;
@@ -64,6 +64,9 @@ pub struct SearchResult<'a> {
     pub query: &'a Query,
     pub result: ResultData,
     pub search_help: Option<SearchHelp>,
+    /// The generation the result has been searched in. Data referenced by the result has to be
+    /// looked up in it too
+    pub generation: Arc<Generation>,
 }
 
 /// The particular search result items
@@ -71,7 +74,7 @@ pub struct SearchResult<'a> {
 pub enum ResultData {
     Word(WordResult),
     KanjiInfo(Vec<KanjiItem>),
-    Name(Vec<&'static Name>),
+    Name(Vec<Name>),
     Sentence(SentenceResult),
 }
 
@@ -172,11 +175,13 @@ impl<'a> BaseData<'a> {
         query: &'a Query,
         result: ResultData,
         search_help: Option<SearchHelp>,
+        generation: Arc<Generation>,
     ) -> Self {
         let search_result = SearchResult {
             query,
             result,
             search_help,
+            generation,
         };
         self.with_site(Site::SearchResult(search_result))
     }
@@ -423,6 +428,22 @@ impl<'a> BaseData<'a> {
             .into()
     }
 
+    /// Same as `gt_search_link` but inserts a comma separated list of links to all `values`
+    #[inline]
+    pub fn gt_search_link_list<T: Translatable, V: Display + Sized + Clone>(
+        &self,
+        t: T,
+        values: &[V],
+    ) -> UnescapedString {
+        let links = values
+            .iter()
+            .map(format_search_link)
+            .collect::<Vec<_>>()
+            .join(", ");
+        t.gettext_fmt(&self.dict, &[links], Some(self.get_lang()))
+            .into()
+    }
+
     #[inline]
     pub fn ngt_search_links<T: TranslatablePlural, V: Display + Sized + Clone>(
         &self,
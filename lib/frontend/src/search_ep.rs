@@ -18,6 +18,7 @@ use crate::{
 use config::Config;
 use search::{
     self,
+    generation::{self, Generation},
     query::{Query, UserSettings},
 };
 
@@ -72,10 +73,20 @@ async fn search(
 
     let search_timeout = config.get_search_timeout();
 
+    // The whole request uses the same generation, even if the resources get reloaded meanwhile
+    let generation = generation::get();
+
     // Log search duration if too long and available
     let search_result = timeout(
         search_timeout,
-        do_search(query.type_, &locale_dict, settings, &query, &config),
+        do_search(
+            query.type_,
+            &locale_dict,
+            settings,
+            &query,
+            &config,
+            generation,
+        ),
     )
     .await
     .map_err(|_| {
@@ -99,40 +110,51 @@ async fn do_search<'a>(
     settings: UserSettings,
     query: &'a Query,
     config: &'a Config,
+    generation: Arc<Generation>,
 ) -> Result<BaseData<'a>, web_error::Error> {
     let mut base_data = BaseData::new(locale_dict, settings, &config.asset_hash, &config);
 
+    let res_generation = generation.clone();
     let result_data = match querytype {
-        QueryType::Kanji => kanji_search(&mut base_data, &query).await,
-        QueryType::Sentences => sentence_search(&mut base_data, &query).await,
-        QueryType::Names => name_search(&mut base_data, &query).await,
-        QueryType::Words => word_search(&mut base_data, &query).await,
+        QueryType::Kanji => kanji_search(&mut base_data, &query, res_generation).await,
+        QueryType::Sentences => sentence_search(&mut base_data, &query, res_generation).await,
+        QueryType::Names => name_search(&mut base_data, &query, res_generation).await,
+        QueryType::Words => word_search(&mut base_data, &query, res_generation).await,
     }?;
 
     let mut search_help: Option<SearchHelp> = None;
     if result_data.is_empty() {
         let query = query.to_owned();
-        search_help = web::block(move || build_search_help(querytype, &query)).await?;
+        let generation = generation.clone();
+        search_help = web::block(move || build_search_help(&generation, querytype, &query)).await?;
     }
 
-    Ok(base_data.with_search_result(query, result_data, search_help))
+    Ok(base_data.with_search_result(query, result_data, search_help, generation))
 }
 
 type SResult = Result<ResultData, web_error::Error>;
 
 /// Perform a sentence search
-async fn sentence_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn sentence_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    generation: Arc<Generation>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || search::sentence::search(&q)).await??;
+    let result = web::block(move || search::sentence::search(&generation, &q)).await??;
 
     base_data.with_pages(result.len as u32, query.page as u32);
     Ok(ResultData::Sentence(result))
 }
 
 /// Perform a kanji search
-async fn kanji_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn kanji_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    generation: Arc<Generation>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || search::kanji::search(&q)).await??;
+    let result = web::block(move || search::kanji::search(&generation, &q)).await??;
     base_data.with_cust_pages(
         result.total_items as u32,
         query.page as u32,
@@ -143,38 +165,52 @@ async fn kanji_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SRe
 }
 
 /// Perform a name search
-async fn name_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn name_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    generation: Arc<Generation>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || search::name::search(&q)).await??;
+    let result = web::block(move || search::name::search(&generation, &q)).await??;
 
     base_data.with_pages(result.total_count, query.page as u32);
     Ok(ResultData::Name(result.items))
 }
 
 /// Perform a word search
-async fn word_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn word_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    generation: Arc<Generation>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || search::word::search(&q)).await??;
+    let result = web::block(move || search::word::search(&generation, &q)).await??;
 
     base_data.with_pages(result.count as u32, query.page as u32);
     Ok(ResultData::Word(result))
 }
 
 /// Build a [`SearchHelp`] in for cases without any search results
-fn build_search_help(querytype: QueryType, query: &Query) -> Option<SearchHelp> {
+fn build_search_help(
+    generation: &Generation,
+    querytype: QueryType,
+    query: &Query,
+) -> Option<SearchHelp> {
     let mut help = SearchHelp::default();
 
     for qt in QueryType::iterate().filter(|i| *i != querytype) {
         match qt {
-            QueryType::Kanji => help.kanji = search::kanji::guess_result(query),
-            QueryType::Sentences => help.sentences = search::sentence::guess_result(query),
-            QueryType::Names => help.names = search::name::guess_result(query),
-            QueryType::Words => help.words = search::word::guess_result(query),
+            QueryType::Kanji => help.kanji = search::kanji::guess_result(generation, query),
+            QueryType::Sentences => {
+                help.sentences = search::sentence::guess_result(generation, query)
+            }
+            QueryType::Names => help.names = search::name::guess_result(generation, query),
+            QueryType::Words => help.words = search::word::guess_result(generation, query),
         }
     }
 
     if querytype == QueryType::Words {
-        help.other_langs = search::word::guess_inp_language(query);
+        help.other_langs = search::word::guess_inp_language(generation, query);
     }
 
     (!help.is_empty()).then(|| help)
@@ -1,4 +1,6 @@
-use japanese::furigana::SentencePartRef;
+use std::borrow::Cow;
+
+use search::generation::Generation;
 use types::jotoba::{
     languages::Language,
     words::{filter_languages, sense::Sense, Word},
@@ -6,6 +8,7 @@ use types::jotoba::{
 
 /// Returns a list of all collocations of a word
 pub fn get_collocations(
+    generation: &Generation,
     word: &Word,
     language: Language,
     show_english: bool,
@@ -14,7 +17,7 @@ pub fn get_collocations(
         return vec![];
     }
 
-    let word_storage = resources::get().words();
+    let word_storage = generation.resources().words();
 
     let mut words = word
         .collocations
@@ -22,7 +25,7 @@ pub fn get_collocations(
         .unwrap()
         .iter()
         .filter_map(|i| word_storage.by_sequence(*i))
-        .cloned()
+        .map(Cow::into_owned)
         .collect::<Vec<_>>();
 
     filter_languages(words.iter_mut(), language, show_english);
@@ -49,25 +52,36 @@ pub fn get_collocations(
 
 /// Returns the transive verion of `word`
 #[inline]
-pub fn get_transitive_counterpart(word: &Word) -> Option<Word> {
+pub fn get_transitive_counterpart(generation: &Generation, word: &Word) -> Option<Word> {
     let seq_id = word.transive_verion.as_ref()?;
-    resources::get().words().by_sequence(*seq_id).cloned()
+    generation
+        .resources()
+        .words()
+        .by_sequence(*seq_id)
+        .map(Cow::into_owned)
 }
 
 /// Returns the intransive verion of `word`
 #[inline]
-pub fn get_intransitive_counterpart(word: &Word) -> Option<Word> {
+pub fn get_intransitive_counterpart(generation: &Generation, word: &Word) -> Option<Word> {
     let seq_id = word.intransive_verion.as_ref()?;
-    resources::get().words().by_sequence(*seq_id).cloned()
+    generation
+        .resources()
+        .words()
+        .by_sequence(*seq_id)
+        .map(Cow::into_owned)
 }
 
 /// Returns an example sentences of a `sense` if existing.
-/// tries to use a sentence written in `language` or falls back to english
+/// tries to use a sentence written in `language` or falls back to english. Returns the
+/// sentence's encoded furigana along with its translation
 pub fn ext_sentence(
+    generation: &Generation,
     sense: &Sense,
     language: &Language,
-) -> Option<(Vec<SentencePartRef<'static>>, &'static str)> {
-    let sentence = resources::get()
+) -> Option<(String, String)> {
+    let sentence = generation
+        .resources()
         .sentences()
         .by_id(sense.example_sentence?)?;
 
@@ -75,7 +89,5 @@ pub fn ext_sentence(
         .get_translations(*language)
         .or_else(|| sentence.get_translations(Language::English))?;
 
-    let furigana = japanese::furigana::from_str(&sentence.furigana).collect::<Vec<_>>();
-
-    Some((furigana, translation))
+    Some((sentence.furigana.clone(), translation.to_string()))
 }
@@ -55,6 +55,11 @@ pub(super) fn parse(request: &HttpRequest) -> UserSettings {
         .and_then(|i| Some(i.value() == "true"))
         .unwrap_or_else(|| UserSettings::default().sentence_furigana);
 
+    let show_romaji = request
+        .cookie("show_romaji")
+        .and_then(|i| Some(i.value() == "true"))
+        .unwrap_or_else(|| UserSettings::default().show_romaji);
+
     UserSettings {
         user_lang,
         show_english,
@@ -65,6 +70,7 @@ pub(super) fn parse(request: &HttpRequest) -> UserSettings {
         kanji_page_size: items_per_kanji_page,
         show_example_sentences: example_sentences_enabled,
         sentence_furigana,
+        show_romaji,
         ..Default::default()
     }
 }
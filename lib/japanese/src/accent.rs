@@ -75,6 +75,42 @@ pub fn calc_pitch(kana_word: &str, drop: i32) -> Option<Vec<(&str, bool)>> {
     ]);
 }
 
+/// Pitch of each mora of a word and of a particle following it
+#[derive(Clone, Debug, PartialEq)]
+pub struct PitchPattern<'a> {
+    /// Each mora of the word with its pitch (true = high)
+    pub morae: Vec<(&'a str, bool)>,
+    /// Pitch of a particle following the word. This allows us to distinguish between odaka and
+    /// heiban patterns
+    pub particle: bool,
+}
+
+/// Returns the pitch of each mora of `kana_word` with the pitch dropping after the mora at
+/// position `drop`. A `drop` of 0 represents a heiban pattern. Returns `None` if the word is empty
+/// or `drop` is behind its last mora
+pub fn pitch_pattern(kana_word: &str, drop: u8) -> Option<PitchPattern<'_>> {
+    let morae = split_kana(kana_word).collect::<Vec<_>>();
+    let drop = drop as usize;
+    if morae.is_empty() || drop > morae.len() {
+        return None;
+    }
+
+    let is_high = |pos: usize| match drop {
+        0 => pos > 0,
+        1 => pos == 0,
+        _ => pos > 0 && pos < drop,
+    };
+
+    let particle = is_high(morae.len());
+    let morae = morae
+        .into_iter()
+        .enumerate()
+        .map(|(pos, mora)| (mora, is_high(pos)))
+        .collect();
+
+    Some(PitchPattern { morae, particle })
+}
+
 /// Returns an iterator over all kana characters. The reason for Item to be &str is that 'きゅう'
 /// gets split up into ["きゅ", "う"] which can't be represented with only one char
 pub fn split_kana(inp: &str) -> impl Iterator<Item = &str> {
@@ -110,6 +146,38 @@ mod test {
         assert!("ュ".is_small_kana());
     }
 
+    #[test]
+    fn test_pitch_pattern() {
+        // Heiban and odaka only differ in the particle
+        let heiban = pitch_pattern("はし", 0).unwrap();
+        assert_eq!(heiban.morae, vec![("は", false), ("し", true)]);
+        assert!(heiban.particle);
+
+        let odaka = pitch_pattern("はし", 2).unwrap();
+        assert_eq!(odaka.morae, vec![("は", false), ("し", true)]);
+        assert!(!odaka.particle);
+
+        let atamadaka = pitch_pattern("きょうかしょ", 1).unwrap();
+        assert_eq!(
+            atamadaka.morae,
+            vec![
+                ("きょ", true),
+                ("う", false),
+                ("か", false),
+                ("しょ", false)
+            ]
+        );
+
+        let nakadaka = pitch_pattern("たまご", 2).unwrap();
+        assert_eq!(
+            nakadaka.morae,
+            vec![("た", false), ("ま", true), ("ご", false)]
+        );
+
+        assert_eq!(pitch_pattern("はし", 3), None);
+        assert_eq!(pitch_pattern("", 0), None);
+    }
+
     #[test]
     fn test_split_kana_small() {
         let inp = "きょうかしょ";
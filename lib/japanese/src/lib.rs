@@ -2,6 +2,7 @@ pub mod accent;
 pub mod furigana;
 pub mod guessing;
 pub mod radicals;
+pub mod romaji;
 
 use itertools::Itertools;
 use std::iter;
@@ -163,7 +164,7 @@ impl JapaneseExt for char {
 
     #[inline]
     fn to_hiragana(&self) -> String {
-        romaji::RomajiExt::to_hiragana(self.to_string().as_str())
+        ::romaji::RomajiExt::to_hiragana(self.to_string().as_str())
     }
 
     #[inline]
@@ -296,7 +297,7 @@ impl JapaneseExt for str {
 
     #[inline]
     fn to_hiragana(&self) -> String {
-        romaji::RomajiExt::to_hiragana(self)
+        ::romaji::RomajiExt::to_hiragana(self)
     }
 
     #[inline]
@@ -0,0 +1,504 @@
+/// Converts romanized japanese into hiragana. Hepburn, Kunrei-shiki and Nihon-shiki spellings are
+/// supported and can be mixed. Returns `None` if `inp` contains anything which can't be read as
+/// romaji.
+///
+/// Example: "shinjitakunakatta" -> "しんじたくなかった"
+/// Example: "tōkyō" -> "とうきょう"
+pub fn to_hiragana(inp: &str) -> Option<String> {
+    let inp = normalize(inp);
+    let chars: Vec<char> = inp.chars().collect();
+    let mut out = String::with_capacity(inp.len() * 3);
+
+    let mut pos = 0;
+    while pos < chars.len() {
+        let curr = chars[pos];
+        let next = chars.get(pos + 1).copied();
+
+        if SKIP_CHARS.contains(&curr) {
+            pos += 1;
+            continue;
+        }
+
+        if curr == '-' {
+            out.push('ー');
+            pos += 1;
+            continue;
+        }
+
+        if curr == 'n' && !starts_n_syllable(next) {
+            out.push('ん');
+            pos += 1;
+
+            // Skip explicit separators of ん (n', nn)
+            if next == Some('\'')
+                || (next == Some('n') && !starts_n_syllable(chars.get(pos + 1).copied()))
+            {
+                pos += 1;
+            }
+            continue;
+        }
+
+        // Hepburn writes ん as 'm' in front of labials (eg. shimbun)
+        if curr == 'm' && matches!(next, Some('b' | 'p' | 'm')) {
+            out.push('ん');
+            pos += 1;
+            continue;
+        }
+
+        // Doubled consonants and 'tch' are written with a small tsu
+        if (next == Some(curr) && !is_vowel(curr)) || (curr == 't' && next == Some('c')) {
+            out.push('っ');
+            pos += 1;
+            continue;
+        }
+
+        let (kana, len) = (1..=3)
+            .rev()
+            .filter(|len| pos + len <= chars.len())
+            .find_map(|len| {
+                let syllable: String = chars[pos..pos + len].iter().collect();
+                syllable_to_kana(&syllable).map(|kana| (kana, len))
+            })?;
+
+        out.push_str(kana);
+        pos += len;
+    }
+
+    Some(out)
+}
+
+/// Converts kana into modified Hepburn romaji. Long vowels are written with macrons and ん is
+/// separated by an apostrophe if a vowel or 'y' follows. Characters which aren't kana are kept
+/// as they are.
+///
+/// Example: "しんぶん" -> "shinbun"
+/// Example: "トーキョー" -> "tōkyō"
+pub fn to_romaji(inp: &str) -> String {
+    let chars: Vec<char> = inp.chars().map(katakana_to_hiragana).collect();
+    let mut out = String::with_capacity(inp.len());
+
+    for (pos, &curr) in chars.iter().enumerate() {
+        let next = chars.get(pos + 1).copied();
+
+        match curr {
+            'っ' => {
+                // Double the first consonant of the following syllable
+                if let Some(c) = next
+                    .and_then(kana_to_syllable)
+                    .and_then(|i| i.chars().next())
+                {
+                    if !is_vowel(c) {
+                        out.push(if c == 'c' { 't' } else { c });
+                    }
+                }
+            }
+            'ん' => {
+                out.push('n');
+                let next_syllable = next.and_then(kana_to_syllable);
+                if matches!(next_syllable.and_then(|i| i.chars().next()), Some(c) if is_vowel(c) || c == 'y')
+                {
+                    out.push('\'');
+                }
+            }
+            'ー' => lengthen_last_vowel(&mut out),
+            'ゃ' | 'ゅ' | 'ょ' => {
+                let vowel = small_kana_vowel(curr);
+                // Combine with a preceeding i-row syllable (eg. きゃ -> kya, しゃ -> sha)
+                if out.ends_with('i') {
+                    out.pop();
+                    if !(out.ends_with("sh") || out.ends_with("ch") || out.ends_with('j')) {
+                        out.push('y');
+                    }
+                } else {
+                    out.push('y');
+                }
+                out.push(vowel);
+            }
+            'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' => {
+                let vowel = small_kana_vowel(curr);
+                // Replace the vowel of the preceeding syllable (eg. ふぁ -> fa, ウィ -> wi)
+                match out.chars().last() {
+                    Some('u')
+                        if out.len() == 1
+                            || !out[..out.len() - 1].ends_with(char::is_alphabetic) =>
+                    {
+                        out.pop();
+                        out.push('w');
+                    }
+                    Some(c) if is_vowel(c) => {
+                        out.pop();
+                    }
+                    _ => (),
+                }
+                out.push(vowel);
+            }
+            'う' if out.ends_with('o') || out.ends_with('u') => lengthen_last_vowel(&mut out),
+            'お' if out.ends_with('o') => lengthen_last_vowel(&mut out),
+            _ => match kana_to_syllable(curr) {
+                Some(syllable) => out.push_str(syllable),
+                None => out.push(curr),
+            },
+        }
+    }
+
+    out
+}
+
+/// Replaces the last vowel of `out` with its long form. Does nothing if `out` doesn't end with a
+/// vowel
+fn lengthen_last_vowel(out: &mut String) {
+    let long = match out.chars().last() {
+        Some('a') => 'ā',
+        Some('i') => 'ī',
+        Some('u') => 'ū',
+        Some('e') => 'ē',
+        Some('o') => 'ō',
+        _ => return,
+    };
+    out.pop();
+    out.push(long);
+}
+
+/// Returns the vowel of a small kana
+#[inline]
+fn small_kana_vowel(c: char) -> char {
+    match c {
+        'ゃ' | 'ぁ' => 'a',
+        'ぃ' => 'i',
+        'ゅ' | 'ぅ' => 'u',
+        'ぇ' => 'e',
+        _ => 'o',
+    }
+}
+
+/// Converts katakana characters into their hiragana equivalent and leaves all others untouched
+#[inline]
+fn katakana_to_hiragana(c: char) -> char {
+    if ('ァ'..='ヶ').contains(&c) {
+        char::from_u32(c as u32 - 0x60).unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+/// Returns the Hepburn romanization of a single hiragana character
+fn kana_to_syllable(kana: char) -> Option<&'static str> {
+    Some(match kana {
+        'あ' => "a",
+        'い' => "i",
+        'う' => "u",
+        'え' => "e",
+        'お' => "o",
+        'か' => "ka",
+        'き' => "ki",
+        'く' => "ku",
+        'け' => "ke",
+        'こ' => "ko",
+        'が' => "ga",
+        'ぎ' => "gi",
+        'ぐ' => "gu",
+        'げ' => "ge",
+        'ご' => "go",
+        'さ' => "sa",
+        'し' => "shi",
+        'す' => "su",
+        'せ' => "se",
+        'そ' => "so",
+        'ざ' => "za",
+        'じ' => "ji",
+        'ず' => "zu",
+        'ぜ' => "ze",
+        'ぞ' => "zo",
+        'た' => "ta",
+        'ち' => "chi",
+        'つ' => "tsu",
+        'て' => "te",
+        'と' => "to",
+        'だ' => "da",
+        'ぢ' => "ji",
+        'づ' => "zu",
+        'で' => "de",
+        'ど' => "do",
+        'な' => "na",
+        'に' => "ni",
+        'ぬ' => "nu",
+        'ね' => "ne",
+        'の' => "no",
+        'は' => "ha",
+        'ひ' => "hi",
+        'ふ' => "fu",
+        'へ' => "he",
+        'ほ' => "ho",
+        'ば' => "ba",
+        'び' => "bi",
+        'ぶ' => "bu",
+        'べ' => "be",
+        'ぼ' => "bo",
+        'ぱ' => "pa",
+        'ぴ' => "pi",
+        'ぷ' => "pu",
+        'ぺ' => "pe",
+        'ぽ' => "po",
+        'ま' => "ma",
+        'み' => "mi",
+        'む' => "mu",
+        'め' => "me",
+        'も' => "mo",
+        'や' => "ya",
+        'ゆ' => "yu",
+        'よ' => "yo",
+        'ら' => "ra",
+        'り' => "ri",
+        'る' => "ru",
+        'れ' => "re",
+        'ろ' => "ro",
+        'わ' => "wa",
+        'ゐ' => "i",
+        'ゑ' => "e",
+        'を' => "o",
+        'ゔ' => "vu",
+        _ => return None,
+    })
+}
+
+/// Characters which get ignored while converting romaji
+const SKIP_CHARS: &[char] = &[' ', '　', '\''];
+
+/// Lowercases `inp` and replaces vowels with macrons or circumflexes with their long form
+fn normalize(inp: &str) -> String {
+    let mut out = String::with_capacity(inp.len());
+
+    for c in inp.chars().flat_map(|c| c.to_lowercase()) {
+        match c {
+            'ā' | 'â' => out.push_str("aa"),
+            'ī' | 'î' => out.push_str("ii"),
+            'ū' | 'û' => out.push_str("uu"),
+            'ē' | 'ê' => out.push_str("ee"),
+            'ō' | 'ô' => out.push_str("ou"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Returns `true` if an 'n' followed by `next` is the start of a syllable like 'na' or 'nya'
+#[inline]
+fn starts_n_syllable(next: Option<char>) -> bool {
+    matches!(next, Some(c) if is_vowel(c) || c == 'y')
+}
+
+#[inline]
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Returns the hiragana for a single romanized syllable
+fn syllable_to_kana(syllable: &str) -> Option<&'static str> {
+    Some(match syllable {
+        "a" => "あ",
+        "i" => "い",
+        "u" => "う",
+        "e" => "え",
+        "o" => "お",
+
+        "ka" => "か",
+        "ki" => "き",
+        "ku" => "く",
+        "ke" => "け",
+        "ko" => "こ",
+        "kya" => "きゃ",
+        "kyu" => "きゅ",
+        "kyo" => "きょ",
+        "kwa" => "くゎ",
+
+        "ga" => "が",
+        "gi" => "ぎ",
+        "gu" => "ぐ",
+        "ge" => "げ",
+        "go" => "ご",
+        "gya" => "ぎゃ",
+        "gyu" => "ぎゅ",
+        "gyo" => "ぎょ",
+        "gwa" => "ぐゎ",
+
+        "sa" => "さ",
+        "si" | "shi" => "し",
+        "su" => "す",
+        "se" => "せ",
+        "so" => "そ",
+        "sya" | "sha" => "しゃ",
+        "syu" | "shu" => "しゅ",
+        "syo" | "sho" => "しょ",
+        "she" => "しぇ",
+
+        "za" => "ざ",
+        "zi" | "ji" => "じ",
+        "zu" => "ず",
+        "ze" => "ぜ",
+        "zo" => "ぞ",
+        "zya" | "ja" | "jya" => "じゃ",
+        "zyu" | "ju" | "jyu" => "じゅ",
+        "zyo" | "jo" | "jyo" => "じょ",
+        "je" => "じぇ",
+
+        "ta" => "た",
+        "ti" | "chi" => "ち",
+        "tu" | "tsu" => "つ",
+        "te" => "て",
+        "to" => "と",
+        "tya" | "cha" => "ちゃ",
+        "tyu" | "chu" => "ちゅ",
+        "tyo" | "cho" => "ちょ",
+        "che" => "ちぇ",
+
+        "da" => "だ",
+        "di" => "ぢ",
+        "du" | "dzu" => "づ",
+        "de" => "で",
+        "do" => "ど",
+        "dya" => "ぢゃ",
+        "dyu" => "ぢゅ",
+        "dyo" => "ぢょ",
+
+        "na" => "な",
+        "ni" => "に",
+        "nu" => "ぬ",
+        "ne" => "ね",
+        "no" => "の",
+        "nya" => "にゃ",
+        "nyu" => "にゅ",
+        "nyo" => "にょ",
+
+        "ha" => "は",
+        "hi" => "ひ",
+        "hu" | "fu" => "ふ",
+        "he" => "へ",
+        "ho" => "ほ",
+        "hya" => "ひゃ",
+        "hyu" => "ひゅ",
+        "hyo" => "ひょ",
+        "fa" => "ふぁ",
+        "fi" => "ふぃ",
+        "fe" => "ふぇ",
+        "fo" => "ふぉ",
+
+        "ba" => "ば",
+        "bi" => "び",
+        "bu" => "ぶ",
+        "be" => "べ",
+        "bo" => "ぼ",
+        "bya" => "びゃ",
+        "byu" => "びゅ",
+        "byo" => "びょ",
+
+        "pa" => "ぱ",
+        "pi" => "ぴ",
+        "pu" => "ぷ",
+        "pe" => "ぺ",
+        "po" => "ぽ",
+        "pya" => "ぴゃ",
+        "pyu" => "ぴゅ",
+        "pyo" => "ぴょ",
+
+        "ma" => "ま",
+        "mi" => "み",
+        "mu" => "む",
+        "me" => "め",
+        "mo" => "も",
+        "mya" => "みゃ",
+        "myu" => "みゅ",
+        "myo" => "みょ",
+
+        "ya" => "や",
+        "yu" => "ゆ",
+        "yo" => "よ",
+
+        "ra" => "ら",
+        "ri" => "り",
+        "ru" => "る",
+        "re" => "れ",
+        "ro" => "ろ",
+        "rya" => "りゃ",
+        "ryu" => "りゅ",
+        "ryo" => "りょ",
+
+        "wa" => "わ",
+        "wi" => "ゐ",
+        "we" => "ゑ",
+        "wo" => "を",
+
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hepburn() {
+        test("shinjitakunakatta", "しんじたくなかった");
+        test("chokusetsu", "ちょくせつ");
+        test("fujisan", "ふじさん");
+        test("kitte", "きって");
+        test("matcha", "まっちゃ");
+        test("shimbun", "しんぶん");
+        test("tōkyō", "とうきょう");
+        test("Ongaku desu yo", "おんがくですよ");
+    }
+
+    #[test]
+    fn test_kunrei_nihon() {
+        test("sinzitakunakatta", "しんじたくなかった");
+        test("tyokusetu", "ちょくせつ");
+        test("huzisan", "ふじさん");
+        test("tôkyô", "とうきょう");
+        test("hanadi", "はなぢ");
+        test("tuduku", "つづく");
+        test("kwazi", "くゎじ");
+    }
+
+    #[test]
+    fn test_n() {
+        test("konnichiwa", "こんにちわ");
+        test("kan'i", "かんい");
+        test("kanni", "かんに");
+        test("hon", "ほん");
+        test("honn", "ほん");
+        test("kinyoubi", "きにょうび");
+        test("onna", "おんな");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(to_hiragana("this is a laptop"), None);
+        assert_eq!(to_hiragana("kx"), None);
+    }
+
+    #[test]
+    fn test_to_romaji() {
+        test_romaji("しんじたくなかった", "shinjitakunakatta");
+        test_romaji("きって", "kitte");
+        test_romaji("まっちゃ", "matcha");
+        test_romaji("とうきょう", "tōkyō");
+        test_romaji("トーキョー", "tōkyō");
+        test_romaji("おおきい", "ōkii");
+        test_romaji("かんい", "kan'i");
+        test_romaji("きんよう", "kin'yō");
+        test_romaji("しんぶん", "shinbun");
+        test_romaji("じゃあね", "jaane");
+        test_romaji("ファイル", "fairu");
+        test_romaji("ウィキ", "wiki");
+        test_romaji("ティー", "tī");
+        test_romaji("日本ご", "日本go");
+    }
+
+    fn test(inp: &str, exp: &str) {
+        assert_eq!(to_hiragana(inp).as_deref(), Some(exp), "{inp}");
+    }
+
+    fn test_romaji(inp: &str, exp: &str) {
+        assert_eq!(to_romaji(inp), exp, "{inp}");
+    }
+}
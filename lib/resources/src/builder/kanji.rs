@@ -0,0 +1,167 @@
+use std::{collections::HashMap, path::Path};
+
+use japanese::JapaneseExt;
+use types::{
+    jotoba::{
+        kanji::{DetailedRadical, Kanji},
+        words::Word,
+    },
+    raw::kanjidict::Character,
+};
+use utils::to_option;
+
+use super::{load_level_map, open, path_str, radical_map, BuildSources};
+use crate::parse::{error::Error, kanji_ele, kanjidict, parser::Parse};
+
+/// Max amount of example words assigned to a single kanji reading type
+const MAX_EXAMPLE_WORDS: usize = 10;
+
+/// Parses all kanji from the kanjidict and merges them with the radical and kanji element data
+pub(super) fn parse(
+    sources: &BuildSources,
+    radicals: &[DetailedRadical],
+) -> Result<Vec<Kanji>, Error> {
+    let radicals = radical_map(radicals);
+
+    let parts: HashMap<char, Vec<char>> = match sources.kanji_elements {
+        Some(ref path) => kanji_ele::parse(path_str(path)?)
+            .map(|i| (i.kanji, i.radicals))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    let mut kanji = Vec::new();
+
+    kanjidict::Parser::new(open(&sources.kanjidict)?).parse(|character, _| {
+        let radical = character
+            .radical
+            .and_then(|i| radicals.get(&(i as u16)))
+            .map(|i| (*i).clone())
+            .unwrap_or_default();
+        let parts = parts.get(&character.literal).cloned();
+
+        kanji.push(convert(character, radical, parts));
+        false
+    })?;
+
+    Ok(kanji)
+}
+
+/// Converts a raw kanjidict character into a `Kanji`
+fn convert(character: Character, radical: DetailedRadical, parts: Option<Vec<char>>) -> Kanji {
+    Kanji {
+        literal: character.literal,
+        grade: character.grade,
+        stroke_count: character.stroke_count,
+        frequency: character.frequency,
+        jlpt: character.jlpt,
+        variant: to_option(character.variant),
+        onyomi: to_option(character.on_readings),
+        kunyomi: to_option(character.kun_readings),
+        chinese: to_option(character.chinese_readings),
+        korean_r: to_option(character.korean_romanized),
+        korean_h: to_option(character.korean_hangul),
+        natori: to_option(character.natori),
+        kun_dicts: None,
+        on_dicts: None,
+        similar_kanji: None,
+        meanings: character.meaning,
+        radical,
+        parts: parts.and_then(to_option),
+    }
+}
+
+/// Loads the genki lesson file
+pub(super) fn load_genki<P: AsRef<Path>>(path: P) -> Result<HashMap<u8, Vec<char>>, Error> {
+    load_level_map(path, |i| {
+        let mut chars = i.chars();
+        let literal = chars.next()?;
+        chars.next().is_none().then(|| literal)
+    })
+}
+
+/// Builds a map from jlpt level to all kanji with the given level
+pub(super) fn jlpt_map(kanji: &[Kanji]) -> HashMap<u8, Vec<char>> {
+    let mut map: HashMap<u8, Vec<char>> = HashMap::new();
+
+    for k in kanji {
+        if let Some(jlpt) = k.jlpt {
+            map.entry(jlpt).or_default().push(k.literal);
+        }
+    }
+
+    map
+}
+
+/// Assigns example words for each kun and on reading of all kanji
+pub(super) fn assign_words(kanji: Vec<Kanji>, words: &[Word]) -> Vec<Kanji> {
+    // Index words by the kanji they contain to prevent iterating over all words for each kanji
+    let mut by_literal: HashMap<char, Vec<&Word>> = HashMap::new();
+    for word in words.iter().filter(|i| i.furigana.is_some()) {
+        let kanji_reading = match word.reading.kanji {
+            Some(ref kanji) => &kanji.reading,
+            None => continue,
+        };
+
+        for literal in kanji_reading.chars().filter(|i| i.is_kanji()) {
+            by_literal.entry(literal).or_default().push(word);
+        }
+    }
+
+    kanji
+        .into_iter()
+        .map(|mut k| {
+            let words = match by_literal.get_mut(&k.literal) {
+                Some(words) => words,
+                None => return k,
+            };
+
+            // Prefer common words
+            words.sort_by_key(|i| !i.is_common());
+
+            k.kun_dicts = k
+                .kunyomi
+                .as_ref()
+                .and_then(|r| find_examples(k.literal, r, words));
+            k.on_dicts = k
+                .onyomi
+                .as_ref()
+                .and_then(|r| find_examples(k.literal, r, words));
+            k
+        })
+        .collect()
+}
+
+/// Returns sequence ids of words using `literal` with one of the given readings
+fn find_examples(literal: char, readings: &[String], words: &[&Word]) -> Option<Vec<u32>> {
+    let readings = readings
+        .iter()
+        .map(|i| format_reading(i))
+        .filter(|i| !i.is_empty())
+        .collect::<Vec<_>>();
+
+    let examples = words
+        .iter()
+        .filter(|word| {
+            let furigana = word.furigana.as_ref().unwrap();
+            readings
+                .iter()
+                .any(|reading| japanese::has_reading(furigana, literal, reading).any(|i| i))
+        })
+        .map(|i| i.sequence)
+        .take(MAX_EXAMPLE_WORDS)
+        .collect();
+
+    to_option(examples)
+}
+
+/// Strips okurigana and affix markers from a kanjidict reading and converts it to hiragana.
+/// Eg. 'た.べる' => 'た' and 'ショク' => 'しょく'
+fn format_reading(reading: &str) -> String {
+    reading
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .replace('-', "")
+        .to_hiragana()
+}
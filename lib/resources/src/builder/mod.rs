@@ -0,0 +1,214 @@
+pub mod kanji;
+pub mod names;
+pub mod words;
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use types::jotoba::kanji::DetailedRadical;
+
+use crate::{
+    models::{
+        storage::{
+            handwriting::{HandwritingStorage, StrokeEntry},
+            stroke_paths::StrokePathStorage,
+            SentenceStorage,
+        },
+        DictResources,
+    },
+    parse::{error::Error, kanjivg, radicals, tatoeba},
+};
+
+/// Paths to all source files required to build a `DictResources` file. Only jmdict, jmnedict and
+/// kanjidict are required, all other sources are optional and get skipped if not set.
+#[derive(Debug, Default, Clone)]
+pub struct BuildSources {
+    pub jmdict: PathBuf,
+    pub jmnedict: PathBuf,
+    pub kanjidict: PathBuf,
+    /// Pitch accent file (`kanji\tkana\tpitch,pitch`)
+    pub accents: Option<PathBuf>,
+    /// Radical info file (same format as expected by `parse::radicals`)
+    pub radicals: Option<PathBuf>,
+    /// Kanji elements file (`kanji:parts`)
+    pub kanji_elements: Option<PathBuf>,
+    /// JLPT levels of words (`sequence\tlevel`)
+    pub word_jlpt: Option<PathBuf>,
+    /// Genki lessons of kanji (`literal\tlesson`)
+    pub kanji_genki: Option<PathBuf>,
+    /// Tatoeba sentences export (`id\tlang\ttext`)
+    pub tatoeba_sentences: Option<PathBuf>,
+    /// Tatoeba links export (`id\ttranslation_id`)
+    pub tatoeba_links: Option<PathBuf>,
+    /// KanjiVG stroke data. Either the combined xml file or a folder with the SVG files
+    pub kanjivg: Option<PathBuf>,
+}
+
+impl BuildSources {
+    /// Returns an error if one of the given source files doesn't exist
+    fn check(&self) -> Result<(), Error> {
+        let required = [&self.jmdict, &self.jmnedict, &self.kanjidict];
+        let optional = [
+            &self.accents,
+            &self.radicals,
+            &self.kanji_elements,
+            &self.word_jlpt,
+            &self.kanji_genki,
+            &self.tatoeba_sentences,
+            &self.tatoeba_links,
+            &self.kanjivg,
+        ];
+
+        let all_exist = required
+            .into_iter()
+            .chain(optional.into_iter().flatten())
+            .all(|i| i.exists());
+
+        if !all_exist {
+            return Err(Error::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses all sources and merges them into a single `DictResources` value which can be written
+/// into a file using `DictResources::build`
+pub fn build(sources: &BuildSources) -> Result<DictResources, Error> {
+    sources.check()?;
+
+    let radicals = match sources.radicals {
+        Some(ref path) => radicals::parse(path_str(path)?).collect::<Vec<_>>(),
+        None => vec![],
+    };
+
+    let kanji = kanji::parse(sources, &radicals)?;
+    let kanji_genki = match sources.kanji_genki {
+        Some(ref path) => kanji::load_genki(path)?,
+        None => HashMap::new(),
+    };
+    let kanji_jlpt = kanji::jlpt_map(&kanji);
+
+    let word_jlpt = match sources.word_jlpt {
+        Some(ref path) => words::load_jlpt(path)?,
+        None => HashMap::new(),
+    };
+    let words = words::parse(sources, &kanji, &word_jlpt)?;
+    let irregular_iru_eru = words::irregular_iru_eru(&words);
+
+    // Kanji example words can only be assigned after all words are available
+    let kanji = kanji::assign_words(kanji, &words);
+
+    let names = names::parse(&sources.jmnedict)?;
+
+    Ok(DictResources {
+        words,
+        word_jlpt,
+        irregular_iru_eru,
+        kanji,
+        kanji_genki,
+        kanji_jlpt,
+        names,
+        radicals,
+    })
+}
+
+/// Parses the tatoeba sentences and assigns them to the words of `resources`. Returns `None` if
+/// no tatoeba sources are set
+pub fn build_sentences(
+    sources: &BuildSources,
+    resources: &mut DictResources,
+) -> Result<Option<SentenceStorage>, Error> {
+    let (sentences, links) = match (&sources.tatoeba_sentences, &sources.tatoeba_links) {
+        (Some(sentences), Some(links)) => (sentences, links),
+        _ => return Ok(None),
+    };
+
+    let mut sentences = tatoeba::parse(sentences, links)?;
+    tatoeba::assign_words(&mut sentences, &mut resources.words, &resources.kanji);
+
+    Ok(Some(tatoeba::build_storage(sentences)))
+}
+
+/// Parses the KanjiVG stroke paths of all kanji. Returns `None` if no KanjiVG source is set
+pub fn build_stroke_paths(sources: &BuildSources) -> Result<Option<StrokePathStorage>, Error> {
+    let path = match sources.kanjivg {
+        Some(ref path) => path,
+        None => return Ok(None),
+    };
+
+    Ok(Some(StrokePathStorage::new(kanjivg::parse(path)?)))
+}
+
+/// Derives the stroke directions used for handwriting recognition from the KanjiVG stroke paths.
+/// Kanji with paths that can't be parsed are skipped
+pub fn build_stroke_data(stroke_paths: &StrokePathStorage) -> HandwritingStorage {
+    let mut storage = HandwritingStorage::default();
+
+    for (literal, paths) in stroke_paths.iter() {
+        let strokes = paths
+            .iter()
+            .map(|path| kanjivg::path::points(path))
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(entry) = strokes.and_then(|strokes| StrokeEntry::from_points(literal, &strokes))
+        {
+            storage.insert(entry);
+        }
+    }
+
+    storage
+}
+
+/// Opens `path` as buffered reader
+#[inline]
+fn open<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, Error> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+/// Returns `path` as str since some parsers require a `&str`
+#[inline]
+fn path_str(path: &Path) -> Result<&str, Error> {
+    path.to_str().ok_or(Error::NotFound)
+}
+
+/// Parses a tab separated file with two columns into a map of `value` => [`key`]. Lines starting
+/// with '#' are treated as comment and lines that can't be parsed get skipped
+fn load_level_map<P, K, F>(path: P, parse_key: F) -> Result<HashMap<u8, Vec<K>>, Error>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<K>,
+{
+    let mut map: HashMap<u8, Vec<K>> = HashMap::new();
+
+    for line in open(path)?.lines() {
+        let line = line?;
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let mut split = line.split('\t');
+        let key = match split.next().and_then(|i| parse_key(i.trim())) {
+            Some(key) => key,
+            None => continue,
+        };
+        let level: u8 = match split.next().and_then(|i| i.trim().parse().ok()) {
+            Some(level) => level,
+            None => continue,
+        };
+
+        map.entry(level).or_default().push(key);
+    }
+
+    Ok(map)
+}
+
+/// Returns a map from radical id to the radical itself
+#[inline]
+fn radical_map(radicals: &[DetailedRadical]) -> HashMap<u16, &DetailedRadical> {
+    radicals.iter().map(|i| (i.id, i)).collect()
+}
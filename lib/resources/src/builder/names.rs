@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use types::{jotoba::names::Name, raw::jmnedict::NameEntry};
+
+use super::open;
+use crate::parse::{error::Error, jmnedict, parser::Parse};
+
+/// Parses all names from jmnedict
+pub(super) fn parse<P: AsRef<Path>>(path: P) -> Result<Vec<Name>, Error> {
+    let mut names = Vec::new();
+
+    jmnedict::Parser::new(open(path)?).parse(|entry, _| {
+        names.push(convert(entry));
+        false
+    })?;
+
+    Ok(names)
+}
+
+/// Converts a raw jmnedict entry into a `Name`
+fn convert(entry: NameEntry) -> Name {
+    Name {
+        sequence: entry.sequence as u32,
+        kana: entry.kana_element,
+        kanji: entry.kanji_element,
+        transcription: entry.transcription,
+        name_type: entry.name_type,
+        xref: entry.xref,
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::HashMap, path::Path};
+
+use itertools::Itertools;
+use japanese::{furigana, JapaneseExt};
+use types::{
+    jotoba::{
+        kanji::Kanji,
+        languages::Language,
+        words::{
+            part_of_speech::{GodanVerbEnding, PartOfSpeech, VerbType},
+            sense::{Gloss, Sense},
+            Dict, Reading, Word,
+        },
+    },
+    raw::jmdict::{Entry, EntryElement, EntrySense},
+};
+use utils::to_option;
+
+use super::{load_level_map, open, BuildSources};
+use crate::parse::{accents, error::Error, jmdict, parser::Parse};
+
+/// Parses all words from jmdict and merges them with pitch accent, furigana and jlpt data
+pub(super) fn parse(
+    sources: &BuildSources,
+    kanji: &[Kanji],
+    word_jlpt: &HashMap<u8, Vec<u32>>,
+) -> Result<Vec<Word>, Error> {
+    let accents = match sources.accents {
+        Some(ref path) => load_accents(path)?,
+        None => HashMap::new(),
+    };
+
+    let jlpt: HashMap<u32, u8> = word_jlpt
+        .iter()
+        .flat_map(|(lvl, seqs)| seqs.iter().map(move |seq| (*seq, *lvl)))
+        .collect();
+
+    let kanji: HashMap<char, &Kanji> = kanji.iter().map(|i| (i.literal, i)).collect();
+    let retrieve = |literal: String| {
+        let k = kanji.get(&literal.chars().next()?)?;
+        Some((k.kunyomi.clone(), k.onyomi.clone()))
+    };
+
+    let mut words = Vec::new();
+
+    jmdict::Parser::new(open(&sources.jmdict)?).parse(|entry, _| {
+        let mut word = convert(entry);
+
+        let reading = &word.reading;
+        let accent_key = (reading.get_reading().reading.clone(), reading.kana.reading.clone());
+        word.accents = accents.get(&accent_key).cloned();
+        word.jlpt_lvl = jlpt.get(&word.sequence).copied();
+        word.furigana = reading
+            .kanji
+            .as_ref()
+            .map(|k| furigana::generate::checked(retrieve, &k.reading, &reading.kana.reading));
+
+        words.push(word);
+        false
+    })?;
+
+    Ok(words)
+}
+
+/// Converts a raw jmdict entry into a `Word`. Accents, furigana and the jlpt level are not set
+fn convert(entry: Entry) -> Word {
+    let (kanji, kana): (Vec<_>, Vec<_>) = entry.elements.into_iter().partition(|i| i.kanji);
+
+    let mut kanji = kanji.into_iter().map(convert_element);
+    let mut kana = kana.into_iter().map(convert_element);
+
+    let mut main_kana = kana.next().unwrap_or_default();
+    main_kana.is_main = true;
+    let main_kanji = kanji.next().map(|mut i| {
+        i.is_main = true;
+        i
+    });
+
+    let reading = Reading {
+        kana: main_kana,
+        kanji: main_kanji,
+        alternative: kanji.chain(kana).collect(),
+    };
+
+    let senses = entry
+        .senses
+        .into_iter()
+        .flat_map(convert_sense)
+        .collect::<Vec<_>>();
+
+    Word {
+        sequence: entry.sequence,
+        priorities: reading.get_reading().priorities.clone(),
+        reading,
+        senses,
+        ..Default::default()
+    }
+}
+
+/// Converts a raw jmdict reading element into a `Dict`
+fn convert_element(element: EntryElement) -> Dict {
+    Dict {
+        reading: element.value,
+        kanji: element.kanji,
+        no_kanji: element.no_true_reading,
+        priorities: to_option(element.priorities),
+        reading_info: to_option(element.reading_info),
+        is_main: false,
+    }
+}
+
+/// Splits a raw jmdict sense into one `Sense` for each language its glosses are available in
+fn convert_sense(sense: EntrySense) -> Vec<Sense> {
+    let by_lang = sense
+        .glosses
+        .iter()
+        .map(|i| (i.language, i))
+        .into_group_map();
+
+    // Keep the language order deterministic
+    let languages = sense
+        .glosses
+        .iter()
+        .map(|i| i.language)
+        .unique()
+        .collect::<Vec<Language>>();
+
+    languages
+        .into_iter()
+        .map(|language| Sense {
+            id: sense.id,
+            misc: sense.misc,
+            field: sense.field,
+            dialect: sense.dialect,
+            glosses: by_lang[&language]
+                .iter()
+                .map(|i| Gloss {
+                    gloss: i.value.clone(),
+                    g_type: i.g_type,
+                    occurrence: 0,
+                })
+                .collect(),
+            xref: sense.xref.clone(),
+            antonym: sense.antonym.clone(),
+            information: sense.information.clone(),
+            part_of_speech: sense.part_of_speech.clone(),
+            language,
+            example_sentence: sense.example_sentence,
+            gairaigo: sense.gairaigo.clone(),
+        })
+        .collect()
+}
+
+/// Loads the pitch accent file into a map of (kanji, kana) => pitch
+fn load_accents<P: AsRef<Path>>(path: P) -> Result<HashMap<(String, String), Vec<u8>>, Error> {
+    let path = path.as_ref().to_str().ok_or(Error::NotFound)?;
+    let (_, items) = accents::parse(path)?;
+
+    Ok(items
+        .map(|i| {
+            let pitch = i.pitch.into_iter().map(|i| i as u8).collect();
+            ((i.kanji, i.kana), pitch)
+        })
+        .collect())
+}
+
+/// Loads the jlpt level file for words
+pub(super) fn load_jlpt<P: AsRef<Path>>(path: P) -> Result<HashMap<u8, Vec<u32>>, Error> {
+    load_level_map(path, |i| i.parse().ok())
+}
+
+/// Returns the sequence ids of all godan verbs which end with いる/える and therefore look like
+/// ichidan verbs (eg. 帰る, 入る)
+pub(super) fn irregular_iru_eru(words: &[Word]) -> Vec<u32> {
+    words
+        .iter()
+        .filter(|word| {
+            let is_godan_ru = word
+                .senses
+                .iter()
+                .flat_map(|i| i.part_of_speech.iter())
+                .any(|i| matches!(i, PartOfSpeech::Verb(VerbType::Godan(GodanVerbEnding::Ru))));
+
+            is_godan_ru && ends_with_iru_eru(&word.reading.kana.reading)
+        })
+        .map(|i| i.sequence)
+        .collect()
+}
+
+/// Returns `true` if `kana` ends with a kana of the i or e row followed by a る
+fn ends_with_iru_eru(kana: &str) -> bool {
+    let kana = kana.to_hiragana();
+    let mut chars = kana.chars().rev();
+    if chars.next() != Some('る') {
+        return false;
+    }
+
+    matches!(
+        chars.next(),
+        Some(
+            'い' | 'き' | 'ぎ' | 'し' | 'じ' | 'ち' | 'ぢ' | 'に' | 'ひ' | 'び' | 'ぴ' | 'み' | 'り'
+                | 'え' | 'け' | 'げ' | 'せ' | 'ぜ' | 'て' | 'で' | 'ね' | 'へ' | 'べ' | 'ぺ'
+                | 'め' | 'れ'
+        )
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ends_with_iru_eru() {
+        assert!(ends_with_iru_eru("かえる"));
+        assert!(ends_with_iru_eru("はいる"));
+        assert!(ends_with_iru_eru("しる"));
+        assert!(!ends_with_iru_eru("わかる"));
+        assert!(!ends_with_iru_eru("のる"));
+        assert!(!ends_with_iru_eru("かう"));
+    }
+}
@@ -9,7 +9,10 @@ use std::{
     path::Path,
 };
 
-use self::storage::{RadicalStorage, ResourceStorage, SentenceStorage};
+use self::storage::{
+    handwriting::HandwritingStorage, mapped::MappedResources, stroke_paths::StrokePathStorage,
+    RadicalStorage, ResourceStorage, SentenceStorage,
+};
 use serde::{Deserialize, Serialize};
 use types::jotoba::{
     kanji::{DetailedRadical, Kanji},
@@ -51,23 +54,42 @@ impl DictResources {
     }
 }
 
-/// Load a resource storage from a BufReader
+/// Load a resource storage from a BufReader. Storage data files written by
+/// `DictResources::build_mapped` get memory mapped instead of being loaded into memory
 pub fn load_storage<P: AsRef<Path>>(
     dict_data_path: P,
     suggestion_path: P,
     rad_mapc_path: P,
     sentences_path: P,
+    stroke_data_path: P,
+    stroke_paths_path: P,
 ) -> Result<ResourceStorage, Box<dyn Error>> {
-    let dict_data = load_dict_data(dict_data_path)?;
     let suggestion_data = suggestions::parse::load(suggestion_path)?;
     let radical_map = load_rad_map(rad_mapc_path)?;
     let sentences = load_sentences(sentences_path)?;
+    let handwriting = load_stroke_data(stroke_data_path)?;
+    let stroke_paths = load_stroke_paths(stroke_paths_path)?;
+
+    if MappedResources::is_mapped(&dict_data_path)? {
+        let dict_data = MappedResources::open(dict_data_path)?;
+        return Ok(ResourceStorage::new_mapped(
+            dict_data,
+            suggestion_data,
+            radical_map,
+            sentences,
+            handwriting,
+            stroke_paths,
+        ));
+    }
 
+    let dict_data = load_dict_data(dict_data_path)?;
     Ok(ResourceStorage::new(
         dict_data,
         suggestion_data,
         radical_map,
         sentences,
+        handwriting,
+        stroke_paths,
     ))
 }
 
@@ -95,6 +117,30 @@ fn load_rad_map<P: AsRef<Path>>(rad_map_file: P) -> Result<RadicalStorage, Box<d
     Ok(map)
 }
 
+/// Load stroke data used for handwriting recognition. Stroke data is optional, so a missing file
+/// results in an empty storage
+fn load_stroke_data<P: AsRef<Path>>(stroke_data: P) -> Result<HandwritingStorage, Box<dyn Error>> {
+    if !stroke_data.as_ref().exists() {
+        return Ok(HandwritingStorage::default());
+    }
+
+    Ok(HandwritingStorage::read(BufReader::new(File::open(
+        stroke_data,
+    )?))?)
+}
+
+/// Load kanji stroke paths. Stroke paths are optional, so a missing file results in an empty
+/// storage
+fn load_stroke_paths<P: AsRef<Path>>(stroke_paths: P) -> Result<StrokePathStorage, Box<dyn Error>> {
+    if !stroke_paths.as_ref().exists() {
+        return Ok(StrokePathStorage::default());
+    }
+
+    Ok(StrokePathStorage::read(BufReader::new(File::open(
+        stroke_paths,
+    )?))?)
+}
+
 /// Load sentences from sentence file
 fn load_sentences<P: AsRef<Path>>(sentences: P) -> Result<SentenceStorage, Box<dyn Error>> {
     Ok(bincode::deserialize_from(BufReader::new(File::open(
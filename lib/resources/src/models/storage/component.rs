@@ -0,0 +1,180 @@
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet, VecDeque},
+};
+
+use types::jotoba::kanji::{Decomposition, Kanji};
+
+/// Graph of kanji components built from the kanji element data (`Kanji::parts`). Allows
+/// decomposing kanji recursively and looking up kanji by any of their components
+#[derive(Default)]
+pub struct ComponentGraph {
+    /// Components of each kanji
+    parts: HashMap<char, Vec<char>>,
+    /// Kanji directly built with a component
+    users: HashMap<char, Vec<char>>,
+    /// Stroke counts of all kanji, used to order lookup results
+    stroke_counts: HashMap<char, u8>,
+}
+
+impl ComponentGraph {
+    /// Builds a new component graph out of all `kanji`
+    pub fn new<K: Borrow<Kanji>, I: IntoIterator<Item = K>>(kanji: I) -> Self {
+        let mut stroke_counts = HashMap::new();
+
+        let mut graph = Self::from_parts(kanji.into_iter().filter_map(|k| {
+            let k = k.borrow();
+            stroke_counts.insert(k.literal, k.stroke_count);
+            Some((k.literal, k.parts.clone()?))
+        }));
+
+        graph.stroke_counts = stroke_counts;
+        graph
+    }
+
+    /// Builds a new component graph out of kanji literals and their components
+    pub fn from_parts<I: IntoIterator<Item = (char, Vec<char>)>>(parts: I) -> Self {
+        let parts: HashMap<char, Vec<char>> = parts.into_iter().collect();
+
+        let mut users: HashMap<char, Vec<char>> = HashMap::new();
+        for (literal, components) in parts.iter() {
+            for component in components.iter().filter(|i| *i != literal) {
+                users.entry(*component).or_default().push(*literal);
+            }
+        }
+
+        for kanji in users.values_mut() {
+            kanji.sort_unstable();
+            kanji.dedup();
+        }
+
+        Self {
+            parts,
+            users,
+            stroke_counts: HashMap::new(),
+        }
+    }
+
+    /// Returns the decomposition tree of `literal`. Components which are part of another component
+    /// of the same kanji get placed below that component instead of the kanji itself
+    pub fn decomposition(&self, literal: char) -> Decomposition {
+        self.decompose(literal, &mut vec![])
+    }
+
+    /// Returns all kanji containing `component`, either directly or through another component.
+    /// The kanji are ordered by their stroke count
+    pub fn containing(&self, component: char) -> Vec<char> {
+        let mut found = HashSet::new();
+        let mut queue = VecDeque::from([component]);
+
+        while let Some(current) = queue.pop_front() {
+            for kanji in self.users.get(&current).into_iter().flatten() {
+                if *kanji != component && found.insert(*kanji) {
+                    queue.push_back(*kanji);
+                }
+            }
+        }
+
+        let mut found: Vec<_> = found.into_iter().collect();
+        found.sort_unstable_by_key(|i| {
+            let stroke_count = self.stroke_counts.get(i).copied().unwrap_or_default();
+            (stroke_count, *i)
+        });
+        found
+    }
+
+    fn decompose(&self, literal: char, path: &mut Vec<char>) -> Decomposition {
+        path.push(literal);
+
+        let parts: Vec<char> = self
+            .parts
+            .get(&literal)
+            .into_iter()
+            .flatten()
+            // Prevent cycles within the element data
+            .filter(|i| !path.contains(i))
+            .copied()
+            .collect();
+
+        // Only keep components which aren't a component of another part
+        let direct: Vec<char> = parts
+            .iter()
+            .filter(|part| {
+                !parts
+                    .iter()
+                    .any(|other| other != *part && self.has_component(*other, **part))
+            })
+            .copied()
+            .collect();
+
+        let components = direct
+            .into_iter()
+            .map(|part| self.decompose(part, path))
+            .collect();
+
+        path.pop();
+
+        Decomposition {
+            literal,
+            components,
+        }
+    }
+
+    /// Returns `true` if `component` is listed as part of `literal`
+    #[inline]
+    fn has_component(&self, literal: char, component: char) -> bool {
+        self.parts
+            .get(&literal)
+            .map(|i| i.contains(&component))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph() -> ComponentGraph {
+        ComponentGraph::from_parts(vec![
+            ('木', vec!['木']),
+            ('林', vec!['木']),
+            ('森', vec!['木', '林']),
+            ('日', vec!['日']),
+            ('明', vec!['日', '月']),
+        ])
+    }
+
+    #[test]
+    fn test_decomposition() {
+        let leaf = |literal| Decomposition {
+            literal,
+            components: vec![],
+        };
+
+        assert_eq!(
+            graph().decomposition('森'),
+            Decomposition {
+                literal: '森',
+                components: vec![Decomposition {
+                    literal: '林',
+                    components: vec![leaf('木')],
+                }],
+            }
+        );
+
+        assert_eq!(
+            graph().decomposition('明'),
+            Decomposition {
+                literal: '明',
+                components: vec![leaf('日'), leaf('月')],
+            }
+        );
+    }
+
+    #[test]
+    fn test_containing() {
+        assert_eq!(graph().containing('木'), vec!['林', '森']);
+        assert_eq!(graph().containing('林'), vec!['森']);
+        assert!(graph().containing('森').is_empty());
+    }
+}
@@ -0,0 +1,403 @@
+//! Offline handwriting recognition based on stroke count and stroke directions.
+//!
+//! # Resource format
+//!
+//! The stroke data file contains one kanji per line. Each line starts with the kanji literal
+//! followed by its strokes in stroke order, separated by whitespace. A stroke is written as
+//! the sequence of directions it is drawn in, each being a digit from 0 to 7:
+//!
+//! `0` right, `1` down-right, `2` down, `3` down-left, `4` left, `5` up-left, `6` up, `7` up-right
+//!
+//! Example: `十 0 2`. Empty lines and lines starting with `#` are ignored.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{self, BufRead, Write},
+};
+
+/// Amount of strokes a drawn kanji is allowed to differ from a candidate
+const MAX_STROKE_DIFF: usize = 2;
+
+/// Cost for each missing or additional stroke of a candidate
+const STROKE_COUNT_PENALTY: f32 = 1.0;
+
+/// Minimum length of a segment relative to the drawings size. Shorter movements are treated
+/// as jitter
+const MIN_SEGMENT_LEN: f32 = 0.06;
+
+/// A single point of a drawn stroke
+pub type Point = [f32; 2];
+
+/// Direction a part of a stroke is drawn in. Directions are based on screen coordinates so
+/// `Down` points towards increasing y values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+/// Stroke data of a single kanji
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrokeEntry {
+    pub literal: char,
+    pub strokes: Vec<Vec<Direction>>,
+}
+
+/// Stroke data of all kanji, grouped by their stroke count
+#[derive(Default)]
+pub struct HandwritingStorage {
+    by_stroke_count: HashMap<usize, Vec<StrokeEntry>>,
+}
+
+impl Direction {
+    const ALL: [Direction; 8] = [
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+        Direction::Up,
+        Direction::UpRight,
+    ];
+
+    /// Parses a direction from its digit in the stroke data file
+    pub fn from_char(c: char) -> Option<Self> {
+        let index = c.to_digit(10)? as usize;
+        Self::ALL.get(index).copied()
+    }
+
+    /// Returns the digit representing the direction in the stroke data file
+    #[inline]
+    pub fn to_char(self) -> char {
+        char::from(b'0' + self as u8)
+    }
+
+    /// Returns the direction closest to the vector (`dx`, `dy`) or `None` if the vector has no
+    /// length
+    pub fn from_vector(dx: f32, dy: f32) -> Option<Self> {
+        if dx == 0.0 && dy == 0.0 {
+            return None;
+        }
+
+        let angle = dy.atan2(dx).to_degrees().rem_euclid(360.0);
+        let index = (angle / 45.0).round() as usize % 8;
+        Some(Self::ALL[index])
+    }
+
+    /// Returns the amount of 45° steps between both directions (0-4)
+    #[inline]
+    pub fn distance(self, other: Direction) -> u8 {
+        let diff = (self as i8 - other as i8).rem_euclid(8) as u8;
+        diff.min(8 - diff)
+    }
+}
+
+impl StrokeEntry {
+    /// Parses a single line of the stroke data file
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split_whitespace();
+
+        let mut chars = parts.next()?.chars();
+        let literal = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+
+        let strokes = parts
+            .map(|stroke| stroke.chars().map(Direction::from_char).collect())
+            .collect::<Option<Vec<Vec<_>>>>()?;
+
+        if strokes.is_empty() {
+            return None;
+        }
+
+        Some(Self { literal, strokes })
+    }
+
+    /// Creates the stroke data of a kanji from the points of its strokes in stroke order.
+    /// Returns `None` if a stroke has no direction
+    pub fn from_points(literal: char, strokes: &[Vec<Point>]) -> Option<Self> {
+        let strokes = stroke_directions(strokes);
+        if strokes.is_empty() || strokes.iter().any(|i| i.is_empty()) {
+            return None;
+        }
+
+        Some(Self { literal, strokes })
+    }
+}
+
+impl Display for StrokeEntry {
+    /// Formats the entry as a line of the stroke data file
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.literal)?;
+
+        for stroke in &self.strokes {
+            write!(f, " ")?;
+            for dir in stroke {
+                write!(f, "{}", dir.to_char())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl HandwritingStorage {
+    /// Reads the stroke data from `reader`. Invalid lines are skipped
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, io::Error> {
+        let mut storage = Self::default();
+
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(entry) = StrokeEntry::parse(line) {
+                storage.insert(entry);
+            }
+        }
+
+        Ok(storage)
+    }
+
+    /// Writes the stroke data into `out` using the format read by `read`. Entries are ordered by
+    /// their literal
+    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), io::Error> {
+        let mut entries = self.by_stroke_count.values().flatten().collect::<Vec<_>>();
+        entries.sort_by_key(|i| i.literal);
+
+        for entry in entries {
+            writeln!(out, "{}", entry)?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds stroke data of a kanji to the storage
+    pub fn insert(&mut self, entry: StrokeEntry) {
+        self.by_stroke_count
+            .entry(entry.strokes.len())
+            .or_default()
+            .push(entry);
+    }
+
+    /// Returns the amount of kanji with stroke data
+    pub fn len(&self) -> usize {
+        self.by_stroke_count.values().map(|i| i.len()).sum()
+    }
+
+    /// Returns `true` if there is no stroke data
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns all kanji matching the drawn `strokes` along with their score (0-1), ordered by
+    /// the score. Each stroke is a list of points in drawing order
+    pub fn recognize(&self, strokes: &[Vec<Point>]) -> Vec<(char, f32)> {
+        let drawn = stroke_directions(strokes);
+        if drawn.is_empty() {
+            return vec![];
+        }
+
+        let min_count = drawn.len().saturating_sub(MAX_STROKE_DIFF).max(1);
+        let max_count = drawn.len() + MAX_STROKE_DIFF;
+
+        let mut candidates: Vec<_> = (min_count..=max_count)
+            .filter_map(|count| self.by_stroke_count.get(&count))
+            .flatten()
+            .map(|entry| (entry.literal, score(&drawn, &entry.strokes)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        candidates
+    }
+}
+
+/// Converts drawn strokes into their directions
+pub fn stroke_directions(strokes: &[Vec<Point>]) -> Vec<Vec<Direction>> {
+    let points = strokes.iter().flatten();
+    let (min_x, max_x) = min_max(points.clone().map(|i| i[0]));
+    let (min_y, max_y) = min_max(points.map(|i| i[1]));
+
+    let size = (max_x - min_x).max(max_y - min_y);
+    let min_len = (size * MIN_SEGMENT_LEN).max(f32::EPSILON);
+
+    strokes
+        .iter()
+        .filter(|i| !i.is_empty())
+        .map(|stroke| directions(stroke, min_len))
+        .collect()
+}
+
+/// Returns the directions a single stroke is drawn in. Movements shorter than `min_len` get
+/// merged with the following ones
+fn directions(stroke: &[Point], min_len: f32) -> Vec<Direction> {
+    let mut out: Vec<Direction> = vec![];
+    let mut anchor = stroke[0];
+
+    for point in &stroke[1..] {
+        let (dx, dy) = (point[0] - anchor[0], point[1] - anchor[1]);
+        if dx.hypot(dy) < min_len {
+            continue;
+        }
+
+        if let Some(dir) = Direction::from_vector(dx, dy) {
+            if out.last() != Some(&dir) {
+                out.push(dir);
+            }
+        }
+        anchor = *point;
+    }
+
+    // Short strokes (eg. dots) still have a direction
+    if out.is_empty() {
+        let last = stroke[stroke.len() - 1];
+        out.extend(Direction::from_vector(
+            last[0] - stroke[0][0],
+            last[1] - stroke[0][1],
+        ));
+    }
+
+    out
+}
+
+/// Returns how similar the `drawn` strokes are to the strokes of a kanji (0-1)
+fn score(drawn: &[Vec<Direction>], strokes: &[Vec<Direction>]) -> f32 {
+    let count_diff = drawn.len().abs_diff(strokes.len());
+
+    let cost: f32 = drawn
+        .iter()
+        .zip(strokes.iter())
+        .map(|(a, b)| stroke_cost(a, b))
+        .sum::<f32>()
+        + count_diff as f32 * STROKE_COUNT_PENALTY;
+
+    1.0 - cost / drawn.len().max(strokes.len()) as f32
+}
+
+/// Returns the cost (0-1) of turning stroke `a` into `b`. Calculated as edit distance where
+/// substituting a direction costs less the more similar both directions are
+fn stroke_cost(a: &[Direction], b: &[Direction]) -> f32 {
+    let len = a.len().max(b.len());
+    if len == 0 {
+        return 0.0;
+    }
+
+    let mut prev: Vec<f32> = (0..=b.len()).map(|i| i as f32).collect();
+    let mut curr = vec![0.0; b.len() + 1];
+
+    for (i, a_dir) in a.iter().enumerate() {
+        curr[0] = (i + 1) as f32;
+
+        for (j, b_dir) in b.iter().enumerate() {
+            let substitution = prev[j] + a_dir.distance(*b_dir) as f32 / 4.0;
+            curr[j + 1] = substitution.min(prev[j + 1] + 1.0).min(curr[j] + 1.0);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()] / len as f32
+}
+
+fn min_max<I: Iterator<Item = f32>>(iter: I) -> (f32, f32) {
+    iter.fold((f32::MAX, f32::MIN), |(min, max), i| {
+        (min.min(i), max.max(i))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction::*;
+
+    fn storage() -> HandwritingStorage {
+        let data = "# test data\n十 0 2\n一 0\n二 0 0\n人 3 1\n口 2 02 0\n";
+        HandwritingStorage::read(data.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            StrokeEntry::parse("口 2 02 0"),
+            Some(StrokeEntry {
+                literal: '口',
+                strokes: vec![vec![Down], vec![Right, Down], vec![Right]],
+            })
+        );
+        assert_eq!(StrokeEntry::parse("口"), None);
+        assert_eq!(StrokeEntry::parse("口 29"), None);
+        assert_eq!(storage().len(), 5);
+    }
+
+    #[test]
+    fn test_write() {
+        let mut out = vec![];
+        storage().write(&mut out).unwrap();
+
+        let written = HandwritingStorage::read(out.as_slice()).unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "一 0\n二 0 0\n人 3 1\n十 0 2\n口 2 02 0\n"
+        );
+    }
+
+    #[test]
+    fn test_from_points() {
+        let strokes = vec![
+            vec![[10.0, 50.0], [95.0, 49.0]],
+            vec![[50.0, 10.0], [51.0, 50.0], [50.0, 95.0]],
+        ];
+        let entry = StrokeEntry::from_points('十', &strokes).unwrap();
+        assert_eq!(entry.strokes, vec![vec![Right], vec![Down]]);
+        assert_eq!(entry.to_string(), "十 0 2");
+
+        assert_eq!(StrokeEntry::from_points('一', &[vec![[1.0, 1.0]]]), None);
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::from_vector(1.0, 0.1), Some(Right));
+        assert_eq!(Direction::from_vector(-1.0, 1.0), Some(DownLeft));
+        assert_eq!(Direction::from_vector(0.0, -3.0), Some(Up));
+        assert_eq!(Direction::from_vector(0.0, 0.0), None);
+        assert_eq!(Right.distance(UpRight), 1);
+        assert_eq!(Right.distance(Left), 4);
+    }
+
+    #[test]
+    fn test_recognize() {
+        let storage = storage();
+
+        // 十 drawn with a slightly shaky horizontal stroke
+        let strokes = vec![
+            vec![[0.0, 50.0], [30.0, 52.0], [60.0, 49.0], [100.0, 51.0]],
+            vec![[50.0, 0.0], [51.0, 40.0], [50.0, 100.0]],
+        ];
+        let res = storage.recognize(&strokes);
+        assert_eq!(res[0].0, '十');
+        assert_eq!(res[0].1, 1.0);
+
+        // 人
+        let strokes = vec![
+            vec![[50.0, 0.0], [25.0, 50.0], [0.0, 100.0]],
+            vec![[45.0, 20.0], [70.0, 60.0], [100.0, 100.0]],
+        ];
+        assert_eq!(storage.recognize(&strokes)[0].0, '人');
+
+        assert!(storage.recognize(&[]).is_empty());
+    }
+}
@@ -1,6 +1,88 @@
-use super::ResourceStorage;
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashMap,
+};
+
+use super::{handwriting::Point, ResourceStorage};
+use serde::{Deserialize, Serialize};
 use sorted_intersection::SortedIntersection;
-use types::jotoba::kanji::{DetailedRadical, Kanji};
+use types::jotoba::kanji::{Decomposition, DetailedRadical, Kanji};
+
+/// Maps korean readings and meanings to the kanji using them and holds the properties of all
+/// kanji. This allows looking up and filtering kanji without iterating over (and decoding) all
+/// kanji
+#[derive(Serialize, Deserialize, Default)]
+pub struct KanjiIndex {
+    korean: HashMap<String, Vec<char>>,
+    meanings: HashMap<String, Vec<char>>,
+    /// Ordered by frequency, see `KanjiRetrieve::by_frequency`
+    properties: Vec<KanjiProperties>,
+}
+
+/// Numeric properties of a kanji which can be used for filtering
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct KanjiProperties {
+    pub literal: char,
+    pub stroke_count: u8,
+    pub grade: Option<u8>,
+    pub frequency: Option<u16>,
+}
+
+impl From<&Kanji> for KanjiProperties {
+    #[inline]
+    fn from(kanji: &Kanji) -> Self {
+        Self {
+            literal: kanji.literal,
+            stroke_count: kanji.stroke_count,
+            grade: kanji.grade,
+            frequency: kanji.frequency,
+        }
+    }
+}
+
+impl KanjiIndex {
+    /// Builds a new index out of all `kanji`
+    pub fn new<K: Borrow<Kanji>, I: IntoIterator<Item = K>>(kanji: I) -> Self {
+        let mut index = Self::default();
+
+        for k in kanji {
+            let k = k.borrow();
+            index.properties.push(k.into());
+
+            for reading in k.korean_h.iter().flatten() {
+                index
+                    .korean
+                    .entry(reading.clone())
+                    .or_default()
+                    .push(k.literal);
+            }
+
+            for meaning in k.meanings.iter() {
+                index
+                    .meanings
+                    .entry(meaning.clone())
+                    .or_default()
+                    .push(k.literal);
+            }
+        }
+
+        for literals in index.korean.values_mut().chain(index.meanings.values_mut()) {
+            literals.sort_unstable();
+            literals.dedup();
+        }
+
+        index.properties.sort_by(|a, b| {
+            let freq_a = a.frequency.unwrap_or(u16::MAX);
+            let freq_b = b.frequency.unwrap_or(u16::MAX);
+            freq_a
+                .cmp(&freq_b)
+                .then(a.stroke_count.cmp(&b.stroke_count))
+                .then(a.literal.cmp(&b.literal))
+        });
+
+        index
+    }
+}
 
 #[derive(Clone, Copy)]
 pub struct KanjiRetrieve<'a> {
@@ -15,19 +97,19 @@ impl<'a> KanjiRetrieve<'a> {
 
     /// Returns an iterator over all loaded kanji
     #[inline]
-    pub fn all(&self) -> impl Iterator<Item = &Kanji> {
-        self.storage.dict_data.kanji.kanji.iter().map(|i| i.1)
+    pub fn all(&self) -> impl Iterator<Item = Cow<'a, Kanji>> {
+        self.storage.dict_data.kanji.kanji.iter()
     }
 
     /// Get a kanji by its sequence id
     #[inline]
-    pub fn by_literal(&self, literal: char) -> Option<&Kanji> {
-        self.storage.dict_data.kanji.kanji.get(&literal)
+    pub fn by_literal(&self, literal: char) -> Option<Cow<'a, Kanji>> {
+        self.storage.dict_data.kanji.kanji.get(literal as u32)
     }
 
     /// Returns all kanji with the given radicals
     #[inline]
-    pub fn by_radicals(&self, radicals: &[char]) -> Vec<&Kanji> {
+    pub fn by_radicals(&self, radicals: &[char]) -> Vec<Cow<'a, Kanji>> {
         let rad_map = &self.storage.dict_data.rad_kanji_map;
 
         let mut maps = radicals
@@ -44,9 +126,44 @@ impl<'a> KanjiRetrieve<'a> {
             .collect::<Vec<_>>()
     }
 
+    /// Returns an iterator over all kanji with the given korean reading (hangul)
+    pub fn by_korean_reading(&self, reading: &str) -> impl Iterator<Item = Cow<'a, Kanji>> {
+        let retrieve = *self;
+        self.storage
+            .dict_data
+            .kanji
+            .index
+            .korean
+            .get(reading)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| retrieve.by_literal(*i))
+    }
+
+    /// Returns an iterator over all kanji with the given meaning
+    pub fn by_meaning(&self, meaning: &str) -> impl Iterator<Item = Cow<'a, Kanji>> {
+        let retrieve = *self;
+        self.storage
+            .dict_data
+            .kanji
+            .index
+            .meanings
+            .get(meaning)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| retrieve.by_literal(*i))
+    }
+
+    /// Returns the properties of all kanji ordered by their frequency. Kanji without frequency come
+    /// last and kanji with the same frequency are ordered by their stroke count
+    #[inline]
+    pub fn by_frequency(&self) -> &'a [KanjiProperties] {
+        &self.storage.dict_data.kanji.index.properties
+    }
+
     /// Returns all kanji with given jlpt level
     #[inline]
-    pub fn by_jlpt(&self, jlpt: u8) -> Option<&Vec<char>> {
+    pub fn by_jlpt(&self, jlpt: u8) -> Option<&'a Vec<char>> {
         self.storage.dict_data.kanji.jlpt_data.get(&jlpt)
     }
 
@@ -58,7 +175,7 @@ impl<'a> KanjiRetrieve<'a> {
 
     /// Returns a list of kanji taught in given genki_lesson
     #[inline]
-    pub fn by_genki_lesson(&self, genki_lektion: u8) -> Option<&Vec<char>> {
+    pub fn by_genki_lesson(&self, genki_lektion: u8) -> Option<&'a Vec<char>> {
         self.storage
             .dict_data
             .kanji
@@ -66,8 +183,45 @@ impl<'a> KanjiRetrieve<'a> {
             .get(&genki_lektion)
     }
 
+    /// Returns the decomposition tree of the kanji with the given literal
+    #[inline]
+    pub fn decomposition(&self, literal: char) -> Option<Decomposition> {
+        self.by_literal(literal)?;
+        let components = &self.storage.dict_data.kanji.components;
+        Some(components.decomposition(literal))
+    }
+
+    /// Returns the literals of all kanji containing `component`, either directly or through
+    /// another component. The kanji are ordered by their stroke count
+    #[inline]
+    pub fn by_component(&self, component: char) -> Vec<char> {
+        self.storage
+            .dict_data
+            .kanji
+            .components
+            .containing(component)
+    }
+
+    /// Returns the stroke paths of the kanji with the given literal in stroke order
+    #[inline]
+    pub fn stroke_paths(&self, literal: char) -> Option<&'a [String]> {
+        self.storage.dict_data.kanji.stroke_paths.get(literal)
+    }
+
+    /// Returns kanji matching the handwritten `strokes` along with their score (0-1), ordered by
+    /// the score. Each stroke is a list of points in drawing order
+    pub fn by_handwriting(&self, strokes: &[Vec<Point>]) -> Vec<(Cow<'a, Kanji>, f32)> {
+        self.storage
+            .dict_data
+            .handwriting
+            .recognize(strokes)
+            .into_iter()
+            .filter_map(|(literal, score)| Some((self.by_literal(literal)?, score)))
+            .collect()
+    }
+
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Kanji> {
-        self.storage.dict_data.kanji.kanji.iter().map(|i| i.1)
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'a, Kanji>> {
+        self.storage.dict_data.kanji.kanji.iter()
     }
 }
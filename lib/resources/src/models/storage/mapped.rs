@@ -0,0 +1,364 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    error::Error,
+    fs::File,
+    io::{self, Read, Write},
+    marker::PhantomData,
+    path::Path,
+    sync::Arc,
+};
+
+use memmap2::Mmap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use types::jotoba::{
+    kanji::{DetailedRadical, Kanji},
+    names::Name,
+    words::Word,
+};
+
+use super::{kanji::KanjiIndex, word::WordTagIndex};
+use crate::models::DictResources;
+
+/// Magic bytes at the beginning of a memory mapped storage file
+pub const MAGIC: &[u8; 8] = b"JTBMAP01";
+
+/// Size of a single entry in the offsets table: `id: u32` + `offset: u64`
+const ENTRY_SIZE: usize = 12;
+
+/// Dictionary data read from a memory mapped storage file. Only `meta` is held in memory, all
+/// words, names and kanji get decoded on access.
+///
+/// Layout: `MAGIC | meta_len: u64 | meta | words | names | kanji`
+pub struct MappedResources {
+    pub meta: Meta,
+    pub words: MappedTable<Word>,
+    pub names: MappedTable<Name>,
+    pub kanji: MappedTable<Kanji>,
+}
+
+/// Small parts of `DictResources` and indexes over the tables which always get loaded into
+/// memory
+#[derive(Serialize, Deserialize)]
+pub struct Meta {
+    pub word_jlpt: HashMap<u8, Vec<u32>>,
+    pub irregular_iru_eru: Vec<u32>,
+    pub kanji_genki: HashMap<u8, Vec<char>>,
+    pub kanji_jlpt: HashMap<u8, Vec<char>>,
+    pub radicals: Vec<DetailedRadical>,
+    pub word_tag_index: WordTagIndex,
+    pub kanji_index: KanjiIndex,
+}
+
+impl MappedResources {
+    /// Memory maps the storage file at `path`. The file must not be modified while it is mapped,
+    /// so new storage files should be written to a different path and renamed afterwards
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(path)?;
+        // Safety: See doc comment above
+        let map = Arc::new(unsafe { Mmap::map(&file)? });
+
+        if map.get(..MAGIC.len()) != Some(MAGIC) {
+            return Err(invalid_data().into());
+        }
+
+        let meta_start = MAGIC.len() + 8;
+        let meta_len = read_u64(&map, MAGIC.len()).ok_or_else(invalid_data)? as usize;
+        let meta_end = meta_start.checked_add(meta_len).ok_or_else(invalid_data)?;
+        let meta_data = map.get(meta_start..meta_end).ok_or_else(invalid_data)?;
+        let meta: Meta = bincode::deserialize(meta_data)?;
+
+        let (words, pos) = MappedTable::open(map.clone(), meta_end)?;
+        let (names, pos) = MappedTable::open(map.clone(), pos)?;
+        let (kanji, _) = MappedTable::open(map, pos)?;
+
+        Ok(Self {
+            meta,
+            words,
+            names,
+            kanji,
+        })
+    }
+
+    /// Returns `true` if the file at `path` is a memory mapped storage file
+    pub fn is_mapped<P: AsRef<Path>>(path: P) -> io::Result<bool> {
+        let mut magic = [0u8; 8];
+        match File::open(path)?.read_exact(&mut magic) {
+            Ok(()) => Ok(&magic == MAGIC),
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl DictResources {
+    /// Writes the resources into `out` using the memory mapped storage format which can be
+    /// opened with `MappedResources::open`
+    pub fn build_mapped<W: Write>(&self, out: &mut W) -> Result<(), bincode::Error> {
+        let meta = Meta {
+            word_jlpt: self.word_jlpt.clone(),
+            irregular_iru_eru: self.irregular_iru_eru.clone(),
+            kanji_genki: self.kanji_genki.clone(),
+            kanji_jlpt: self.kanji_jlpt.clone(),
+            radicals: self.radicals.clone(),
+            word_tag_index: WordTagIndex::new(&self.words),
+            kanji_index: KanjiIndex::new(&self.kanji),
+        };
+        let meta = bincode::serialize(&meta)?;
+
+        out.write_all(MAGIC)?;
+        out.write_all(&(meta.len() as u64).to_le_bytes())?;
+        out.write_all(&meta)?;
+
+        TableWriter::new(self.words.iter().map(|i| (i.sequence, i)))?.write(out)?;
+        TableWriter::new(self.names.iter().map(|i| (i.sequence, i)))?.write(out)?;
+        TableWriter::new(self.kanji.iter().map(|i| (i.literal as u32, i)))?.write(out)?;
+
+        Ok(())
+    }
+}
+
+/// A table of bincode encoded items inside a memory mapped file. Items are sorted by their id
+/// and get decoded on each access without being kept in memory.
+///
+/// Layout (little endian):
+/// `len: u32 | len + 1 entries (id: u32, offset: u64) | encoded items`
+///
+/// The offsets are relative to the start of the encoded items and the last entry only marks the
+/// end of the data section.
+pub struct MappedTable<T> {
+    map: Arc<Mmap>,
+    /// Position of the first entry in `map`
+    entries: usize,
+    /// Position of the first encoded item in `map`
+    data: usize,
+    len: usize,
+    item_type: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> MappedTable<T> {
+    /// Opens a table starting at `pos` in `map`. Returns the table and the position right after
+    /// it. Fails if the entries aren't sorted by their id or point outside of the table
+    pub fn open(map: Arc<Mmap>, pos: usize) -> io::Result<(Self, usize)> {
+        let len = read_u32(&map, pos).ok_or_else(invalid_data)? as usize;
+
+        let entries = pos + 4;
+        let data = len
+            .checked_add(1)
+            .and_then(|i| i.checked_mul(ENTRY_SIZE))
+            .and_then(|i| i.checked_add(entries))
+            .filter(|data| *data <= map.len())
+            .ok_or_else(invalid_data)?;
+
+        let table = Self {
+            map,
+            entries,
+            data,
+            len,
+            item_type: PhantomData,
+        };
+
+        let end = table.validate().ok_or_else(invalid_data)?;
+        Ok((table, end))
+    }
+
+    /// Returns the item with the given `id` or `None` if there is no such item. Items which can't
+    /// be decoded get logged and treated as missing
+    pub fn get(&self, id: u32) -> Option<T> {
+        let (mut low, mut high) = (0, self.len);
+
+        while low < high {
+            let mid = (low + high) / 2;
+            match self.id(mid)?.cmp(&id) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return self.get_at(mid),
+            }
+        }
+
+        None
+    }
+
+    /// Returns an iterator over all items ordered by their id. Items which can't be decoded get
+    /// logged and skipped
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len).filter_map(move |i| self.get_at(i))
+    }
+
+    /// Returns the amount of items in the table
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the table has no items
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes the item at `pos`
+    fn get_at(&self, pos: usize) -> Option<T> {
+        let data = self.map.get(self.offset(pos)?..self.offset(pos + 1)?)?;
+        match bincode::deserialize(data) {
+            Ok(item) => Some(item),
+            Err(err) => {
+                log::error!("Failed to decode mapped item {:?}: {}", self.id(pos), err);
+                None
+            }
+        }
+    }
+
+    /// Checks that all ids are sorted and unique and that all items lie within the mapped file.
+    /// Returns the position right after the last item
+    fn validate(&self) -> Option<usize> {
+        let mut last_offset = self.data;
+        for pos in 0..self.len {
+            if pos > 0 && self.id(pos - 1)? >= self.id(pos)? {
+                return None;
+            }
+
+            let offset = self.offset(pos)?;
+            if offset < last_offset {
+                return None;
+            }
+            last_offset = offset;
+        }
+
+        let end = self.offset(self.len)?;
+        (end >= last_offset && end <= self.map.len()).then(|| end)
+    }
+
+    /// Returns the id of the entry at `pos`
+    #[inline]
+    fn id(&self, pos: usize) -> Option<u32> {
+        read_u32(&self.map, self.entries + pos * ENTRY_SIZE)
+    }
+
+    /// Returns the absolute position of the item at `pos`
+    #[inline]
+    fn offset(&self, pos: usize) -> Option<usize> {
+        let offset = read_u64(&self.map, self.entries + pos * ENTRY_SIZE + 4)?;
+        usize::try_from(offset).ok()?.checked_add(self.data)
+    }
+}
+
+/// Writes items into the format read by `MappedTable`
+pub struct TableWriter<'a, T> {
+    /// Items with their ids and encoded sizes
+    items: Vec<(u32, &'a T, u64)>,
+}
+
+impl<'a, T: Serialize> TableWriter<'a, T> {
+    /// Creates a new `TableWriter` for `items` where each item is paired with its id
+    pub fn new<I>(items: I) -> Result<Self, bincode::Error>
+    where
+        I: Iterator<Item = (u32, &'a T)>,
+    {
+        let mut items = items
+            .map(|(id, item)| Ok((id, item, bincode::serialized_size(item)?)))
+            .collect::<Result<Vec<_>, bincode::Error>>()?;
+        items.sort_by_key(|i| i.0);
+
+        Ok(Self { items })
+    }
+
+    /// Writes the table into `out`
+    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), bincode::Error> {
+        out.write_all(&(self.items.len() as u32).to_le_bytes())?;
+
+        let mut offset = 0u64;
+        for (id, _, size) in self.items.iter() {
+            out.write_all(&id.to_le_bytes())?;
+            out.write_all(&offset.to_le_bytes())?;
+            offset += size;
+        }
+
+        // End of the data section
+        let last_id = self.items.last().map(|i| i.0).unwrap_or_default();
+        out.write_all(&last_id.to_le_bytes())?;
+        out.write_all(&offset.to_le_bytes())?;
+
+        for (_, item, _) in self.items.iter() {
+            bincode::serialize_into(&mut *out, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    let bytes = data.get(pos..pos + 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+#[inline]
+fn read_u64(data: &[u8], pos: usize) -> Option<u64> {
+    let bytes = data.get(pos..pos + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+#[inline]
+fn invalid_data() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "Invalid storage data")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_roundtrip() {
+        let items = vec![
+            (7, String::from("seven")),
+            (1, String::from("one")),
+            (3, String::new()),
+        ];
+
+        let mut data = vec![0u8; 5];
+        TableWriter::new(items.iter().map(|i| (i.0, &i.1)))
+            .unwrap()
+            .write(&mut data)
+            .unwrap();
+        data.extend_from_slice(b"tail");
+
+        let path = std::env::temp_dir().join("jotoba_mapped_table_test");
+        std::fs::write(&path, &data).unwrap();
+        let map = Arc::new(unsafe { Mmap::map(&File::open(&path).unwrap()).unwrap() });
+
+        let (table, end) = MappedTable::<String>::open(map, 5).unwrap();
+        assert_eq!(end, data.len() - 4);
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.get(7).as_deref(), Some("seven"));
+        assert_eq!(table.get(3).as_deref(), Some(""));
+        assert_eq!(table.get(2), None);
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec!["one", "", "seven"]);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_table_invalid_offsets() {
+        let items = vec![(1, String::from("one")), (2, String::from("two"))];
+
+        let mut data = vec![];
+        TableWriter::new(items.iter().map(|i| (i.0, &i.1)))
+            .unwrap()
+            .write(&mut data)
+            .unwrap();
+
+        // Let the end of the data section point outside of the file
+        let end_entry = 4 + 2 * ENTRY_SIZE + 4;
+        data[end_entry..end_entry + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let path = std::env::temp_dir().join("jotoba_mapped_table_invalid_test");
+        std::fs::write(&path, &data).unwrap();
+        let map = Arc::new(unsafe { Mmap::map(&File::open(&path).unwrap()).unwrap() });
+
+        assert!(MappedTable::<String>::open(map, 0).is_err());
+
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -1,7 +1,12 @@
+pub mod component;
+pub mod handwriting;
 pub mod kanji;
+pub mod mapped;
 pub mod name;
 pub mod sentence;
+pub mod stroke_paths;
 pub mod suggestion;
+pub mod table;
 pub mod word;
 
 use intmap::IntMap;
@@ -10,17 +15,22 @@ use serde::{Deserialize, Serialize};
 use types::jotoba::languages::Language;
 
 use self::{
-    kanji::KanjiRetrieve,
+    component::ComponentGraph,
+    handwriting::HandwritingStorage,
+    kanji::{KanjiIndex, KanjiRetrieve},
+    mapped::MappedResources,
     name::NameRetrieve,
     sentence::SentenceRetrieve,
+    stroke_paths::StrokePathStorage,
     suggestion::{provider::SuggestionProvider, SuggestionDictionary},
-    word::WordRetrieve,
+    table::Table,
+    word::{WordRetrieve, WordTagIndex},
 };
 use super::{
     suggestions::{foreign_words::ForeignSuggestion, native_words::NativeSuggestion},
     DictResources,
 };
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 use types::jotoba::{
     kanji::{DetailedRadical, Kanji},
     names::Name,
@@ -28,9 +38,10 @@ use types::jotoba::{
     words::Word,
 };
 
-pub type WordStorage = IntMap<Word>;
-pub type NameStorage = IntMap<Name>;
-pub type KanjiStorage = HashMap<char, Kanji>;
+pub type WordStorage = Table<Word>;
+pub type NameStorage = Table<Name>;
+/// Kanji are stored by their literal
+pub type KanjiStorage = Table<Kanji>;
 /// Maps radicals to all kanji using the raical
 pub type RadicalStorage = HashMap<char, Vec<char>>;
 
@@ -50,6 +61,7 @@ pub struct DictionaryData {
     rad_kanji_map: RadicalStorage,
     sentences: SentenceStorage,
     radicals: HashMap<char, DetailedRadical>,
+    handwriting: HandwritingStorage,
 }
 
 /// Represents the dictionary word data
@@ -58,6 +70,7 @@ pub struct WordData {
     words: WordStorage,
     jlpt_word_map: HashMap<u8, Vec<u32>>,
     irregular_ichidan: Vec<u32>,
+    tag_index: WordTagIndex,
     // genki_levels: HashMap<u8, Vec<u32>>,
 }
 
@@ -66,6 +79,9 @@ pub struct KanjiData {
     kanji: KanjiStorage,
     genki_levels: HashMap<u8, Vec<char>>,
     jlpt_data: HashMap<u8, Vec<char>>,
+    components: ComponentGraph,
+    stroke_paths: StrokePathStorage,
+    index: KanjiIndex,
 }
 
 /// Contains sentences and jlpt levels for the dictionary.
@@ -75,6 +91,14 @@ pub struct SentenceStorage {
     pub jlpt_map: HashMap<u8, Vec<u32>>,
 }
 
+impl SentenceStorage {
+    /// Writes the sentence storage into `out`
+    #[inline]
+    pub fn build<W: std::io::Write>(&self, out: &mut W) -> Result<(), bincode::Error> {
+        bincode::serialize_into(out, &self)
+    }
+}
+
 /// Contains all data for the dictionary suggestions.
 #[derive(Default)]
 pub(crate) struct SuggestionData {
@@ -91,6 +115,7 @@ impl DictionaryData {
         rad_kanji_map: RadicalStorage,
         sentences: SentenceStorage,
         radicals: HashMap<char, DetailedRadical>,
+        handwriting: HandwritingStorage,
     ) -> Self {
         Self {
             word_data,
@@ -99,6 +124,7 @@ impl DictionaryData {
             rad_kanji_map,
             sentences,
             radicals,
+            handwriting,
         }
     }
 
@@ -141,9 +167,13 @@ impl ResourceStorage {
         suggestions: Option<SuggestionData>,
         rad_kanji_map: RadicalStorage,
         sentences: SentenceStorage,
+        handwriting: HandwritingStorage,
+        stroke_paths: StrokePathStorage,
     ) -> Self {
+        let word_tag_index = WordTagIndex::new(&resources.words);
         let words = build_words(resources.words);
         let names = build_names(resources.names);
+        let kanji_index = KanjiIndex::new(&resources.kanji);
         let kanji = build_kanji(resources.kanji);
         let radicals = build_radicals(resources.radicals);
 
@@ -151,12 +181,18 @@ impl ResourceStorage {
             words,
             jlpt_word_map: resources.word_jlpt,
             irregular_ichidan: resources.irregular_iru_eru,
+            tag_index: word_tag_index,
         };
 
+        let components = ComponentGraph::new(kanji.iter());
+
         let kanji_data = KanjiData {
             kanji,
             genki_levels: resources.kanji_genki,
             jlpt_data: resources.kanji_jlpt,
+            components,
+            stroke_paths,
+            index: kanji_index,
         };
 
         let dict_data = DictionaryData::new(
@@ -166,6 +202,55 @@ impl ResourceStorage {
             rad_kanji_map,
             sentences,
             radicals,
+            handwriting,
+        );
+
+        Self {
+            dict_data,
+            suggestions,
+        }
+    }
+
+    /// Create a new `ResourceStorage` which reads words, names and kanji from a memory mapped
+    /// storage file
+    pub(super) fn new_mapped(
+        resources: MappedResources,
+        suggestions: Option<SuggestionData>,
+        rad_kanji_map: RadicalStorage,
+        sentences: SentenceStorage,
+        handwriting: HandwritingStorage,
+        stroke_paths: StrokePathStorage,
+    ) -> Self {
+        let meta = resources.meta;
+        let radicals = build_radicals(meta.radicals);
+
+        let word_data = WordData {
+            words: Table::Mapped(resources.words),
+            jlpt_word_map: meta.word_jlpt,
+            irregular_ichidan: meta.irregular_iru_eru,
+            tag_index: meta.word_tag_index,
+        };
+
+        let kanji = Table::Mapped(resources.kanji);
+        let components = ComponentGraph::new(kanji.iter());
+
+        let kanji_data = KanjiData {
+            kanji,
+            genki_levels: meta.kanji_genki,
+            jlpt_data: meta.kanji_jlpt,
+            components,
+            stroke_paths,
+            index: meta.kanji_index,
+        };
+
+        let dict_data = DictionaryData::new(
+            word_data,
+            Table::Mapped(resources.names),
+            kanji_data,
+            rad_kanji_map,
+            sentences,
+            radicals,
+            handwriting,
         );
 
         Self {
@@ -181,7 +266,7 @@ impl ResourceStorage {
     }
 
     /// Returns an iterator over all words with given `jlpt` level
-    pub fn word_jlpt(&self, jlpt: u8) -> impl Iterator<Item = &'_ Word> {
+    pub fn word_jlpt(&self, jlpt: u8) -> impl Iterator<Item = Cow<'_, Word>> {
         let word = self.words();
 
         self.dict_data
@@ -218,7 +303,7 @@ impl ResourceStorage {
 
     /// Returns a `WordRetrieve` which can be used to retrieve names from the `ResourceStorage`
     #[inline]
-    pub fn names(&'static self) -> NameRetrieve<'static> {
+    pub fn names(&self) -> NameRetrieve<'_> {
         NameRetrieve::new(self)
     }
 
@@ -242,15 +327,15 @@ impl ResourceStorage {
 }
 
 pub fn build_words(words: Vec<Word>) -> WordStorage {
-    words.into_iter().map(|i| (i.sequence as u64, i)).collect()
+    words.into_iter().map(|i| (i.sequence, i)).collect()
 }
 
 pub fn build_names(names: Vec<Name>) -> NameStorage {
-    names.into_iter().map(|i| (i.sequence as u64, i)).collect()
+    names.into_iter().map(|i| (i.sequence, i)).collect()
 }
 
 pub fn build_kanji(kanji: Vec<Kanji>) -> KanjiStorage {
-    kanji.into_iter().map(|i| (i.literal, i)).collect()
+    kanji.into_iter().map(|i| (i.literal as u32, i)).collect()
 }
 
 pub fn build_radicals(radicals: Vec<DetailedRadical>) -> HashMap<char, DetailedRadical> {
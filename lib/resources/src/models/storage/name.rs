@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use types::jotoba::names::Name;
 
 use super::ResourceStorage;
@@ -15,7 +17,13 @@ impl<'a> NameRetrieve<'a> {
 
     /// Get a name by its sequence id
     #[inline]
-    pub fn by_sequence(&self, seq_id: u32) -> Option<&'a Name> {
-        self.storage.dict_data.names.get(seq_id as u64)
+    pub fn by_sequence(&self, seq_id: u32) -> Option<Cow<'a, Name>> {
+        self.storage.dict_data.names.get(seq_id)
+    }
+
+    /// Returns an iterator over all names
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'a, Name>> {
+        self.storage.dict_data.names.iter()
     }
 }
@@ -18,4 +18,10 @@ impl<'a> SentenceRetrieve<'a> {
     pub fn by_id(&self, id: u32) -> Option<&'a Sentence> {
         self.storage.dict_data.sentences.sentences.get(id as u64)
     }
+
+    /// Returns an iterator over all sentences
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a Sentence> {
+        self.storage.dict_data.sentences.sentences.iter().map(|i| i.1)
+    }
 }
@@ -0,0 +1,58 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Stroke paths of kanji in stroke order. Each path is SVG path data within the 109x109 viewbox
+/// used by KanjiVG
+#[derive(Serialize, Deserialize, Default)]
+pub struct StrokePathStorage {
+    paths: HashMap<char, Vec<String>>,
+}
+
+impl StrokePathStorage {
+    #[inline]
+    pub fn new(paths: HashMap<char, Vec<String>>) -> Self {
+        Self { paths }
+    }
+
+    /// Writes the stroke path storage into `out`
+    #[inline]
+    pub fn build<W: Write>(&self, out: &mut W) -> Result<(), bincode::Error> {
+        bincode::serialize_into(out, &self)
+    }
+
+    /// Reads a stroke path storage written by `build` from `reader`
+    #[inline]
+    pub fn read<R: Read>(reader: R) -> Result<Self, bincode::Error> {
+        bincode::deserialize_from(reader)
+    }
+
+    /// Returns the stroke paths of the kanji with the given literal in stroke order
+    #[inline]
+    pub fn get(&self, literal: char) -> Option<&[String]> {
+        self.paths.get(&literal).map(|i| i.as_slice())
+    }
+
+    /// Returns an iterator over all kanji literals with their stroke paths
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (char, &[String])> {
+        self.paths
+            .iter()
+            .map(|(literal, paths)| (*literal, paths.as_slice()))
+    }
+
+    /// Returns the amount of kanji with stroke paths
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns `true` if there are no stroke paths
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
@@ -40,6 +40,12 @@ impl<I: From<Vec<u8>>> SuggestionDictionary<I> {
         self.data.clone().read_line_raw(pos, &mut data).ok()?;
         Some(I::from(data))
     }
+
+    /// Returns an iterator over all suggestion items
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = I> + '_ {
+        (0..self.len()).filter_map(move |pos| self.get(pos))
+    }
 }
 
 impl<T: From<Vec<u8>>> BinarySearchable for SuggestionDictionary<T> {
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+use intmap::IntMap;
+use itertools::Either;
+use serde::de::DeserializeOwned;
+
+use super::mapped::MappedTable;
+
+/// Items which can be retrieved by their id. The items are either held in memory or read lazily
+/// from a memory mapped storage file. Items held in memory get borrowed while mapped items are
+/// decoded on each access
+pub enum Table<T> {
+    Memory(IntMap<T>),
+    Mapped(MappedTable<T>),
+}
+
+impl<T: DeserializeOwned + Clone> Table<T> {
+    /// Returns the item with the given `id`
+    #[inline]
+    pub fn get(&self, id: u32) -> Option<Cow<'_, T>> {
+        match self {
+            Table::Memory(map) => map.get(id as u64).map(Cow::Borrowed),
+            Table::Mapped(table) => table.get(id).map(Cow::Owned),
+        }
+    }
+
+    /// Returns an iterator over all items
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'_, T>> {
+        match self {
+            Table::Memory(map) => Either::Left(map.iter().map(|i| Cow::Borrowed(i.1))),
+            Table::Mapped(table) => Either::Right(table.iter().map(Cow::Owned)),
+        }
+    }
+
+    /// Returns the amount of items
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Table::Memory(map) => map.len(),
+            Table::Mapped(table) => table.len(),
+        }
+    }
+
+    /// Returns `true` if there are no items
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for Table<T> {
+    #[inline]
+    fn default() -> Self {
+        Table::Memory(IntMap::new())
+    }
+}
+
+impl<T> FromIterator<(u32, T)> for Table<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (u32, T)>>(iter: I) -> Self {
+        Table::Memory(iter.into_iter().map(|(id, i)| (id as u64, i)).collect())
+    }
+}
@@ -1,7 +1,70 @@
-use types::jotoba::words::Word;
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashMap,
+};
+
+use serde::{Deserialize, Serialize};
+use types::jotoba::words::{
+    dialect::Dialect, field::Field, foreign_language::ForeignLanguage, Word,
+};
 
 use super::ResourceStorage;
 
+/// Maps fields, dialects and loanword source languages of senses to the sequence ids of all words
+/// having such a sense. This allows searching by those tags without iterating over (and decoding)
+/// all words
+#[derive(Serialize, Deserialize, Default)]
+pub struct WordTagIndex {
+    fields: HashMap<u8, Vec<u32>>,
+    dialects: HashMap<u8, Vec<u32>>,
+    loans: HashMap<u8, Vec<u32>>,
+}
+
+impl WordTagIndex {
+    /// Builds a new index out of all `words`
+    pub fn new<W: Borrow<Word>, I: IntoIterator<Item = W>>(words: I) -> Self {
+        let mut index = Self::default();
+
+        for word in words {
+            let word = word.borrow();
+
+            for sense in word.senses.iter() {
+                if let Some(field) = sense.field {
+                    index
+                        .fields
+                        .entry(field as u8)
+                        .or_default()
+                        .push(word.sequence);
+                }
+
+                if let Some(dialect) = sense.dialect {
+                    index
+                        .dialects
+                        .entry(dialect as u8)
+                        .or_default()
+                        .push(word.sequence);
+                }
+
+                if let Some(ref gairaigo) = sense.gairaigo {
+                    index
+                        .loans
+                        .entry(gairaigo.language as u8)
+                        .or_default()
+                        .push(word.sequence);
+                }
+            }
+        }
+
+        let lists = index.fields.values_mut().chain(index.dialects.values_mut());
+        for seq_ids in lists.chain(index.loans.values_mut()) {
+            seq_ids.sort_unstable();
+            seq_ids.dedup();
+        }
+
+        index
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct WordRetrieve<'a> {
     storage: &'a ResourceStorage,
@@ -15,12 +78,41 @@ impl<'a> WordRetrieve<'a> {
 
     /// Get a word by its sequence id
     #[inline]
-    pub fn by_sequence(&self, seq_id: u32) -> Option<&'a Word> {
-        self.storage.dict_data.word_data.words.get(seq_id as u64)
+    pub fn by_sequence(&self, seq_id: u32) -> Option<Cow<'a, Word>> {
+        self.storage.dict_data.word_data.words.get(seq_id)
+    }
+
+    /// Returns an iterator over all words
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'a, Word>> {
+        self.storage.dict_data.word_data.words.iter()
+    }
+
+    /// Returns the sequence ids of all words with a sense used in `field`
+    #[inline]
+    pub fn by_field(&self, field: Field) -> &'a [u32] {
+        seq_ids(&self.tag_index().fields, field as u8)
+    }
+
+    /// Returns the sequence ids of all words with a sense used in `dialect`
+    #[inline]
+    pub fn by_dialect(&self, dialect: Dialect) -> &'a [u32] {
+        seq_ids(&self.tag_index().dialects, dialect as u8)
+    }
+
+    /// Returns the sequence ids of all loanwords (gairaigo) from `language`
+    #[inline]
+    pub fn by_loan_language(&self, language: ForeignLanguage) -> &'a [u32] {
+        seq_ids(&self.tag_index().loans, language as u8)
+    }
+
+    #[inline]
+    fn tag_index(&self) -> &'a WordTagIndex {
+        &self.storage.dict_data.word_data.tag_index
     }
 
     /// returns an iterator over all irregular ichidan words
-    pub fn irregular_ichidan(&'a self) -> impl Iterator<Item = &'a Word> {
+    pub fn irregular_ichidan(&'a self) -> impl Iterator<Item = Cow<'a, Word>> {
         self.storage
             .dict_data
             .word_data
@@ -35,3 +127,9 @@ impl<'a> WordRetrieve<'a> {
         self.storage.dict_data.word_data.irregular_ichidan.len()
     }
 }
+
+/// Returns the sequence ids stored for `tag` in `map`
+#[inline]
+fn seq_ids(map: &HashMap<u8, Vec<u32>>, tag: u8) -> &[u32] {
+    map.get(&tag).map(|i| i.as_slice()).unwrap_or_default()
+}
@@ -0,0 +1,138 @@
+pub mod path;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{BufRead, BufReader},
+    path::Path,
+    str,
+};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::error::Error;
+
+/// Stroke paths of a single kanji, ordered by their stroke number
+type KanjiStrokes = BTreeMap<u16, String>;
+
+/// Parses all stroke paths of a KanjiVG source. `path` can either be the combined `kanjivg.xml`
+/// file or a folder containing the SVG files of each kanji. Variants of kanji are skipped.
+/// Returns the stroke paths of each kanji in stroke order
+pub fn parse<P: AsRef<Path>>(path: P) -> Result<HashMap<char, Vec<String>>, Error> {
+    let path = path.as_ref();
+    let mut strokes: HashMap<char, KanjiStrokes> = HashMap::new();
+
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.extension().map(|i| i == "svg").unwrap_or(false) {
+                parse_reader(BufReader::new(File::open(path)?), &mut strokes)?;
+            }
+        }
+    } else {
+        parse_reader(BufReader::new(File::open(path)?), &mut strokes)?;
+    }
+
+    Ok(strokes
+        .into_iter()
+        .map(|(literal, strokes)| (literal, strokes.into_values().collect()))
+        .collect())
+}
+
+/// Parses all stroke paths within `reader` into `out`
+fn parse_reader<R: BufRead>(reader: R, out: &mut HashMap<char, KanjiStrokes>) -> Result<(), Error> {
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) | Event::Empty(ref e) if e.name() == b"path" => {
+                if let Some((literal, nr, path)) = parse_path(e) {
+                    out.entry(literal).or_default().insert(nr, path);
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+/// Parses a single `path` element into the kanji literal, the stroke number and the path data
+fn parse_path(element: &BytesStart) -> Option<(char, u16, String)> {
+    let mut id = None;
+    let mut data = None;
+
+    for attribute in element.attributes().filter_map(|i| i.ok()) {
+        match attribute.key {
+            b"id" => id = Some(str::from_utf8(&attribute.value).ok()?.to_string()),
+            b"d" => data = Some(str::from_utf8(&attribute.value).ok()?.trim().to_string()),
+            _ => (),
+        }
+    }
+
+    let (literal, nr) = parse_stroke_id(&id?)?;
+    let data = data.filter(|i| is_path_data(i))?;
+    Some((literal, nr, data))
+}
+
+/// Parses a stroke id like `kvg:04e00-s1` into the kanji literal and the stroke number. Returns
+/// `None` for ids of kanji variants (eg. `kvg:04e00-Kaisho-s1`)
+fn parse_stroke_id(id: &str) -> Option<(char, u16)> {
+    let (code, nr) = id.strip_prefix("kvg:")?.split_once("-s")?;
+    let literal = char::from_u32(u32::from_str_radix(code, 16).ok()?)?;
+    Some((literal, nr.parse().ok()?))
+}
+
+/// Returns `true` if `data` only contains characters allowed in SVG path data
+fn is_path_data(data: &str) -> bool {
+    !data.is_empty()
+        && data.chars().all(|c| {
+            c.is_ascii_alphanumeric() || c.is_ascii_whitespace() || matches!(c, '.' | ',' | '-')
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SVG: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="109" height="109" viewBox="0 0 109 109">
+<g id="kvg:StrokePaths_05341">
+<g id="kvg:05341" kvg:element="十">
+	<path id="kvg:05341-s2" kvg:type="㇑" d="M53.5,13.5c0.88,0.88,1.25,2.5,1.25,4.5c0,11.25,0,68.5,0,78.5"/>
+	<path id="kvg:05341-s1" kvg:type="㇐" d="M13.25,52.55c3,0.7,6.38,0.63,9.4,0.29"/>
+	<path id="kvg:05341-Kaisho-s1" d="M1,1"/>
+</g>
+</g>
+</svg>"#;
+
+    #[test]
+    fn test_parse_stroke_id() {
+        assert_eq!(parse_stroke_id("kvg:04e00-s1"), Some(('一', 1)));
+        assert_eq!(parse_stroke_id("kvg:05341-s12"), Some(('十', 12)));
+        assert_eq!(parse_stroke_id("kvg:04e00-Kaisho-s1"), None);
+        assert_eq!(parse_stroke_id("kvg:05341"), None);
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let mut out = HashMap::new();
+        parse_reader(SVG.as_bytes(), &mut out).unwrap();
+
+        let strokes: Vec<_> = out[&'十'].values().collect();
+        assert_eq!(
+            strokes,
+            vec![
+                "M13.25,52.55c3,0.7,6.38,0.63,9.4,0.29",
+                "M53.5,13.5c0.88,0.88,1.25,2.5,1.25,4.5c0,11.25,0,68.5,0,78.5"
+            ]
+        );
+    }
+}
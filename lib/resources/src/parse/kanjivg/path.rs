@@ -0,0 +1,221 @@
+use crate::models::storage::handwriting::Point;
+
+/// Amount of points a cubic bézier curve gets sampled into
+const CURVE_SEGMENTS: usize = 8;
+
+/// A token of SVG path data
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Command(char),
+    Number(f32),
+}
+
+/// Converts SVG path data into the points it passes in drawing order. Curves get sampled into
+/// straight segments. Supports the commands used by KanjiVG (`M`, `L`, `H`, `V`, `C`, `S`, `Z`
+/// and their relative versions). Returns `None` for invalid or unsupported path data
+pub fn points(data: &str) -> Option<Vec<Point>> {
+    let tokens = tokenize(data)?;
+    let mut tokens = tokens.as_slice();
+
+    let mut out: Vec<Point> = vec![];
+    let mut pos = [0.0, 0.0];
+    let mut start = pos;
+    // Second control point of the previous curve
+    let mut last_ctrl: Option<Point> = None;
+    let mut command: Option<char> = None;
+
+    while !tokens.is_empty() {
+        if let Token::Command(c) = tokens[0] {
+            command = Some(c);
+            tokens = &tokens[1..];
+        }
+
+        let c = command?;
+        let argc = match c.to_ascii_uppercase() {
+            'Z' => 0,
+            'H' | 'V' => 1,
+            'M' | 'L' => 2,
+            'S' => 4,
+            'C' => 6,
+            _ => return None,
+        };
+
+        let args = numbers(tokens, argc)?;
+        tokens = &tokens[argc..];
+
+        let offset = if c.is_ascii_lowercase() {
+            pos
+        } else {
+            [0.0, 0.0]
+        };
+        let point = |i: usize| [args[i] + offset[0], args[i + 1] + offset[1]];
+
+        let mut ctrl = None;
+        match c.to_ascii_uppercase() {
+            'M' => {
+                pos = point(0);
+                start = pos;
+                out.push(pos);
+            }
+            'L' => {
+                pos = point(0);
+                out.push(pos);
+            }
+            'H' => {
+                pos[0] = args[0] + offset[0];
+                out.push(pos);
+            }
+            'V' => {
+                pos[1] = args[0] + offset[1];
+                out.push(pos);
+            }
+            'C' => {
+                let end = point(4);
+                cubic(&mut out, pos, point(0), point(2), end);
+                ctrl = Some(point(2));
+                pos = end;
+            }
+            'S' => {
+                // The first control point is the reflection of the previous curves second one
+                let first = match last_ctrl {
+                    Some(last) => [2.0 * pos[0] - last[0], 2.0 * pos[1] - last[1]],
+                    None => pos,
+                };
+                let end = point(2);
+                cubic(&mut out, pos, first, point(0), end);
+                ctrl = Some(point(0));
+                pos = end;
+            }
+            _ => {
+                pos = start;
+                out.push(pos);
+            }
+        }
+        last_ctrl = ctrl;
+
+        command = match c {
+            // Coordinates following a move are implicit line commands
+            'M' => Some('L'),
+            'm' => Some('l'),
+            'Z' | 'z' => None,
+            c => Some(c),
+        };
+    }
+
+    (!out.is_empty()).then(|| out)
+}
+
+/// Returns the first `count` tokens as numbers
+fn numbers(tokens: &[Token], count: usize) -> Option<Vec<f32>> {
+    tokens
+        .get(..count)?
+        .iter()
+        .map(|token| match token {
+            Token::Number(nr) => Some(*nr),
+            Token::Command(_) => None,
+        })
+        .collect()
+}
+
+/// Appends the points of a cubic bézier curve from `p0` to `p3` to `out`, excluding `p0`
+fn cubic(out: &mut Vec<Point>, p0: Point, p1: Point, p2: Point, p3: Point) {
+    for i in 1..=CURVE_SEGMENTS {
+        let t = i as f32 / CURVE_SEGMENTS as f32;
+        let mt = 1.0 - t;
+
+        let a = mt * mt * mt;
+        let b = 3.0 * mt * mt * t;
+        let c = 3.0 * mt * t * t;
+        let d = t * t * t;
+
+        out.push([
+            a * p0[0] + b * p1[0] + c * p2[0] + d * p3[0],
+            a * p0[1] + b * p1[1] + c * p2[1] + d * p3[1],
+        ]);
+    }
+}
+
+/// Splits path data into commands and numbers. Numbers can be separated by whitespace, commas,
+/// a sign or a second decimal point (eg. `0.5.5-1`)
+fn tokenize(data: &str) -> Option<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = data.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_ascii_whitespace() || c == ',' {
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() {
+            tokens.push(Token::Command(c));
+            continue;
+        }
+
+        if !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')) {
+            return None;
+        }
+
+        let mut has_dot = c == '.';
+        let mut end = start + c.len_utf8();
+        while let Some(&(pos, c)) = chars.peek() {
+            if c.is_ascii_digit() || (c == '.' && !has_dot) {
+                has_dot |= c == '.';
+                end = pos + c.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        tokens.push(Token::Number(data[start..end].parse().ok()?));
+    }
+
+    Some(tokens)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("M1.5-2c0.5.5,3 4"),
+            Some(vec![
+                Token::Command('M'),
+                Token::Number(1.5),
+                Token::Number(-2.0),
+                Token::Command('c'),
+                Token::Number(0.5),
+                Token::Number(0.5),
+                Token::Number(3.0),
+                Token::Number(4.0),
+            ])
+        );
+        assert_eq!(tokenize("M1,1 #"), None);
+    }
+
+    #[test]
+    fn test_points() {
+        assert_eq!(
+            points("M10,10L20,10l0,5h-5V30z"),
+            Some(vec![
+                [10.0, 10.0],
+                [20.0, 10.0],
+                [20.0, 15.0],
+                [15.0, 15.0],
+                [15.0, 30.0],
+                [10.0, 10.0],
+            ])
+        );
+
+        let curve = points("M10,10c0,10,0,20,0,30s0,10,0,20").unwrap();
+        assert_eq!(curve.len(), 1 + 2 * CURVE_SEGMENTS);
+        assert_eq!(curve[CURVE_SEGMENTS], [10.0, 40.0]);
+        assert_eq!(curve.last(), Some(&[10.0, 60.0]));
+
+        assert_eq!(points(""), None);
+        assert_eq!(points("10,10"), None);
+        assert_eq!(points("M10,10 A1,1"), None);
+    }
+}
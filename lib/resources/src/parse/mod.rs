@@ -4,5 +4,7 @@ pub mod jmdict;
 pub mod jmnedict;
 pub mod kanji_ele;
 pub mod kanjidict;
+pub mod kanjivg;
 pub mod parser;
 pub mod radicals;
+pub mod tatoeba;
@@ -0,0 +1,267 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    str::FromStr,
+};
+
+use japanese::{furigana, JapaneseExt};
+use sentence_reader::Part;
+use types::jotoba::{
+    kanji::Kanji,
+    languages::Language,
+    sentences::{Sentence, Translation},
+    words::Word,
+};
+
+use crate::{models::storage::SentenceStorage, parse::error::Error};
+
+/// A single line of a tatoeba sentences export
+struct RawSentence {
+    id: u32,
+    language: Language,
+    text: String,
+}
+
+/// Parses the tatoeba sentences (`id\tlang\ttext`) and links (`id\ttranslation_id`) exports into
+/// japanese `Sentence`s with all their translations. Sentences without a translation into one of
+/// the supported languages are skipped. The furigana of the returned sentences is set to the
+/// plain japanese text and can be generated with `assign_words`
+pub fn parse<P: AsRef<Path>>(sentences: P, links: P) -> Result<Vec<Sentence>, Error> {
+    let mut japanese: HashMap<u32, String> = HashMap::new();
+    let mut foreign: HashMap<u32, Translation> = HashMap::new();
+
+    for line in BufReader::new(File::open(sentences)?).lines() {
+        let raw = match parse_sentence(&line?) {
+            Some(raw) => raw,
+            None => continue,
+        };
+
+        if raw.language == Language::Japanese {
+            japanese.insert(raw.id, raw.text);
+        } else {
+            let translation = Translation {
+                text: raw.text,
+                language: raw.language,
+            };
+            foreign.insert(raw.id, translation);
+        }
+    }
+
+    let mut translations: HashMap<u32, Vec<Translation>> = HashMap::new();
+
+    for line in BufReader::new(File::open(links)?).lines() {
+        let (id, translation_id) = match parse_link(&line?) {
+            Some(link) => link,
+            None => continue,
+        };
+
+        if !japanese.contains_key(&id) {
+            continue;
+        }
+
+        if let Some(translation) = foreign.get(&translation_id) {
+            let sentence_tr = translations.entry(id).or_default();
+            // Tatoeba can have multiple translations per language. Only keep the first one
+            if !sentence_tr
+                .iter()
+                .any(|i| i.language == translation.language)
+            {
+                sentence_tr.push(translation.clone());
+            }
+        }
+    }
+
+    let mut sentences = translations
+        .into_iter()
+        .map(|(id, translations)| {
+            let text = japanese.remove(&id).unwrap();
+            Sentence::new(id, text.clone(), text, translations)
+        })
+        .collect::<Vec<_>>();
+
+    sentences.sort_by_key(|i| i.id);
+    Ok(sentences)
+}
+
+/// Parses a single line of the tatoeba sentences export. Returns `None` if the line is invalid
+/// or the language isn't supported
+fn parse_sentence(line: &str) -> Option<RawSentence> {
+    let mut split = line.splitn(3, '\t');
+
+    let id: u32 = split.next()?.parse().ok()?;
+    let language = Language::from_str(split.next()?).ok()?;
+    let text = split.next()?.trim().to_string();
+
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(RawSentence { id, language, text })
+}
+
+/// Parses a single line of the tatoeba links export
+fn parse_link(line: &str) -> Option<(u32, u32)> {
+    let mut split = line.split('\t');
+    let id: u32 = split.next()?.trim().parse().ok()?;
+    let translation_id: u32 = split.next()?.trim().parse().ok()?;
+    Some((id, translation_id))
+}
+
+/// Analyzes all sentences to find the words they contain. This generates the sentences furigana
+/// out of the readings of each part, guesses their jlpt level and sets `sentences_available` of
+/// all found words
+pub fn assign_words(sentences: &mut [Sentence], words: &mut [Word], kanji: &[Kanji]) {
+    let word_map = word_map(words);
+
+    let kanji: HashMap<char, &Kanji> = kanji.iter().map(|i| (i.literal, i)).collect();
+    let retrieve = |literal: String| {
+        let k = kanji.get(&literal.chars().next()?)?;
+        Some((k.kunyomi.clone(), k.onyomi.clone()))
+    };
+
+    for sentence in sentences.iter_mut() {
+        // Use all parts since `parse` doesn't return sentences consisting of a single word
+        // without inflections
+        let parts = sentence_reader::Parser::new(&sentence.japanese).parts();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let lang_mask = sentence.calc_lang_mask();
+        let mut jlpt_guess: Option<u8> = None;
+
+        for part in parts.iter() {
+            let word_pos = match word_map.get(&part.get_normalized()) {
+                Some(pos) => *pos,
+                None => continue,
+            };
+
+            let word = &mut words[word_pos];
+            word.sentences_available |= lang_mask;
+
+            // Lower jlpt levels are harder so the sentence is at least as hard as its hardest word
+            if let Some(jlpt) = word.jlpt_lvl {
+                jlpt_guess = Some(jlpt_guess.map_or(jlpt, |i| i.min(jlpt)));
+            }
+        }
+
+        sentence.furigana = sentence_furigana(&sentence.japanese, &parts, retrieve);
+        sentence.jlpt_guess = jlpt_guess;
+    }
+}
+
+/// Builds a `SentenceStorage` out of `sentences`
+pub fn build_storage(sentences: Vec<Sentence>) -> SentenceStorage {
+    let mut jlpt_map: HashMap<u8, Vec<u32>> = HashMap::new();
+
+    for sentence in sentences.iter() {
+        if let Some(jlpt) = sentence.jlpt_guess {
+            jlpt_map.entry(jlpt).or_default().push(sentence.id);
+        }
+    }
+
+    let sentences = sentences.into_iter().map(|i| (i.id as u64, i)).collect();
+
+    SentenceStorage {
+        sentences,
+        jlpt_map,
+    }
+}
+
+/// Maps all readings of the words to the position of the word in `words`. Common words are
+/// preferred if multiple words share the same reading
+fn word_map(words: &[Word]) -> HashMap<String, usize> {
+    let mut map: HashMap<String, usize> = HashMap::new();
+
+    for (pos, word) in words.iter().enumerate() {
+        for reading in word.reading_iter(true) {
+            let replace = match map.get(reading.reading.as_str()) {
+                Some(other) => !words[*other].is_common() && word.is_common(),
+                None => true,
+            };
+
+            if replace {
+                map.insert(reading.reading.clone(), pos);
+            }
+        }
+    }
+
+    map
+}
+
+/// Builds the encoded furigana of a whole sentence. Text which isn't covered by `parts` (eg.
+/// symbols) gets copied as it is
+fn sentence_furigana<R>(text: &str, parts: &[Part], retrieve: R) -> String
+where
+    R: furigana::generate::RetrieveKanji + Copy,
+{
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut pos = 0;
+
+    for part in parts {
+        let surface = part.get_inflected();
+        let start = match text[pos..].find(&surface) {
+            Some(start) => pos + start,
+            None => continue,
+        };
+
+        out.push_str(&text[pos..start]);
+        out.push_str(&part_furigana(part, retrieve));
+
+        pos = start + surface.len();
+    }
+
+    out.push_str(&text[pos..]);
+    out
+}
+
+/// Generates the encoded furigana of a single part out of the surface and kana reading of each
+/// of its morphemes
+fn part_furigana<R>(part: &Part, retrieve: R) -> String
+where
+    R: furigana::generate::RetrieveKanji + Copy,
+{
+    let mut out = String::new();
+
+    for morpheme in part.morphemes() {
+        let reading = morpheme.reading.to_hiragana();
+        if morpheme.surface.has_kanji() && !reading.is_empty() && reading.is_kana() {
+            out.push_str(&furigana::generate::checked(
+                retrieve,
+                &morpheme.surface,
+                &reading,
+            ));
+        } else {
+            out.push_str(&morpheme.surface);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_sentence() {
+        let sentence = parse_sentence("4707\tjpn\t私は学生です。").unwrap();
+        assert_eq!(sentence.id, 4707);
+        assert_eq!(sentence.language, Language::Japanese);
+        assert_eq!(sentence.text, "私は学生です。");
+
+        let sentence = parse_sentence("1276\teng\tLet's try something.").unwrap();
+        assert_eq!(sentence.language, Language::English);
+
+        assert!(parse_sentence("1\tzzz\tunsupported").is_none());
+        assert!(parse_sentence("invalid").is_none());
+    }
+
+    #[test]
+    fn test_parse_link() {
+        assert_eq!(parse_link("1\t77"), Some((1, 77)));
+        assert_eq!(parse_link("1"), None);
+    }
+}
@@ -1,9 +1,9 @@
 use std::io::Read;
 
 use bitflags::BitFlag;
-use byteorder::{ByteOrder, ReadBytesExt};
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
 use types::jotoba::languages::Language;
-use vector_space_model::traits::Decodable;
+use vector_space_model::traits::{Decodable, Encodable};
 
 /// A document belongs to a document-vector and contains the seq_ids of all items who represent
 /// this document
@@ -25,6 +25,19 @@ impl Decodable for MultiDocument {
     }
 }
 
+impl Encodable for MultiDocument {
+    fn encode<T: ByteOrder>(&self) -> Result<Vec<u8>, vector_space_model::Error> {
+        let mut out = Vec::with_capacity(2 + self.seq_ids.len() * 4);
+
+        out.write_u16::<T>(self.seq_ids.len() as u16)?;
+        for seq_id in self.seq_ids.iter() {
+            out.write_u32::<T>(*seq_id)?;
+        }
+
+        Ok(out)
+    }
+}
+
 /// A document belongs to a document-vector and contains a single seq_id which means this
 /// document represents a single resource item.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
@@ -41,6 +54,14 @@ impl Decodable for SingleDocument {
     }
 }
 
+impl Encodable for SingleDocument {
+    fn encode<T: ByteOrder>(&self) -> Result<Vec<u8>, vector_space_model::Error> {
+        let mut out = Vec::with_capacity(4);
+        out.write_u32::<T>(self.seq_id)?;
+        Ok(out)
+    }
+}
+
 /// A sentence document represents a single sentence, referenced by its ID, and a bitmask of
 /// supported languages for more efficient searching
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
@@ -58,6 +79,15 @@ impl Decodable for SentenceDocument {
     }
 }
 
+impl Encodable for SentenceDocument {
+    fn encode<T: ByteOrder>(&self) -> Result<Vec<u8>, vector_space_model::Error> {
+        let mut out = Vec::with_capacity(6);
+        out.write_u32::<T>(self.seq_id)?;
+        out.write_u16::<T>(self.mask)?;
+        Ok(out)
+    }
+}
+
 impl SentenceDocument {
     /// Returns true if the given SentenceDocument has a translation for `language`
     #[inline]
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs::File,
+    hash::Hash,
+    io::BufWriter,
+    path::Path,
+};
+
+use bktree::BkTree;
+use byteorder::ByteOrder;
+use vector_space_model::{
+    build::IndexBuilder, document_vector, metadata::Metadata, traits::Encodable,
+};
+
+use super::document::MultiDocument;
+
+/// A single document to be indexed. `terms` are used to build the document vector and
+/// `document` gets encoded into the index to be able to resolve the vector later on
+#[derive(Clone)]
+pub(crate) struct IndexDoc<D> {
+    pub terms: Vec<String>,
+    pub document: D,
+}
+
+impl<D> IndexDoc<D> {
+    #[inline]
+    pub fn new(terms: Vec<String>, document: D) -> Self {
+        Self { terms, document }
+    }
+}
+
+impl<D> document_vector::Document for IndexDoc<D> {
+    #[inline]
+    fn get_terms(&self) -> Vec<String> {
+        self.terms.clone()
+    }
+}
+
+impl<D: Encodable> Encodable for IndexDoc<D> {
+    #[inline]
+    fn encode<T: ByteOrder>(&self) -> Result<Vec<u8>, vector_space_model::Error> {
+        self.document.encode::<T>()
+    }
+}
+
+/// Builds a new index out of `docs` and writes it to `path`
+pub(crate) fn write_index<D, M, P>(
+    docs: impl Iterator<Item = IndexDoc<D>>,
+    metadata: M,
+    path: P,
+) -> Result<(), Box<dyn Error>>
+where
+    D: Encodable,
+    M: Metadata + Encodable,
+    P: AsRef<Path>,
+{
+    let mut builder = IndexBuilder::new();
+
+    for doc in docs.filter(|i| !i.terms.is_empty()) {
+        builder.insert_new_vec(doc);
+    }
+
+    let out = BufWriter::new(File::create(path)?);
+    builder.build(metadata, out)?;
+
+    Ok(())
+}
+
+/// Groups items with the same key into a single `MultiDocument`. This prevents the index from
+/// holding multiple equal vectors
+pub(crate) fn group_multi_docs<K, I>(items: I) -> impl Iterator<Item = (K, MultiDocument)>
+where
+    K: Hash + Eq,
+    I: Iterator<Item = (K, u32)>,
+{
+    let mut map: HashMap<K, Vec<u32>> = HashMap::new();
+
+    for (key, seq_id) in items {
+        let seq_ids = map.entry(key).or_default();
+        if !seq_ids.contains(&seq_id) {
+            seq_ids.push(seq_id);
+        }
+    }
+
+    map.into_iter()
+        .map(|(key, seq_ids)| (key, MultiDocument { seq_ids }))
+}
+
+/// Builds a term tree containing all `terms`
+pub(crate) fn term_tree<'a, I>(terms: I) -> BkTree<String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let mut terms = terms.collect::<Vec<_>>();
+    terms.sort_unstable();
+    terms.dedup();
+
+    let mut tree = BkTree::new();
+    for term in terms {
+        tree.insert(term.clone());
+    }
+    tree
+}
+
+/// Writes `value` bincode encoded into `path`
+pub(crate) fn write_bincode<T, P>(value: &T, path: P) -> Result<(), Box<dyn Error>>
+where
+    T: serde::Serialize,
+    P: AsRef<Path>,
+{
+    let out = BufWriter::new(File::create(path)?);
+    bincode::serialize_into(out, value)?;
+    Ok(())
+}
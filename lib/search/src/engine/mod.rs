@@ -1,9 +1,11 @@
 pub mod document;
 pub mod guess;
+pub(crate) mod index_builder;
 pub mod kanji;
 pub mod metadata;
 pub mod names;
 pub mod radical;
+pub mod regex_index;
 pub mod result;
 pub mod result_item;
 pub mod search_task;
@@ -11,10 +13,12 @@ pub mod sentences;
 pub mod simple_gen_doc;
 pub mod words;
 
-use std::hash::Hash;
+use std::{borrow::Cow, collections::HashMap, hash::Hash, path::Path};
 
+use bktree::BkTree;
 use config::Config;
 
+use regex_index::RegexTermIndex;
 use resources::models::storage::ResourceStorage;
 pub use search_task::SearchTask;
 use types::jotoba::languages::Language;
@@ -22,36 +26,115 @@ use vector_space_model::{
     document_vector, metadata::Metadata, traits::Decodable, DocumentVector, Index,
 };
 
-/// Load all indexes for word search engine
-pub fn load_indexes(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
-    let index_path = config.get_indexes_source().to_owned();
+/// All indexes used for searching
+pub struct Indexes {
+    pub(crate) word_native: words::native::index::Index,
+    pub(crate) word_native_regex: words::native::regex_index::RegexSearchIndex,
+    pub(crate) word_foreign: HashMap<Language, words::foreign::index::Index>,
+    pub(crate) word_foreign_term_trees: HashMap<Language, BkTree<String>>,
+    pub(crate) word_foreign_regex: HashMap<Language, RegexTermIndex>,
+    pub(crate) name_native: names::native::index::Index,
+    pub(crate) name_foreign: names::foreign::index::Index,
+    pub(crate) name_foreign_term_tree: BkTree<String>,
+    pub(crate) name_regex: RegexTermIndex,
+    pub(crate) sentence_native: sentences::native::index::Index,
+    pub(crate) sentence_foreign: HashMap<Language, sentences::foreign::index::Index>,
+    pub(crate) radical: radical::index::RadicalIndex,
+}
 
-    rayon::scope(|s| {
-        s.spawn(|_| {
-            words::native::index::load(config.get_indexes_source());
-        });
-        s.spawn(|_| {
-            words::native::regex_index::load(config.get_indexes_source());
-        });
-        s.spawn(|_| {
-            words::foreign::index::load(index_path).expect("failed to load index");
-        });
-        s.spawn(|_| {
-            names::foreign::index::load(&config);
-        });
-        s.spawn(|_| {
-            names::native::index::load(&config);
-        });
-        s.spawn(|_| {
-            sentences::native::index::load(&config);
-        });
-        s.spawn(|_| {
-            radical::index::load(&config).expect("Failed to load radical index");
-        });
-        s.spawn(|_| {
-            sentences::foreign::index::load(&config).expect("Failed to load index");
+impl Indexes {
+    /// Load all indexes for the search engines. Panics if a required index can't be loaded
+    pub fn load(config: &Config) -> Self {
+        let index_path = config.get_indexes_source();
+
+        let mut word_native = None;
+        let mut word_native_regex = None;
+        let mut word_foreign = None;
+        let mut word_foreign_regex = None;
+        let mut name_native = None;
+        let mut name_foreign = None;
+        let mut name_regex = None;
+        let mut sentence_native = None;
+        let mut sentence_foreign = None;
+        let mut radical = None;
+
+        rayon::scope(|s| {
+            s.spawn(|_| {
+                word_native = Some(words::native::index::load(index_path));
+            });
+            s.spawn(|_| {
+                word_native_regex = Some(words::native::regex_index::load(index_path));
+            });
+            s.spawn(|_| {
+                word_foreign =
+                    Some(words::foreign::index::load(index_path).expect("failed to load index"));
+            });
+            s.spawn(|_| {
+                word_foreign_regex = Some(
+                    words::foreign::regex_index::load(index_path)
+                        .expect("failed to load gloss regex indexes"),
+                );
+            });
+            s.spawn(|_| {
+                name_regex = Some(names::regex_index::load(index_path));
+            });
+            s.spawn(|_| {
+                name_foreign = Some(names::foreign::index::load(config));
+            });
+            s.spawn(|_| {
+                name_native = Some(names::native::index::load(config));
+            });
+            s.spawn(|_| {
+                sentence_native = Some(sentences::native::index::load(config));
+            });
+            s.spawn(|_| {
+                radical = Some(radical::index::load(config).expect("Failed to load radical index"));
+            });
+            s.spawn(|_| {
+                sentence_foreign =
+                    Some(sentences::foreign::index::load(config).expect("Failed to load index"));
+            });
         });
-    });
+
+        // All loaders either returned a value or panicked, which gets propagated by the scope
+        let (word_foreign, word_foreign_term_trees) = word_foreign.unwrap();
+        let (name_foreign, name_foreign_term_tree) = name_foreign.unwrap();
+
+        Self {
+            word_native: word_native.unwrap(),
+            word_native_regex: word_native_regex.unwrap(),
+            word_foreign,
+            word_foreign_term_trees,
+            word_foreign_regex: word_foreign_regex.unwrap(),
+            name_native: name_native.unwrap(),
+            name_foreign,
+            name_foreign_term_tree,
+            name_regex: name_regex.unwrap(),
+            sentence_native: sentence_native.unwrap(),
+            sentence_foreign: sentence_foreign.unwrap(),
+            radical: radical.unwrap(),
+        }
+    }
+}
+
+/// Builds all indexes required by `Indexes::load` out of `storage` and writes them into `path`
+pub fn build_indexes<P: AsRef<Path>>(
+    storage: &ResourceStorage,
+    path: P,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = path.as_ref();
+    std::fs::create_dir_all(path)?;
+
+    words::native::index::build(storage, path)?;
+    words::native::regex_index::build(storage, path)?;
+    words::foreign::index::build(storage, path)?;
+    words::foreign::regex_index::build(storage, path)?;
+    names::native::index::build(storage, path)?;
+    names::foreign::index::build(storage, path)?;
+    names::regex_index::build(storage, path)?;
+    sentences::native::index::build(storage, path)?;
+    sentences::foreign::index::build(storage, path)?;
+    radical::index::build(storage, path)?;
 
     Ok(())
 }
@@ -61,8 +144,9 @@ pub trait Indexable {
     type Document: Decodable + Clone + 'static + Eq + Hash + Send;
 
     fn get_index(
+        indexes: &Indexes,
         language: Option<Language>,
-    ) -> Option<&'static Index<Self::Document, Self::Metadata>>;
+    ) -> Option<&Index<Self::Document, Self::Metadata>>;
 }
 
 pub trait DocumentGenerateable {
@@ -71,16 +155,17 @@ pub trait DocumentGenerateable {
 
 pub trait SearchEngine: Indexable {
     type GenDoc: document_vector::Document + DocumentGenerateable + Send;
-    type Output: PartialEq + Eq + Hash + 'static + Send + Sync;
+    type Output: PartialEq + Eq + Hash + Clone + 'static + Send + Sync;
 
     /// Loads the corresponding Output type from a document
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         input: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>>;
+    ) -> Option<Vec<Cow<'a, Self::Output>>>;
 
     /// Generates a vector for a query, in order to be able to compare results with a vector
     fn gen_query_vector(
+        indexes: &Indexes,
         index: &Index<Self::Document, Self::Metadata>,
         query: &str,
         align: bool,
@@ -89,6 +174,7 @@ pub trait SearchEngine: Indexable {
 
     fn align_query<'b>(
         _original: &'b str,
+        _indexes: &'b Indexes,
         _index: &Index<Self::Document, Self::Metadata>,
         _language: Option<Language>,
     ) -> Option<&'b str> {
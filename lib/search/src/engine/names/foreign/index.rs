@@ -1,59 +1,64 @@
-use std::{fs::File, io::BufReader, path::Path};
+use std::{error::Error, fs::File, io::BufReader, path::Path};
 
 use bktree::BkTree;
 use config::Config;
 use log::info;
-use once_cell::sync::OnceCell;
-use vector_space_model::DefaultMetadata;
+use resources::models::storage::ResourceStorage;
+use vector_space_model::{metadata::IndexVersion, DefaultMetadata};
 
-use crate::engine::document::MultiDocument;
+use super::format_word;
+use crate::engine::{
+    document::MultiDocument,
+    index_builder::{self, IndexDoc},
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<MultiDocument, DefaultMetadata>;
+pub(crate) type Index = vector_space_model::Index<MultiDocument, DefaultMetadata>;
 
-// In-memory storage for foreign name index
-pub(super) static INDEX: OnceCell<Index> = OnceCell::new();
-
-// In-memory storage for foreign name index
-pub(super) static TERM_TREE: OnceCell<BkTree<String>> = OnceCell::new();
-
-/// Load foreign name index
-pub(crate) fn load(config: &Config) {
-    load_term_treepath(config);
+/// Load foreign name index along with its term tree
+pub(crate) fn load(config: &Config) -> (Index, BkTree<String>) {
+    let term_tree = load_term_treepath(config);
 
     let file = Path::new(config.get_indexes_source()).join("name_foreign_index");
     let index = Index::open(file).expect("Could not load foreign name index");
     info!("Loaded foreign name index");
-    INDEX.set(index).ok();
+    (index, term_tree)
 }
 
 /// Load foreign name term tree
-pub fn load_term_treepath(config: &Config) {
+pub fn load_term_treepath(config: &Config) -> BkTree<String> {
     let path = Path::new(config.get_indexes_source()).join("name_foreign_index.tree");
     let file = File::open(path).expect("Failed to parse name term tree");
     let tt =
         bincode::deserialize_from(BufReader::new(file)).expect("Failed to parse name term tree");
     info!("Loaded name term tree");
-
-    TERM_TREE.set(tt).ok();
+    tt
 }
 
-/// Returns the loaded foreign name index
-#[inline]
-pub(crate) fn get() -> &'static Index {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { INDEX.get_unchecked() }
-}
+/// Builds the foreign name index and its term tree and writes them into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let transcriptions = storage
+        .names()
+        .iter()
+        .map(|name| (name.transcription.to_lowercase(), name.sequence));
+
+    let docs = index_builder::group_multi_docs(transcriptions)
+        .map(|(transcription, doc)| {
+            let terms = format_word(&transcription)
+                .into_iter()
+                .filter(|i| !i.is_empty())
+                .collect();
+            IndexDoc::new(terms, doc)
+        })
+        .collect::<Vec<_>>();
+
+    let tree = index_builder::term_tree(docs.iter().flat_map(|i| i.terms.iter()));
+    index_builder::write_bincode(&tree, path.as_ref().join("name_foreign_index.tree"))?;
+
+    let metadata = DefaultMetadata::new(IndexVersion::V1);
+    let file = path.as_ref().join("name_foreign_index");
+    index_builder::write_index(docs.into_iter(), metadata, file)?;
 
-/// Returns the loaded foreign name index
-#[inline]
-pub(crate) fn get_term_tree() -> &'static BkTree<String> {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { TERM_TREE.get_unchecked() }
+    info!("Built foreign name index");
+    Ok(())
 }
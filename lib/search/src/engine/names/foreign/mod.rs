@@ -1,6 +1,10 @@
 pub mod index;
 
-use crate::engine::{document::MultiDocument, simple_gen_doc::GenDoc, Indexable, SearchEngine};
+use std::borrow::Cow;
+
+use crate::engine::{
+    document::MultiDocument, simple_gen_doc::GenDoc, Indexable, Indexes, SearchEngine,
+};
 use resources::models::storage::ResourceStorage;
 use types::jotoba::{languages::Language, names::Name};
 use utils::to_option;
@@ -14,9 +18,10 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         _language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        Some(index::get())
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        Some(&indexes.name_foreign)
     }
 }
 
@@ -25,19 +30,20 @@ impl SearchEngine for Engine {
     type Output = Name;
 
     #[inline]
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
         to_option(
             inp.seq_ids
                 .iter()
-                .map(|i| storage.names().by_sequence(*i).unwrap())
+                .filter_map(|i| storage.names().by_sequence(*i))
                 .collect(),
         )
     }
 
     fn gen_query_vector(
+        _indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         _allow_align: bool,
@@ -50,6 +56,7 @@ impl SearchEngine for Engine {
 
     fn align_query<'b>(
         original: &'b str,
+        indexes: &'b Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         _language: Option<Language>,
     ) -> Option<&'b str> {
@@ -64,9 +71,10 @@ impl SearchEngine for Engine {
             return None;
         }
 
-        let mut res = index::get_term_tree().find(&query_str.to_string(), 1);
+        let tree = &indexes.name_foreign_term_tree;
+        let mut res = tree.find(&query_str.to_string(), 1);
         if res.is_empty() {
-            res = index::get_term_tree().find(&query_str.to_string(), 2);
+            res = tree.find(&query_str.to_string(), 2);
         }
         res.sort_by(|a, b| a.1.cmp(&b.1));
         res.get(0).map(|i| i.0.as_str())
@@ -74,7 +82,7 @@ impl SearchEngine for Engine {
 }
 
 /// Replaces all special characters into spaces so we can split it down into words
-fn format_word(inp: &str) -> Vec<String> {
+pub(crate) fn format_word(inp: &str) -> Vec<String> {
     let mut out = String::from(inp);
     for i in ".,[]() \t\"'\\/-;:".chars() {
         out = out.replace(i, " ");
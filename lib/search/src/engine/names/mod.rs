@@ -1,2 +1,4 @@
 pub mod foreign;
 pub mod native;
+pub mod regex;
+pub mod regex_index;
@@ -1,32 +1,44 @@
-use std::path::Path;
+use std::{error::Error, path::Path};
 
 use config::Config;
 use log::info;
-use once_cell::sync::OnceCell;
-use vector_space_model::DefaultMetadata;
+use resources::models::storage::ResourceStorage;
+use vector_space_model::{metadata::IndexVersion, DefaultMetadata};
 
-use crate::engine::document::MultiDocument;
+use crate::engine::{
+    document::MultiDocument,
+    index_builder::{self, IndexDoc},
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<MultiDocument, DefaultMetadata>;
-
-// In-memory storage for japanese name index
-pub(super) static INDEX: OnceCell<Index> = OnceCell::new();
+pub(crate) type Index = vector_space_model::Index<MultiDocument, DefaultMetadata>;
 
 /// Load japanese name index
-pub(crate) fn load(config: &Config) {
+pub(crate) fn load(config: &Config) -> Index {
     let file = Path::new(config.get_indexes_source()).join("name_jp_index");
     let index = Index::open(file).expect("Could not load japanese name index");
     info!("Loaded japanese name index");
-    INDEX.set(index).ok();
+    index
 }
 
-/// Returns the loaded japanese name index
-#[inline]
-pub(crate) fn get() -> &'static Index {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { INDEX.get_unchecked() }
+/// Builds the japanese name index and writes it into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let readings = storage.names().iter().flat_map(|name| {
+        let kanji = name.kanji.clone().map(|kanji| (kanji, name.sequence));
+        kanji.into_iter().chain(std::iter::once((name.kana.clone(), name.sequence)))
+    });
+
+    let docs = index_builder::group_multi_docs(readings)
+        .map(|(reading, doc)| {
+            let mut terms = tinysegmenter::tokenize(&reading);
+            terms.push(reading);
+            IndexDoc::new(terms, doc)
+        })
+        .collect::<Vec<_>>();
+
+    let metadata = DefaultMetadata::new(IndexVersion::V1);
+    index_builder::write_index(docs.into_iter(), metadata, path.as_ref().join("name_jp_index"))?;
+
+    info!("Built japanese name index");
+    Ok(())
 }
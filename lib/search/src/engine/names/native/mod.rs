@@ -1,6 +1,10 @@
 pub mod index;
 
-use crate::engine::{document::MultiDocument, simple_gen_doc::GenDoc, Indexable, SearchEngine};
+use std::borrow::Cow;
+
+use crate::engine::{
+    document::MultiDocument, simple_gen_doc::GenDoc, Indexable, Indexes, SearchEngine,
+};
 use resources::models::storage::ResourceStorage;
 use types::jotoba::{languages::Language, names::Name};
 use utils::to_option;
@@ -14,9 +18,10 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         _language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        Some(index::get())
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        Some(&indexes.name_native)
     }
 }
 
@@ -25,19 +30,20 @@ impl SearchEngine for Engine {
     type Output = Name;
 
     #[inline]
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
         to_option(
             inp.seq_ids
                 .iter()
-                .map(|i| storage.names().by_sequence(*i).unwrap())
+                .filter_map(|i| storage.names().by_sequence(*i))
                 .collect(),
         )
     }
 
     fn gen_query_vector(
+        _indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         _allow_align: bool,
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use types::jotoba::names::Name;
+
+use crate::{generation::Generation, regex_query::RegexSQuery};
+
+/// Returns all names with a kana, kanji or transcription matching `query`, paired with the
+/// matching text
+pub fn search<'a>(
+    generation: &'a Generation,
+    query: &RegexSQuery,
+) -> Vec<(Cow<'a, Name>, &'a String)> {
+    let name_resources = generation.resources().names();
+
+    let mut out: Vec<_> = generation
+        .indexes()
+        .name_regex
+        .find(&query.get_chars())
+        .into_iter()
+        .filter(|i| query.matches(&i.text))
+        .filter_map(|i| Some((name_resources.by_sequence(i.seq_id)?, &i.text)))
+        .collect();
+
+    // Keep the results in a constant order and each name only once
+    out.sort_by(|a, b| {
+        a.0.sequence
+            .cmp(&b.0.sequence)
+            .then(a.1.len().cmp(&b.1.len()))
+    });
+    out.dedup_by_key(|i| i.0.sequence);
+
+    out
+}
@@ -0,0 +1,38 @@
+use log::{info, warn};
+use resources::models::storage::ResourceStorage;
+use std::{error::Error, fs::File, io::BufReader, path::Path};
+
+use crate::engine::{index_builder, regex_index::RegexTermIndex};
+
+/// Load the name regex index. Wildcard name searches find nothing if the index is missing
+pub fn load<P: AsRef<Path>>(path: P) -> RegexTermIndex {
+    let file = match File::open(path.as_ref().join("name_regex_index")) {
+        Ok(file) => file,
+        Err(err) => {
+            warn!("Can't open name regex index: {}", err);
+            return RegexTermIndex::default();
+        }
+    };
+    let index: RegexTermIndex =
+        bincode::deserialize_from(BufReader::new(file)).expect("Invalid name regex index");
+    info!("Loaded name regex index");
+    index
+}
+
+/// Builds the name regex index out of kana, kanji and transcriptions of all names and writes it
+/// into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let mut index = RegexTermIndex::new();
+
+    for name in storage.names().iter() {
+        index.add_term(&name.kana, name.sequence);
+        if let Some(ref kanji) = name.kanji {
+            index.add_term(kanji, name.sequence);
+        }
+        index.add_term(&name.transcription.to_lowercase(), name.sequence);
+    }
+
+    index_builder::write_bincode(&index, path.as_ref().join("name_regex_index"))?;
+    info!("Built name regex index");
+    Ok(())
+}
@@ -3,10 +3,12 @@ use std::{collections::HashMap, error::Error, fs::File, io::BufReader, path::Pat
 use bktree::BkTree;
 use config::Config;
 use log::info;
-use once_cell::sync::OnceCell;
+use resources::models::storage::ResourceStorage;
 use serde::{Deserialize, Serialize};
 use types::jotoba::kanji::SearchRadicalInfo;
 
+use crate::engine::index_builder;
+
 /// Radicals indexed by its meanings
 #[derive(Serialize, Deserialize)]
 pub struct RadicalIndex {
@@ -14,21 +16,53 @@ pub struct RadicalIndex {
     pub term_tree: BkTree<String>,
 }
 
-pub(super) static RADICAL_INDEX: OnceCell<RadicalIndex> = OnceCell::new();
-
 /// Load the radical index
-pub(crate) fn load(config: &Config) -> Result<(), Box<dyn Error>> {
+pub(crate) fn load(config: &Config) -> Result<RadicalIndex, Box<dyn Error>> {
     let file = File::open(Path::new(config.get_indexes_source()).join("radical_index"))?;
     let index: RadicalIndex = bincode::deserialize_from(BufReader::new(file))?;
-    RADICAL_INDEX.set(index).ok();
     info!("Loaded radical index");
-    Ok(())
+    Ok(index)
 }
 
-/// Returns the radical index
-pub fn get_index() -> &'static RadicalIndex {
-    // Safety: This value never gets written and only set once at startup
-    unsafe { RADICAL_INDEX.get_unchecked() }
+/// Builds the radical index and writes it into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let kanji = storage.kanji();
+    let mut meaning_map: HashMap<String, Vec<SearchRadicalInfo>> = HashMap::new();
+
+    for radical in kanji.radicals() {
+        let meanings = match radical.translations {
+            Some(ref translations) => translations,
+            None => continue,
+        };
+
+        let info = SearchRadicalInfo {
+            literal: radical.literal,
+            frequency: kanji.by_radicals(&[radical.literal]).len() as u16,
+            meanings: meanings.clone(),
+        };
+
+        for meaning in meanings {
+            meaning_map
+                .entry(meaning.to_lowercase())
+                .or_default()
+                .push(info.clone());
+        }
+    }
+
+    // Show most used radicals first
+    for infos in meaning_map.values_mut() {
+        infos.sort_by(|a, b| b.frequency.cmp(&a.frequency));
+    }
+
+    let term_tree = index_builder::term_tree(meaning_map.keys());
+    let index = RadicalIndex {
+        meaning_map,
+        term_tree,
+    };
+
+    index_builder::write_bincode(&index, path.as_ref().join("radical_index"))?;
+    info!("Built radical index");
+    Ok(())
 }
 
 impl RadicalIndex {
@@ -1,11 +1,13 @@
 use types::jotoba::kanji::SearchRadicalInfo;
 
+use crate::generation::Generation;
+
 pub mod index;
 
 /// Finds Radicals by its meaning(s)
-pub fn find(inp_query: &str) -> Vec<&'static SearchRadicalInfo> {
+pub fn find<'a>(generation: &'a Generation, inp_query: &str) -> Vec<&'a SearchRadicalInfo> {
     let mut queries = vec![inp_query];
-    let index = index::get_index();
+    let index = &generation.indexes().radical;
 
     if !index.has_term(inp_query) {
         let mut found = index.term_tree.find(&inp_query.to_string(), 2);
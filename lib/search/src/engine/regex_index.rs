@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Compact index to allow regex search queries over a big amount of terms. Each term is stored
+/// only once and referenced by its position for every character it contains.
+#[derive(Serialize, Deserialize, Default)]
+pub struct RegexTermIndex {
+    terms: Vec<IndexedTerm>,
+    data: HashMap<char, Vec<u32>>,
+}
+
+/// A single `RegexTermIndex` item
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct IndexedTerm {
+    pub text: String,
+    pub seq_id: u32,
+}
+
+impl RegexTermIndex {
+    /// Creates a new empty Index
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new term to the index. The `seq_id` is supposed to be used to resolve `term`
+    pub fn add_term(&mut self, term: &str, seq_id: u32) {
+        let pos = self.terms.len() as u32;
+
+        self.terms.push(IndexedTerm {
+            text: term.to_string(),
+            seq_id,
+        });
+
+        for c in term.chars() {
+            let positions = self.data.entry(c).or_default();
+            // Terms get added in ascending order so it's enough to check the last one
+            if positions.last() != Some(&pos) {
+                positions.push(pos);
+            }
+        }
+    }
+
+    /// Get all indexed terms using all characters in `chars`. Characters which aren't indexed at
+    /// all are ignored
+    pub fn find<'a>(&'a self, chars: &[char]) -> Vec<&'a IndexedTerm> {
+        let mut lists: Vec<&Vec<u32>> = chars.iter().filter_map(|c| self.data.get(c)).collect();
+
+        if lists.is_empty() {
+            return vec![];
+        }
+
+        // Start with the shortest list to keep the amount of lookups low
+        lists.sort_by_key(|i| i.len());
+
+        lists[0]
+            .iter()
+            .filter(|pos| {
+                lists[1..]
+                    .iter()
+                    .all(|list| list.binary_search(pos).is_ok())
+            })
+            .map(|pos| &self.terms[*pos as usize])
+            .collect()
+    }
+
+    /// Returns the amount of indexed terms
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns `true` if there is no term indexed
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let mut index = RegexTermIndex::new();
+        index.add_term("biology", 1);
+        index.add_term("geology", 2);
+        index.add_term("house", 3);
+        index.add_term("dog", 4);
+
+        let found: Vec<_> = index
+            .find(&['o', 'l', 'o', 'g', 'y'])
+            .into_iter()
+            .map(|i| i.seq_id)
+            .collect();
+        assert_eq!(found, vec![1, 2]);
+
+        assert!(index.find(&['x', 'z']).is_empty());
+        assert_eq!(index.find(&['h', '*']).len(), 1);
+    }
+}
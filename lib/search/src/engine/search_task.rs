@@ -4,11 +4,10 @@ use super::{
     result_item::ResultItem,
     SearchEngine,
 };
-use crate::engine::DocumentGenerateable;
+use crate::{engine::DocumentGenerateable, generation::Generation};
 use error::Error;
 use itertools::Itertools;
-use resources::models::storage::ResourceStorage;
-use std::{collections::BinaryHeap, marker::PhantomData};
+use std::{borrow::Cow, collections::BinaryHeap, marker::PhantomData};
 use types::jotoba::languages::Language;
 use vector_space_model::DocumentVector;
 
@@ -16,14 +15,16 @@ pub struct SearchTask<'a, T>
 where
     T: SearchEngine,
 {
+    /// Resources and indexes to search in
+    generation: &'a Generation,
     /// Search query
-    queries: Vec<(&'a str, Option<Language>)>,
+    queries: Vec<(String, Option<Language>)>,
     /// filter out vectors
-    vec_filter: Option<Box<dyn Fn(&T::Document) -> bool>>,
+    vec_filter: Option<Box<dyn Fn(&T::Document) -> bool + 'a>>,
     /// Filter out results
-    res_filter: Option<Box<dyn Fn(&T::Output) -> bool>>,
+    res_filter: Option<Box<dyn Fn(&T::Output) -> bool + 'a>>,
     /// Custom result order function
-    order: Option<Box<dyn Fn(&T::Output, f32, &str, Option<Language>) -> usize>>,
+    order: Option<Box<dyn Fn(&T::Output, f32, &str, Option<Language>) -> usize + 'a>>,
     /// Min relevance returned from vector space algo
     threshold: f32,
     limit: usize,
@@ -39,27 +40,27 @@ where
 {
     /// Creates a new Search task
     #[inline]
-    pub fn new(query: &'a str) -> Self {
-        let mut task = Self::default();
-        task.queries.push((query, None));
+    pub fn new(generation: &'a Generation, query: &str) -> Self {
+        let mut task = Self::empty(generation);
+        task.queries.push((query.to_string(), None));
         task
     }
 
     /// Creates a new Search task with a query assigned language
-    pub fn with_language(query: &'a str, language: Language) -> Self {
-        let mut task = Self::default();
-        task.queries.push((query, Some(language)));
+    pub fn with_language(generation: &'a Generation, query: &str, language: Language) -> Self {
+        let mut task = Self::empty(generation);
+        task.queries.push((query.to_string(), Some(language)));
         task
     }
 
     /// Adds another query to look out for to the search task
-    pub fn add_language_query(&mut self, query: &'a str, language: Language) {
-        self.queries.push((query, Some(language)));
+    pub fn add_language_query(&mut self, query: &str, language: Language) {
+        self.queries.push((query.to_string(), Some(language)));
     }
 
     /// Adds another query to look out for to the search task
-    pub fn add_query(&mut self, query: &'a str) {
-        self.queries.push((query, None));
+    pub fn add_query(&mut self, query: &str) {
+        self.queries.push((query.to_string(), None));
     }
 
     /// Set the total limit. This is the max amount of vectors which will be loaded and processed
@@ -89,7 +90,7 @@ where
     }
 
     /// Set the search task's vector filter.
-    pub fn set_vector_filter<F: 'static>(&mut self, vec_filter: F)
+    pub fn set_vector_filter<F: 'a>(&mut self, vec_filter: F)
     where
         F: Fn(&T::Document) -> bool,
     {
@@ -97,7 +98,7 @@ where
     }
 
     /// Set the search task's result filter.
-    pub fn set_result_filter<F: 'static>(&mut self, res_filter: F)
+    pub fn set_result_filter<F: 'a>(&mut self, res_filter: F)
     where
         F: Fn(&T::Output) -> bool,
     {
@@ -105,7 +106,7 @@ where
     }
 
     /// Set the search task's custom order function
-    pub fn set_order_fn<F: 'static>(&mut self, res_filter: F)
+    pub fn set_order_fn<F: 'a>(&mut self, res_filter: F)
     where
         F: Fn(&T::Output, f32, &str, Option<Language>) -> usize,
     {
@@ -122,15 +123,15 @@ where
     #[inline]
     pub fn has_term(&self) -> bool {
         self.queries.iter().any(|(query, language)| {
-            T::get_index(*language)
+            T::get_index(self.generation.indexes(), *language)
                 .map(|i| i.get_indexer().clone().find_term(query).is_some())
                 .unwrap_or(false)
         })
     }
 
-    pub fn find_exact(&self) -> Result<Vec<ResultItem<&'static T::Output>>, Error> {
+    pub fn find_exact(&self) -> Result<Vec<ResultItem<Cow<'a, T::Output>>>, Error> {
         let (query, lang) = self.queries.get(0).unwrap();
-        let index = T::get_index(*lang).expect("Lang not loaded");
+        let index = T::get_index(self.generation.indexes(), *lang).expect("Lang not loaded");
 
         let extact_query = T::GenDoc::new(vec![query]);
         let document =
@@ -185,14 +186,14 @@ where
     }
 
     /// Runs the search task and returns the result.
-    pub fn find(&self) -> Result<SearchResult<&'static T::Output>, Error> {
+    pub fn find(&self) -> Result<SearchResult<Cow<'a, T::Output>>, Error> {
         let items = self
             .get_queries()
             .map(|(q_str, vec, lang)| self.find_by_vec(vec, &q_str, lang))
             .collect::<Result<Vec<_>, Error>>()?
             .into_iter()
             .flatten()
-            .unique_by(|a| a.item)
+            .unique_by(|a| a.item.clone())
             .take(self.vector_limit)
             .collect::<Vec<_>>();
 
@@ -207,10 +208,11 @@ where
     fn get_queries<'b>(
         &'b self,
     ) -> impl Iterator<Item = (String, DocumentVector<T::GenDoc>, Option<Language>)> + 'b {
+        let indexes = self.generation.indexes();
         self.queries.iter().filter_map(move |(q_str, lang)| {
-            let index = T::get_index(*lang).expect("Lang not loaded");
+            let index = T::get_index(indexes, *lang).expect("Lang not loaded");
             let allow_align = self.allow_align && !self.has_term();
-            let (vec, aligned) = T::gen_query_vector(index, q_str, allow_align, *lang)?;
+            let (vec, aligned) = T::gen_query_vector(indexes, index, q_str, allow_align, *lang)?;
             Some((aligned, vec, *lang))
         })
     }
@@ -220,8 +222,8 @@ where
         q_vec: DocumentVector<T::GenDoc>,
         q_str: &str,
         language: Option<Language>,
-    ) -> Result<Vec<ResultItem<&'static T::Output>>, Error> {
-        let index = T::get_index(language);
+    ) -> Result<Vec<ResultItem<Cow<'a, T::Output>>>, Error> {
+        let index = T::get_index(self.generation.indexes(), language);
         if index.is_none() {
             log::error!("Failed to retrieve {:?} index with language", language);
             return Err(Error::Unexpected);
@@ -245,8 +247,8 @@ where
         q_vec: &DocumentVector<T::GenDoc>,
         q_str: &str,
         language: Option<Language>,
-    ) -> Result<Vec<ResultItem<&'static T::Output>>, Error> {
-        let storage: &'static ResourceStorage = resources::get();
+    ) -> Result<Vec<ResultItem<Cow<'a, T::Output>>>, Error> {
+        let storage = self.generation.resources();
 
         let res = document_vectors
             .filter_map(|i| {
@@ -269,11 +271,12 @@ where
             .flatten()
             .filter(|i| self.filter_result(&i.1))
             .map(|(rel, item)| {
-                let relevance = self.calculate_score(item, rel, q_str, language);
+                let relevance = self.calculate_score(&item, rel, q_str, language);
 
-                language
-                    .map(|i| ResultItem::with_language(item, relevance, i))
-                    .unwrap_or(ResultItem::new(item, relevance))
+                match language {
+                    Some(language) => ResultItem::with_language(item, relevance, language),
+                    None => ResultItem::new(item, relevance),
+                }
             })
             .collect::<Vec<_>>();
         Ok(res)
@@ -285,7 +288,7 @@ where
         language: Option<Language>,
         est_limit: usize,
     ) -> Result<usize, Error> {
-        let index = T::get_index(language);
+        let index = T::get_index(self.generation.indexes(), language);
         if index.is_none() {
             log::error!("Failed to retrieve {:?} index with language", language);
             return Err(Error::Unexpected);
@@ -300,7 +303,7 @@ where
             .get_all_iter(&query_dimensions)
             .take(self.vector_limit);
 
-        let storage = resources::get();
+        let storage = self.generation.resources();
 
         let res = document_vectors
             .filter_map(|i| {
@@ -355,12 +358,12 @@ where
     fn filter_vector(&self, vec: &T::Document) -> bool {
         self.vec_filter.as_ref().map(|i| i(vec)).unwrap_or(true)
     }
-}
 
-impl<'a, T: SearchEngine> Default for SearchTask<'a, T> {
+    /// Creates a new search task without any query
     #[inline]
-    fn default() -> Self {
+    fn empty(generation: &'a Generation) -> Self {
         Self {
+            generation,
             queries: Vec::with_capacity(1),
             vec_filter: None,
             res_filter: None,
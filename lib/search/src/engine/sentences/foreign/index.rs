@@ -1,20 +1,23 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, error::Error, path::Path};
 
 use config::Config;
 use log::{error, info};
-use once_cell::sync::OnceCell;
+use resources::models::storage::ResourceStorage;
 use types::jotoba::languages::Language;
+use vector_space_model::metadata::IndexVersion;
 
-use crate::engine::{document::SentenceDocument, metadata::Metadata};
+use super::all_terms;
+use crate::engine::{
+    document::SentenceDocument,
+    index_builder::{self, IndexDoc},
+    metadata::Metadata,
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<SentenceDocument, Metadata>;
+pub(crate) type Index = vector_space_model::Index<SentenceDocument, Metadata>;
 
-// In-memory storage for foreign name index
-pub(super) static INDEXES: OnceCell<HashMap<Language, Index>> = OnceCell::new();
-
-/// Load all available foreign-word indexes into memory
-pub(crate) fn load(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+/// Load all available foreign sentence indexes into memory
+pub(crate) fn load(config: &Config) -> Result<HashMap<Language, Index>, Box<dyn Error>> {
     // All index files in index source folder
     let index_files = std::fs::read_dir(config.get_indexes_source()).and_then(|i| {
         i.map(|res| res.map(|e| e.path()))
@@ -48,17 +51,45 @@ pub(crate) fn load(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
         panic!("No index file loaded");
     }
 
-    INDEXES.set(map).ok();
-
-    Ok(())
+    Ok(map)
 }
 
-/// Returns the loaded foreign name index
-#[inline]
-pub(crate) fn get(lang: Language) -> Option<&'static Index> {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { INDEXES.get_unchecked() }.get(&lang)
+/// Builds the foreign sentence indexes for all languages and writes them into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    for language in Language::word_iter() {
+        let docs = storage
+            .sentences()
+            .iter()
+            .filter_map(|sentence| {
+                let translation = sentence
+                    .translations
+                    .iter()
+                    .find(|i| i.language == language)?;
+
+                let text = translation.text.to_lowercase();
+                let mut terms = all_terms(&text);
+                terms.push(text);
+
+                let document = SentenceDocument {
+                    seq_id: sentence.id,
+                    mask: sentence.calc_lang_mask(),
+                };
+                Some(IndexDoc::new(terms, document))
+            })
+            .collect::<Vec<_>>();
+
+        if docs.is_empty() {
+            continue;
+        }
+
+        let metadata = Metadata::new(IndexVersion::V1, docs.len(), language);
+        let file = path
+            .as_ref()
+            .join(format!("sentences_{}", language.to_query_format()));
+        index_builder::write_index(docs.into_iter(), metadata, file)?;
+
+        info!("Built foreign sentence index: {:?}", language);
+    }
+
+    Ok(())
 }
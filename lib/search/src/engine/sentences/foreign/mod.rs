@@ -1,7 +1,10 @@
 pub mod index;
 
+use std::borrow::Cow;
+
 use crate::engine::{
-    document::SentenceDocument, metadata::Metadata, simple_gen_doc::GenDoc, Indexable, SearchEngine,
+    document::SentenceDocument, metadata::Metadata, simple_gen_doc::GenDoc, Indexable, Indexes,
+    SearchEngine,
 };
 use resources::models::storage::ResourceStorage;
 use types::jotoba::{languages::Language, sentences::Sentence};
@@ -15,9 +18,12 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        index::get(language.expect("Language not provided"))
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        indexes
+            .sentence_foreign
+            .get(&language.expect("Language not provided"))
     }
 }
 
@@ -26,14 +32,18 @@ impl SearchEngine for Engine {
     type Output = Sentence;
 
     #[inline]
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
-        storage.sentences().by_id(inp.seq_id).map(|i| vec![i])
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
+        storage
+            .sentences()
+            .by_id(inp.seq_id)
+            .map(|i| vec![Cow::Borrowed(i)])
     }
 
     fn gen_query_vector(
+        _indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         _allow_align: bool,
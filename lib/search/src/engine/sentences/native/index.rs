@@ -1,32 +1,41 @@
-use std::path::Path;
+use std::{error::Error, path::Path};
 
 use config::Config;
 use log::info;
-use once_cell::sync::OnceCell;
-use vector_space_model::DefaultMetadata;
+use resources::models::storage::ResourceStorage;
+use vector_space_model::{metadata::IndexVersion, DefaultMetadata};
 
-use crate::engine::document::SentenceDocument;
+use crate::engine::{
+    document::SentenceDocument,
+    index_builder::{self, IndexDoc},
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<SentenceDocument, DefaultMetadata>;
-
-// In-memory storage for japanese sentence index
-pub(super) static INDEX: OnceCell<Index> = OnceCell::new();
+pub(crate) type Index = vector_space_model::Index<SentenceDocument, DefaultMetadata>;
 
 /// Load japanese sentence index
-pub fn load(config: &Config) {
+pub(crate) fn load(config: &Config) -> Index {
     let file = Path::new(config.get_indexes_source()).join("sentences_jp_index");
     let index = Index::open(file).expect("Could not load japanese sentence index");
     info!("Loaded japanese sentence index");
-    INDEX.set(index).ok();
+    index
 }
 
-/// Returns the loaded japanese sentence index
-#[inline]
-pub(crate) fn get() -> &'static Index {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { INDEX.get_unchecked() }
+/// Builds the japanese sentence index and writes it into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let docs = storage.sentences().iter().map(|sentence| {
+        let terms = tinysegmenter::tokenize(&sentence.japanese);
+        let document = SentenceDocument {
+            seq_id: sentence.id,
+            mask: sentence.calc_lang_mask(),
+        };
+        IndexDoc::new(terms, document)
+    });
+
+    let metadata = DefaultMetadata::new(IndexVersion::V1);
+    let file = path.as_ref().join("sentences_jp_index");
+    index_builder::write_index(docs, metadata, file)?;
+
+    info!("Built japanese sentence index");
+    Ok(())
 }
@@ -1,6 +1,10 @@
 pub mod index;
 
-use crate::engine::{document::SentenceDocument, simple_gen_doc::GenDoc, Indexable, SearchEngine};
+use std::borrow::Cow;
+
+use crate::engine::{
+    document::SentenceDocument, simple_gen_doc::GenDoc, Indexable, Indexes, SearchEngine,
+};
 use resources::models::storage::ResourceStorage;
 use types::jotoba::{languages::Language, sentences::Sentence};
 use vector_space_model::{DefaultMetadata, DocumentVector};
@@ -13,9 +17,10 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         _language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        Some(index::get())
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        Some(&indexes.sentence_native)
     }
 }
 
@@ -24,14 +29,18 @@ impl SearchEngine for Engine {
     type Output = Sentence;
 
     #[inline]
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
-        storage.sentences().by_id(inp.seq_id).map(|i| vec![i])
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
+        storage
+            .sentences()
+            .by_id(inp.seq_id)
+            .map(|i| vec![Cow::Borrowed(i)])
     }
 
     fn gen_query_vector(
+        _indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         _allow_align: bool,
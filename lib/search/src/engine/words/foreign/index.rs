@@ -2,20 +2,23 @@ use std::{collections::HashMap, error::Error, io::BufReader, path::Path};
 
 use bktree::BkTree;
 use log::{error, info};
-use once_cell::sync::OnceCell;
+use resources::models::storage::ResourceStorage;
 use serde::{Deserialize, Serialize};
 use types::jotoba::languages::Language;
+use vector_space_model::metadata::IndexVersion;
 
-use crate::engine::{document::MultiDocument, metadata::Metadata};
+use super::gen::split_to_words;
+use crate::engine::{
+    document::MultiDocument,
+    index_builder::{self, IndexDoc},
+    metadata::Metadata,
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<MultiDocument, Metadata>;
+pub(crate) type Index = vector_space_model::Index<MultiDocument, Metadata>;
 
-// In-memory storage for all loaded indexes
-pub(super) static INDEXES: OnceCell<HashMap<Language, Index>> = OnceCell::new();
-
-// In-memory storage for all loaded term trees
-pub(super) static TERM_TREE: OnceCell<HashMap<Language, BkTree<String>>> = OnceCell::new();
+/// Loaded foreign-word indexes along with their term trees
+pub(crate) type LoadedIndexes = (HashMap<Language, Index>, HashMap<Language, BkTree<String>>);
 
 #[derive(Serialize, Deserialize)]
 pub struct TermTree {
@@ -23,14 +26,16 @@ pub struct TermTree {
     pub tree: BkTree<String>,
 }
 
-/// Load all available foreign-word indexes into memory
-pub(crate) fn load<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
-    load_index(path.as_ref())?;
-    load_term_trees(path)?;
-    Ok(())
+/// Load all available foreign-word indexes and term trees into memory
+pub(crate) fn load<P: AsRef<Path>>(path: P) -> Result<LoadedIndexes, Box<dyn Error>> {
+    let indexes = load_index(path.as_ref())?;
+    let term_trees = load_term_trees(path)?;
+    Ok((indexes, term_trees))
 }
 
-fn load_term_trees<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
+fn load_term_trees<P: AsRef<Path>>(
+    path: P,
+) -> Result<HashMap<Language, BkTree<String>>, Box<dyn Error>> {
     // All index files in index source folder
     let tree_files = std::fs::read_dir(path).and_then(|i| {
         i.map(|res| res.map(|e| e.path()))
@@ -57,12 +62,10 @@ fn load_term_trees<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
         panic!("No index file loaded");
     }
 
-    TERM_TREE.set(map).ok();
-
-    Ok(())
+    Ok(map)
 }
 
-fn load_index<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
+fn load_index<P: AsRef<Path>>(path: P) -> Result<HashMap<Language, Index>, Box<dyn Error>> {
     // All index files in index source folder
     let index_files = std::fs::read_dir(path).and_then(|i| {
         i.map(|res| res.map(|e| e.path()))
@@ -96,27 +99,51 @@ fn load_index<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
         panic!("No index file loaded");
     }
 
-    INDEXES.set(map).ok();
+    Ok(map)
+}
+
+/// Builds the foreign word index and term tree for all languages and writes them into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    for language in Language::word_iter() {
+        build_language(storage, language, path.as_ref())?;
+    }
 
     Ok(())
 }
 
-/// Retrieve an index of the given language. Returns `None` if there is no index loaded
-#[inline]
-pub(super) fn get(lang: Language) -> Option<&'static Index> {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { INDEXES.get_unchecked() }.get(&lang)
-}
+/// Builds the foreign word index and term tree for `language`
+fn build_language(
+    storage: &ResourceStorage,
+    language: Language,
+    path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let glosses = storage.words().iter().flat_map(|word| {
+        word.senses
+            .iter()
+            .filter(|sense| sense.language == language)
+            .flat_map(|sense| sense.glosses.iter())
+            .map(|gloss| (gloss.gloss.to_lowercase(), word.sequence))
+            .collect::<Vec<_>>()
+    });
+
+    let docs = index_builder::group_multi_docs(glosses)
+        .map(|(gloss, doc)| IndexDoc::new(split_to_words(&gloss), doc))
+        .collect::<Vec<_>>();
+
+    if docs.is_empty() {
+        return Ok(());
+    }
+
+    let tree = index_builder::term_tree(docs.iter().flat_map(|i| i.terms.iter()));
+    let term_tree = TermTree { language, tree };
+    let tree_file = path.join(format!("word_term_{}", language.to_query_format()));
+    index_builder::write_bincode(&term_tree, tree_file)?;
 
-/// Retrieve a term tree of the given language. Returns `None` if there is no index loaded
-#[inline]
-pub(super) fn get_term_tree(lang: Language) -> Option<&'static BkTree<String>> {
-    // Safety:
-    // We don't write to `INDEX` after loading it one time at the startup. Jotoba panics if it
-    // can't load this index, so until a `get()` call gets reached, `INDEX` is always set to a
-    // value.
-    unsafe { TERM_TREE.get_unchecked() }.get(&lang)
+    let metadata = Metadata::new(IndexVersion::V1, docs.len(), language);
+    let index_file = path.join(format!("word_index_{}", language.to_query_format()));
+    index_builder::write_index(docs.into_iter(), metadata, index_file)?;
+
+    info!("Built foreign word index: {:?}", language);
+
+    Ok(())
 }
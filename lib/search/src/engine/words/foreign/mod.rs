@@ -1,15 +1,22 @@
+use std::borrow::Cow;
+
 use resources::models::storage::ResourceStorage;
 use types::jotoba::{languages::Language, words::Word};
 use utils::to_option;
 use vector_space_model::{document_vector, DocumentVector};
 
-use crate::engine::{
-    document::MultiDocument, metadata::Metadata, Indexable, SearchEngine, SearchTask,
+use crate::{
+    engine::{
+        document::MultiDocument, metadata::Metadata, Indexable, Indexes, SearchEngine, SearchTask,
+    },
+    generation::Generation,
 };
 use gen::GenDoc;
 
 pub mod gen;
 pub mod index;
+pub mod regex;
+pub mod regex_index;
 
 pub struct Engine {}
 
@@ -19,9 +26,12 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        index::get(language.expect("Language required"))
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        indexes
+            .word_foreign
+            .get(&language.expect("Language required"))
     }
 }
 
@@ -30,19 +40,20 @@ impl SearchEngine for Engine {
     type Output = Word;
 
     #[inline]
-    fn doc_to_output(
-        storage: &'static ResourceStorage,
+    fn doc_to_output<'a>(
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
         to_option(
             inp.seq_ids
                 .iter()
-                .map(|i| storage.words().by_sequence(*i).unwrap())
+                .filter_map(|i| storage.words().by_sequence(*i))
                 .collect(),
         )
     }
 
     fn gen_query_vector(
+        indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         allow_align: bool,
@@ -59,7 +70,7 @@ impl SearchEngine for Engine {
         // align query to index
         if allow_align {
             for term in query_document.get_terms_mut() {
-                if let Some(aligned) = Self::align_query(term, index, language) {
+                if let Some(aligned) = Self::align_query(term, indexes, index, language) {
                     *term = aligned.to_string();
                     println!("Aligned: {} to {}", &query, term);
                 }
@@ -92,6 +103,7 @@ impl SearchEngine for Engine {
 
     fn align_query<'b>(
         original: &'b str,
+        indexes: &'b Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         language: Option<Language>,
     ) -> Option<&'b str> {
@@ -105,7 +117,7 @@ impl SearchEngine for Engine {
             return None;
         }
 
-        let tree = index::get_term_tree(language?)?;
+        let tree = indexes.word_foreign_term_trees.get(&language?)?;
         let mut res = tree.find(&query_str.to_string(), 1);
         if res.is_empty() {
             res = tree.find(&query_str.to_string(), 2);
@@ -117,11 +129,13 @@ impl SearchEngine for Engine {
 
 /// Guesses the language of `query`. Returns multiple if it can't be exactly determined cause of
 /// same/similar words across multiple languages
-pub fn guess_language(query: &str) -> Vec<Language> {
+pub fn guess_language(generation: &Generation, query: &str) -> Vec<Language> {
+    let indexes = generation.indexes();
     let possible_langs = Language::word_iter()
         .filter(|language| {
             // Filter languages that can theoretically build valid document vectors
-            Engine::gen_query_vector(index::get(*language).unwrap(), query, false, None).is_some()
+            let index = indexes.word_foreign.get(language).unwrap();
+            Engine::gen_query_vector(indexes, index, query, false, None).is_some()
         })
         .collect::<Vec<_>>();
 
@@ -131,7 +145,7 @@ pub fn guess_language(query: &str) -> Vec<Language> {
         let mut scored = Vec::with_capacity(possible_langs.len());
 
         for lang in &possible_langs {
-            let mut guess_task = SearchTask::<Engine>::with_language(query, *lang);
+            let mut guess_task = SearchTask::<Engine>::with_language(generation, query, *lang);
             guess_task.set_align(false);
             let guess = guess_task.estimate_result_count().unwrap(); // Only fails if index is not loaded, which is never the case
             scored.push((*lang, guess.value));
@@ -157,7 +171,7 @@ mod test {
 
     #[test]
     fn test_guess_lang() {
-        load_data();
+        let generation = load_data();
         let test_set = &[
             ("hausaufgabe", vec![Language::German]),
             ("Regen", vec![Language::German, Language::Dutch]),
@@ -171,12 +185,12 @@ mod test {
         for (query, expected) in test_set {
             println!("testing query: {}", query);
             let start = Instant::now();
-            assert_eq!(&guess_language(query), expected);
+            assert_eq!(&guess_language(&generation, query), expected);
             println!("lang guessing: {:?}", start.elapsed());
         }
     }
 
-    fn load_data() {
+    fn load_data() -> Generation {
         // never do this in production!
         let mut config = Config::new(Some(PathBuf::from("../../data/config.toml"))).unwrap();
         config.search = Some(SearchConfig {
@@ -185,23 +199,18 @@ mod test {
             suggestion_timeout: None,
             suggestion_sources: Some(String::from("../../suggestions")),
             report_queries_after: None,
+            api_max_page_size: None,
         });
 
         config.server = ServerConfig {
             storage_data: Some(String::from("../../resources/storage_data")),
             sentences: Some(String::from("../../resources/sentences.bin")),
             radical_map: Some(String::from("../../resources/radical_map")),
+            stroke_data: Some(String::from("../../resources/stroke_data")),
+            stroke_paths: Some(String::from("../../resources/stroke_paths")),
             ..ServerConfig::default()
         };
 
-        resources::initialize_resources(
-            config.get_storage_data_path().as_str(),
-            config.get_suggestion_sources(),
-            config.get_radical_map_path().as_str(),
-            config.get_sentences_path().as_str(),
-        )
-        .expect("Failed to load resources");
-
-        index::load("../../indexes").unwrap();
+        Generation::load(&config).expect("Failed to load resources")
     }
 }
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use types::jotoba::{languages::Language, words::Word};
+
+use crate::{generation::Generation, regex_query::RegexSQuery};
+
+/// Returns all words having a gloss in `language` which matches `query`, paired with the
+/// matching gloss
+pub fn search<'a>(
+    generation: &'a Generation,
+    query: &RegexSQuery,
+    language: Language,
+) -> Vec<(Cow<'a, Word>, &'a String)> {
+    let reg_index = match generation.indexes().word_foreign_regex.get(&language) {
+        Some(index) => index,
+        None => return vec![],
+    };
+
+    let word_resources = generation.resources().words();
+
+    let mut out: Vec<_> = reg_index
+        .find(&query.get_chars())
+        .into_iter()
+        .filter(|i| query.matches(&i.text))
+        .filter_map(|i| Some((word_resources.by_sequence(i.seq_id)?, &i.text)))
+        .collect();
+
+    // Keep the results in a constant order and each word only once
+    out.sort_by(|a, b| {
+        a.0.sequence
+            .cmp(&b.0.sequence)
+            .then(a.1.len().cmp(&b.1.len()))
+    });
+    out.dedup_by_key(|i| i.0.sequence);
+
+    out
+}
@@ -0,0 +1,75 @@
+use log::info;
+use resources::models::storage::ResourceStorage;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, error::Error, fs::File, io::BufReader, path::Path};
+use types::jotoba::languages::Language;
+
+use crate::engine::{index_builder, regex_index::RegexTermIndex};
+
+/// A gloss regex index along with the language of its glosses
+#[derive(Serialize, Deserialize)]
+struct LanguageIndex {
+    language: Language,
+    index: RegexTermIndex,
+}
+
+/// Load all available gloss regex indexes into memory
+pub(crate) fn load<P: AsRef<Path>>(
+    path: P,
+) -> Result<HashMap<Language, RegexTermIndex>, Box<dyn Error>> {
+    // All index files in index source folder
+    let index_files = std::fs::read_dir(path).and_then(|i| {
+        i.map(|res| res.map(|e| e.path()))
+            .collect::<Result<Vec<_>, std::io::Error>>()
+    })?;
+
+    let mut map = HashMap::new();
+
+    for index_file in index_files {
+        let file_name = index_file.file_name().and_then(|i| i.to_str()).unwrap();
+        if !file_name.starts_with("word_regex_index") {
+            continue;
+        }
+
+        let file = File::open(index_file)?;
+        let index: LanguageIndex = bincode::deserialize_from(BufReader::new(file))?;
+
+        info!("Loaded gloss regex index: {:?}", index.language);
+        map.insert(index.language, index.index);
+    }
+
+    Ok(map)
+}
+
+/// Builds the gloss regex indexes for all languages and writes them into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    for language in Language::word_iter() {
+        let mut index = RegexTermIndex::new();
+
+        let glosses = storage.words().iter().flat_map(|word| {
+            word.senses
+                .iter()
+                .filter(|sense| sense.language == language)
+                .flat_map(|sense| sense.glosses.iter())
+                .map(|gloss| (gloss.gloss.to_lowercase(), word.sequence))
+                .collect::<Vec<_>>()
+        });
+
+        for (gloss, seq_id) in glosses {
+            index.add_term(&gloss, seq_id);
+        }
+
+        if index.is_empty() {
+            continue;
+        }
+
+        let file = path
+            .as_ref()
+            .join(format!("word_regex_index_{}", language.to_query_format()));
+        index_builder::write_bincode(&LanguageIndex { language, index }, file)?;
+
+        info!("Built gloss regex index: {:?}", language);
+    }
+
+    Ok(())
+}
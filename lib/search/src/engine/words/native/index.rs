@@ -1,48 +1,43 @@
-use std::path::Path;
+use std::{error::Error, path::Path};
 
-use bktree::BkTree;
 use log::info;
-use once_cell::sync::OnceCell;
-use vector_space_model::DefaultMetadata;
+use resources::models::storage::ResourceStorage;
+use vector_space_model::{metadata::IndexVersion, DefaultMetadata};
 
-use crate::engine::document::SingleDocument;
+use crate::engine::{
+    document::SingleDocument,
+    index_builder::{self, IndexDoc},
+};
 
 // Shortcut for type of index
-pub(super) type Index = vector_space_model::Index<SingleDocument, DefaultMetadata>;
-
-// In-memory storage for japanese index
-pub(super) static INDEX: OnceCell<Index> = OnceCell::new();
-
-// In-memory storage for all loaded term trees
-pub(super) static TERM_TREE: OnceCell<BkTree<String>> = OnceCell::new();
-
-/// Load japanese index
-pub fn load<P: AsRef<Path>>(path: P) {
-    load_index(path.as_ref());
-    load_term_tree(path);
-}
+pub(crate) type Index = vector_space_model::Index<SingleDocument, DefaultMetadata>;
 
 /// Load japanese index
-pub fn load_index<P: AsRef<Path>>(path: P) {
+pub fn load<P: AsRef<Path>>(path: P) -> Index {
     let file = path.as_ref().join("jp_index");
     let index = Index::open(file).expect("Could not load japanese index");
     info!("Loaded japanese index");
-    INDEX.set(index).ok();
-}
-
-/// Load japanese index
-pub fn load_term_tree<P: AsRef<Path>>(_path: P) {
-    // let file = path.as_ref().join("jp_index.tree");
-
-    //let file = File::open(file).expect("Failed to parse japanese term tree");
-    //let tt = bincode::deserialize_from(file).expect("Failed to parse japanese term tree");
-    //info!("Loaded japanese term tree");
-
-    //TERM_TREE.set(tt).ok();
+    index
 }
 
-/// Returns the loaded japanese index
-#[inline]
-pub fn get() -> &'static Index {
-    unsafe { INDEX.get_unchecked() }
+/// Builds the japanese word index and writes it into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let docs = storage.words().iter().map(|word| {
+        let mut terms = word
+            .reading_iter(true)
+            .map(|i| i.reading.clone())
+            .collect::<Vec<_>>();
+        terms.sort_unstable();
+        terms.dedup();
+
+        let document = SingleDocument {
+            seq_id: word.sequence,
+        };
+        IndexDoc::new(terms, document)
+    });
+
+    let metadata = DefaultMetadata::new(IndexVersion::V1);
+    index_builder::write_index(docs, metadata, path.as_ref().join("jp_index"))?;
+    info!("Built japanese index");
+    Ok(())
 }
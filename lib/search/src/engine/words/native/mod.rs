@@ -2,7 +2,11 @@ pub mod index;
 pub mod regex;
 pub mod regex_index;
 
-use crate::engine::{document::SingleDocument, simple_gen_doc::GenDoc, Indexable, SearchEngine};
+use std::borrow::Cow;
+
+use crate::engine::{
+    document::SingleDocument, simple_gen_doc::GenDoc, Indexable, Indexes, SearchEngine,
+};
 use resources::models::storage::ResourceStorage;
 use types::jotoba::languages::Language;
 use types::jotoba::words::Word;
@@ -16,9 +20,10 @@ impl Indexable for Engine {
 
     #[inline]
     fn get_index(
+        indexes: &Indexes,
         _language: Option<Language>,
-    ) -> Option<&'static vector_space_model::Index<Self::Document, Self::Metadata>> {
-        Some(index::get())
+    ) -> Option<&vector_space_model::Index<Self::Document, Self::Metadata>> {
+        Some(&indexes.word_native)
     }
 }
 
@@ -28,13 +33,14 @@ impl SearchEngine for Engine {
 
     #[inline]
     fn doc_to_output<'a>(
-        storage: &'static ResourceStorage,
+        storage: &'a ResourceStorage,
         inp: &Self::Document,
-    ) -> Option<Vec<&'static Self::Output>> {
+    ) -> Option<Vec<Cow<'a, Self::Output>>> {
         storage.words().by_sequence(inp.seq_id).map(|i| vec![i])
     }
 
     fn gen_query_vector(
+        _indexes: &Indexes,
         index: &vector_space_model::Index<Self::Document, Self::Metadata>,
         query: &str,
         _allow_align: bool,
@@ -1,18 +1,21 @@
+use std::borrow::Cow;
+
 use error::Error;
 use types::jotoba::words::Word;
 
-use crate::regex_query::RegexSQuery;
-
-use super::regex_index;
+use crate::{generation::Generation, regex_query::RegexSQuery};
 
-pub fn search(query: &RegexSQuery) -> Result<Vec<(&'static Word, &'static String)>, Error> {
-    let reg_index = regex_index::get();
+pub fn search<'a>(
+    generation: &'a Generation,
+    query: &RegexSQuery,
+) -> Result<Vec<(Cow<'a, Word>, &'a String)>, Error> {
+    let reg_index = &generation.indexes().word_native_regex;
 
     let possible_results = reg_index.find(&query.get_chars());
 
     let mut out = Vec::with_capacity(possible_results.len());
 
-    let word_resources = resources::get().words();
+    let word_resources = generation.resources().words();
 
     for result in possible_results {
         if query.matches(&result.text) {
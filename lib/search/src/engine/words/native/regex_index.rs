@@ -1,22 +1,38 @@
 use log::info;
-use once_cell::sync::OnceCell;
+use resources::models::storage::ResourceStorage;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
+    error::Error,
     fs::File,
     io::BufReader,
     path::Path,
 };
 
-// In-memory storage for japanese regex index
-pub(super) static INDEX: OnceCell<RegexSearchIndex> = OnceCell::new();
+use crate::engine::index_builder;
 
-pub fn load<P: AsRef<Path>>(path: P) {
+/// Load japanese regex index
+pub fn load<P: AsRef<Path>>(path: P) -> RegexSearchIndex {
     let file = File::open(path.as_ref().join("regex_index")).expect("Missing regex index");
     let index: RegexSearchIndex =
         bincode::deserialize_from(BufReader::new(file)).expect("Invaild regex index");
     info!("Loaded japanese regex index");
-    INDEX.set(index).ok();
+    index
+}
+
+/// Builds the japanese regex index and writes it into `path`
+pub fn build<P: AsRef<Path>>(storage: &ResourceStorage, path: P) -> Result<(), Box<dyn Error>> {
+    let mut index = RegexSearchIndex::new();
+
+    for word in storage.words().iter() {
+        for reading in word.reading_iter(true) {
+            index.add_term(&reading.reading, word.sequence);
+        }
+    }
+
+    index_builder::write_bincode(&index, path.as_ref().join("regex_index"))?;
+    info!("Built japanese regex index");
+    Ok(())
 }
 
 /// Special index to allow fast and efficient regex search queries.
@@ -103,9 +119,3 @@ impl RegexSearchIndex {
         out.into_iter().collect()
     }
 }
-
-/// Returns the loaded japanese regex index
-#[inline]
-pub fn get() -> &'static RegexSearchIndex {
-    unsafe { INDEX.get_unchecked() }
-}
@@ -0,0 +1,93 @@
+use std::{collections::HashMap, error::Error, sync::Arc};
+
+use config::Config;
+use once_cell::sync::OnceCell;
+use resources::models::{storage::ResourceStorage, suggestions::foreign_words::ForeignSuggestion};
+use types::jotoba::languages::Language;
+use utils::swap_cell::SwapCell;
+
+use crate::{engine::Indexes, suggestions::TextSearch};
+
+/// The currently used generation
+static CURRENT: SwapCell<Generation> = SwapCell::new();
+
+/// A set of resources along with all indexes built out of them. Resources and indexes always get
+/// loaded and replaced together, so a search never mixes up data of different generations
+pub struct Generation {
+    resources: ResourceStorage,
+    indexes: Indexes,
+    /// Known terms of each language used for spelling corrections. Built on first use
+    correction_terms: HashMap<Language, OnceCell<Option<TextSearch<Vec<ForeignSuggestion>>>>>,
+}
+
+impl Generation {
+    /// Loads all resources and indexes specified in `config`
+    pub fn load(config: &Config) -> Result<Self, Box<dyn Error>> {
+        let (resources, indexes) = rayon::join(
+            || {
+                resources::load(
+                    config.get_storage_data_path().as_str(),
+                    config.get_suggestion_sources(),
+                    config.get_radical_map_path().as_str(),
+                    config.get_sentences_path().as_str(),
+                    config.get_stroke_data_path().as_str(),
+                    config.get_stroke_paths_path().as_str(),
+                )
+                // `Box<dyn Error>` can't be sent across threads
+                .map_err(|err| err.to_string())
+            },
+            || Indexes::load(config),
+        );
+
+        Ok(Self {
+            resources: resources?,
+            indexes,
+            correction_terms: Language::word_iter()
+                .map(|lang| (lang, OnceCell::new()))
+                .collect(),
+        })
+    }
+
+    /// Returns the resources of the generation
+    #[inline]
+    pub fn resources(&self) -> &ResourceStorage {
+        &self.resources
+    }
+
+    /// Returns the indexes of the generation
+    #[inline]
+    pub fn indexes(&self) -> &Indexes {
+        &self.indexes
+    }
+
+    /// Returns the known terms of `language` used for spelling corrections. They get read from
+    /// the foreign word suggestions once they're used the first time. Returns `None` if there
+    /// are no suggestions for `language`
+    pub fn correction_terms(
+        &self,
+        language: Language,
+    ) -> Option<&TextSearch<Vec<ForeignSuggestion>>> {
+        self.correction_terms
+            .get(&language)?
+            .get_or_init(|| {
+                let dict = self.resources.suggestions().foreign_words(language)?;
+                Some(TextSearch::new(dict.iter().collect()))
+            })
+            .as_ref()
+    }
+}
+
+/// Returns the current generation. The returned generation stays valid even if it gets replaced
+/// in the meantime and gets dropped once the last reference to it is gone
+///
+/// Panics if no generation was loaded yet
+#[inline]
+pub fn get() -> Arc<Generation> {
+    CURRENT.get().expect("Resources not loaded")
+}
+
+/// Replaces the current generation with `generation`
+#[inline]
+pub fn set(generation: Generation) {
+    CURRENT.set(generation);
+}
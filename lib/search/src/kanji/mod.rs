@@ -2,11 +2,14 @@ mod order;
 pub mod result;
 mod tag_only;
 
+use std::borrow::Cow;
+
 use itertools::Itertools;
 use result::Item;
 
 use error::Error;
 use japanese::JapaneseExt;
+use resources::models::storage::kanji::KanjiProperties;
 use types::jotoba::kanji::Kanji;
 
 use crate::{
@@ -15,7 +18,8 @@ use crate::{
         words::native,
         SearchTask,
     },
-    query::QueryLang,
+    generation::Generation,
+    query::{KanjiProperty, QueryLang},
 };
 
 use super::query::Query;
@@ -28,9 +32,9 @@ pub struct KanjiResult {
 }
 
 /// The entry of a kanji search
-pub fn search(query: &Query) -> Result<KanjiResult, Error> {
+pub fn search(generation: &Generation, query: &Query) -> Result<KanjiResult, Error> {
     if query.form.is_tag_only() {
-        return tag_only::search(query);
+        return tag_only::search(generation, query);
     }
 
     let query_str = format_query(&query.query);
@@ -39,17 +43,22 @@ pub fn search(query: &Query) -> Result<KanjiResult, Error> {
 
     match query.language {
         QueryLang::Japanese => {
-            res = by_literals(&query.query);
+            res = by_literals(generation, &query.query);
         }
         QueryLang::Foreign | QueryLang::Undetected => {
-            res = by_meaning(&query.query);
+            res = by_meaning(generation, &query.query);
         }
         QueryLang::Korean => {
-            res = by_korean_reading(&query.query);
+            res = by_korean_reading(generation, &query.query);
         }
     }
 
-    let mut items = to_item(res, &query);
+    let res = res
+        .into_iter()
+        .filter(|kanji| range_filter(query, &KanjiProperties::from(kanji)))
+        .collect();
+
+    let mut items = to_item(generation, res, &query);
     if !query_str.is_japanese() {
         items.sort_by(order::by_meaning);
     }
@@ -68,15 +77,15 @@ pub fn search(query: &Query) -> Result<KanjiResult, Error> {
 }
 
 /// Find a kanji by its literal
-fn by_literals(query: &str) -> Vec<Kanji> {
-    let kanji = all_kanji_from_text(query);
+fn by_literals(generation: &Generation, query: &str) -> Vec<Kanji> {
+    let kanji = all_kanji_from_text(generation, query);
     if !kanji.is_empty() || query.is_kanji() {
         return kanji;
     }
 
     // kana search
 
-    let search = SearchTask::<native::Engine>::new(query).threshold(0.89);
+    let search = SearchTask::<native::Engine>::new(generation, query).threshold(0.89);
     let res = search.find_exact().unwrap_or_default();
     if res.is_empty() {
         return vec![];
@@ -91,44 +100,35 @@ fn by_literals(query: &str) -> Vec<Kanji> {
         .unique()
         .join("");
 
-    all_kanji_from_text(&text)
+    all_kanji_from_text(generation, &text)
 }
 
-fn all_kanji_from_text(text: &str) -> Vec<Kanji> {
-    let kanji_storage = resources::get().kanji();
+fn all_kanji_from_text(generation: &Generation, text: &str) -> Vec<Kanji> {
+    let kanji_storage = generation.resources().kanji();
 
     text.chars()
         .into_iter()
         .filter(|i| i.is_kanji())
         .filter_map(|literal| kanji_storage.by_literal(literal))
-        .cloned()
+        .map(Cow::into_owned)
         .take(100)
         .collect()
 }
 
-fn by_korean_reading(query: &str) -> Vec<Kanji> {
-    let kanji = resources::get().kanji();
-    let res = kanji
-        .all()
-        .filter(|k| {
-            let korean = &k.korean_h;
-            if korean.is_none() {
-                return false;
-            }
-            let korean = korean.as_ref().unwrap();
-            korean.iter().any(|kw| kw == query)
-        })
-        .cloned()
-        .collect::<Vec<_>>();
-
-    res
+fn by_korean_reading(generation: &Generation, query: &str) -> Vec<Kanji> {
+    generation
+        .resources()
+        .kanji()
+        .by_korean_reading(query)
+        .map(Cow::into_owned)
+        .collect()
 }
 
 /// Guesses the amount of results a search would return with given `query`
-pub fn guess_result(query: &Query) -> Option<Guess> {
+pub fn guess_result(generation: &Generation, query: &Query) -> Option<Guess> {
     let query_str = &query.query;
 
-    let kanji_storage = resources::get().kanji();
+    let kanji_storage = generation.resources().kanji();
     let guess = query_str
         .chars()
         .into_iter()
@@ -141,26 +141,36 @@ pub fn guess_result(query: &Query) -> Option<Guess> {
 }
 
 /// Find kanji by mits meaning
-fn by_meaning(meaning: &str) -> Vec<Kanji> {
-    let mut out = Vec::new();
-
-    let kanji_storage = resources::get().kanji();
-
+fn by_meaning(generation: &Generation, meaning: &str) -> Vec<Kanji> {
     // TODO: implement a proper meaning search algorithm
-    for kanji in kanji_storage.iter() {
-        if kanji.meanings.contains(&meaning.to_string()) {
-            out.push(kanji.clone());
-        }
-    }
+    generation
+        .resources()
+        .kanji()
+        .by_meaning(meaning)
+        .map(Cow::into_owned)
+        .collect()
+}
+
+/// Returns `true` if `kanji` matches all kanji range tags of the query
+fn range_filter(query: &Query, kanji: &KanjiProperties) -> bool {
+    query.get_kanji_range_tags().all(|tag| {
+        let value = match tag.property {
+            KanjiProperty::Strokes => Some(kanji.stroke_count as u16),
+            KanjiProperty::Grade => kanji.grade.map(|i| i as u16),
+            KanjiProperty::Frequency => kanji.frequency,
+        };
 
-    out
+        value.map(|i| tag.contains(i)).unwrap_or(false)
+    })
 }
 
 #[inline]
-fn to_item(items: Vec<Kanji>, query: &Query) -> Vec<Item> {
+fn to_item(generation: &Generation, items: Vec<Kanji>, query: &Query) -> Vec<Item> {
+    let lang = query.settings.user_lang;
+    let show_english = query.settings.show_english;
     items
         .into_iter()
-        .map(|i| Item::load_words(i, query.settings.user_lang, query.settings.show_english))
+        .map(|i| Item::load_words(generation, i, lang, show_english))
         .collect()
 }
 
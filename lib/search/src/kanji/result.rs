@@ -1,7 +1,8 @@
-use std::{fs::read_to_string, vec};
+use std::{borrow::Cow, fs::read_to_string, vec};
 
+use crate::generation::Generation;
 use types::jotoba::{
-    kanji::Kanji,
+    kanji::{Decomposition, Kanji},
     languages::Language,
     words::{filter_languages, Word},
 };
@@ -11,29 +12,47 @@ pub struct Item {
     pub kanji: Kanji,
     pub kun_dicts: Option<Vec<Word>>,
     pub on_dicts: Option<Vec<Word>>,
+    /// Decomposition tree of the kanji. `None` if the kanji can't be decomposed
+    pub decomposition: Option<Decomposition>,
 }
 
 impl Item {
-    pub fn load_words(k: Kanji, lang: Language, show_english: bool) -> Self {
-        let kun_dicts = load_dicts(&k.kun_dicts, lang, show_english);
-        let on_dicts = load_dicts(&k.on_dicts, lang, show_english);
+    pub fn load_words(
+        generation: &Generation,
+        k: Kanji,
+        lang: Language,
+        show_english: bool,
+    ) -> Self {
+        let kun_dicts = load_dicts(generation, &k.kun_dicts, lang, show_english);
+        let on_dicts = load_dicts(generation, &k.on_dicts, lang, show_english);
+        let decomposition = generation
+            .resources()
+            .kanji()
+            .decomposition(k.literal)
+            .filter(|i| !i.components.is_empty());
 
         Self {
             kun_dicts,
             on_dicts,
+            decomposition,
             kanji: k,
         }
     }
 }
 
-fn load_dicts(dicts: &Option<Vec<u32>>, lang: Language, show_english: bool) -> Option<Vec<Word>> {
-    let word_storage = resources::get().words();
+fn load_dicts(
+    generation: &Generation,
+    dicts: &Option<Vec<u32>>,
+    lang: Language,
+    show_english: bool,
+) -> Option<Vec<Word>> {
+    let word_storage = generation.resources().words();
 
     let loaded_dicts = dicts.as_ref().map(|i| {
         let mut words: Vec<_> = i
             .iter()
             .filter_map(|j| word_storage.by_sequence(*j))
-            .cloned()
+            .map(Cow::into_owned)
             .collect();
 
         filter_languages(words.iter_mut(), lang, show_english);
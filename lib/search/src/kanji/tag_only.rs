@@ -1,38 +1,36 @@
+use std::borrow::Cow;
+
 use error::Error;
+use resources::models::storage::kanji::KanjiProperties;
+use types::jotoba::kanji::Kanji;
 
-use crate::query::{Query, Tag};
+use crate::{
+    generation::Generation,
+    query::{Query, Tag},
+};
 
 use super::KanjiResult;
 
-pub fn search(query: &Query) -> Result<KanjiResult, Error> {
-    let single_tag = query.tags.iter().find(|i| i.is_empty_allowed());
-
-    if single_tag.is_none() {
-        return Ok(KanjiResult::default());
-    }
-
-    match single_tag.unwrap() {
-        Tag::Jlpt(jlpt) => jlpt_search(query, *jlpt),
-        Tag::GenkiLesson(genki_lesson) => genki_search(query, *genki_lesson),
-        _ => return Ok(KanjiResult::default()),
-    }
-}
-
-fn genki_search(query: &Query, genki_lesson: u8) -> Result<KanjiResult, Error> {
-    let kanji_retrieve = resources::get().kanji();
-
-    let genki_lesson = kanji_retrieve.by_genki_lesson(genki_lesson);
-
-    if genki_lesson.is_none() {
-        return Ok(KanjiResult::default());
-    }
-
-    let kanji = genki_lesson
-        // we ensured that there is a genki lesson above
-        .unwrap()
+pub fn search(generation: &Generation, query: &Query) -> Result<KanjiResult, Error> {
+    let single_tag = query
+        .tags
         .iter()
-        .filter_map(|literal| kanji_retrieve.by_literal(*literal))
-        .cloned()
+        .find(|i| i.is_empty_allowed() && !i.is_kanji_range());
+
+    let kanji = match single_tag {
+        Some(Tag::Jlpt(jlpt)) => jlpt_kanji(generation, *jlpt),
+        Some(Tag::GenkiLesson(genki_lesson)) => genki_kanji(generation, *genki_lesson),
+        Some(_) => return Ok(KanjiResult::default()),
+        None if query.get_kanji_range_tags().next().is_some() => {
+            return Ok(all_kanji(generation, query))
+        }
+        None => return Ok(KanjiResult::default()),
+    };
+
+    // Filter before paginating to get the correct amount of total items
+    let kanji = kanji
+        .into_iter()
+        .filter(|kanji| super::range_filter(query, &KanjiProperties::from(kanji.as_ref())))
         .collect::<Vec<_>>();
 
     let len = kanji.len();
@@ -43,38 +41,66 @@ fn genki_search(query: &Query, genki_lesson: u8) -> Result<KanjiResult, Error> {
         .into_iter()
         .skip(page_offset)
         .take(query.settings.kanji_page_size as usize)
+        .map(Cow::into_owned)
         .collect::<Vec<_>>();
 
-    let items = super::to_item(kanji, query);
-
     Ok(KanjiResult {
-        items,
+        items: super::to_item(generation, kanji, query),
         total_items: len,
     })
 }
 
-fn jlpt_search(query: &Query, jlpt: u8) -> Result<KanjiResult, Error> {
-    let kanji_retrieve = resources::get().kanji();
+fn genki_kanji(generation: &Generation, genki_lesson: u8) -> Vec<Cow<Kanji>> {
+    let kanji_retrieve = generation.resources().kanji();
+
+    kanji_retrieve
+        .by_genki_lesson(genki_lesson)
+        .map(|lesson| {
+            lesson
+                .iter()
+                .filter_map(|literal| kanji_retrieve.by_literal(*literal))
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    let jlpt_kanji = match kanji_retrieve.by_jlpt(jlpt) {
-        Some(jlpt) => jlpt,
-        None => return Ok(KanjiResult::default()),
-    };
+fn jlpt_kanji(generation: &Generation, jlpt: u8) -> Vec<Cow<Kanji>> {
+    let kanji_retrieve = generation.resources().kanji();
+
+    kanji_retrieve
+        .by_jlpt(jlpt)
+        .map(|jlpt_kanji| {
+            jlpt_kanji
+                .iter()
+                .filter_map(|literal| kanji_retrieve.by_literal(*literal))
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    let len = jlpt_kanji.len();
+/// Returns all kanji matching the kanji range tags of `query` ordered by their frequency. Kanji
+/// without frequency come last. Only the kanji of the requested page get loaded
+fn all_kanji(generation: &Generation, query: &Query) -> KanjiResult {
+    let kanji_retrieve = generation.resources().kanji();
+
+    let kanji = kanji_retrieve
+        .by_frequency()
+        .iter()
+        .filter(|kanji| super::range_filter(query, kanji))
+        .collect::<Vec<_>>();
 
     let page_offset = query.page_offset(query.settings.kanji_page_size as usize);
 
-    let jlpt_kanji = jlpt_kanji
-        .into_iter()
+    let items = kanji
+        .iter()
         .skip(page_offset)
         .take(query.settings.kanji_page_size as usize)
-        .filter_map(|literal| kanji_retrieve.by_literal(*literal))
-        .cloned()
+        .filter_map(|kanji| kanji_retrieve.by_literal(kanji.literal))
+        .map(Cow::into_owned)
         .collect::<Vec<_>>();
 
-    Ok(KanjiResult {
-        items: super::to_item(jlpt_kanji, query),
-        total_items: len,
-    })
+    KanjiResult {
+        items: super::to_item(generation, items, query),
+        total_items: kanji.len(),
+    }
 }
@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 
 pub mod engine;
+pub mod generation;
 pub mod kanji;
 pub mod name;
 pub mod query;
+pub mod query_expression;
 pub mod query_parser;
 pub mod radical;
 pub mod regex_query;
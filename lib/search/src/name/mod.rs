@@ -4,9 +4,10 @@ pub mod result;
 use crate::{
     engine::{
         guess::Guess,
-        names::{foreign, native},
+        names::{foreign, native, regex},
         SearchEngine, SearchTask,
     },
+    generation::Generation,
     query::QueryLang,
 };
 
@@ -21,27 +22,70 @@ use utils::to_option;
 
 /// Search for names
 #[inline]
-pub fn search(query: &Query) -> Result<NameResult, Error> {
+pub fn search(generation: &Generation, query: &Query) -> Result<NameResult, Error> {
     if query.form.is_kanji_reading() {
-        search_kanji(&query)
+        search_kanji(generation, &query)
+    } else if let Some(res) = regex_search(generation, query) {
+        Ok(res)
     } else {
         if query.language == QueryLang::Japanese {
-            handle_search(japanese_search(&query))
+            handle_search(japanese_search(generation, &query))
         } else {
-            handle_search(foreign_search(&query))
+            handle_search(foreign_search(generation, &query))
         }
     }
 }
 
-fn japanese_search(query: &Query) -> SearchTask<native::Engine> {
-    SearchTask::<native::Engine>::new(&query.query)
+/// Searches names using a wildcard query. Returns `None` if `query` isn't a (reasonable) regex
+/// query or nothing was found
+fn regex_search(generation: &Generation, query: &Query) -> Option<NameResult> {
+    let regex_query = query.as_regex_query()?;
+
+    // prevent heavy queries
+    if regex_query.get_chars().len() < 2 {
+        return None;
+    }
+
+    let mut names = regex::search(generation, &regex_query)
+        .into_iter()
+        .map(|(name, found_in)| {
+            let score = order::regex_order(&name, found_in);
+            (name, score)
+        })
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return None;
+    }
+
+    names.sort_by(|a, b| a.1.cmp(&b.1).reverse());
+
+    let total_count = names.len() as u32;
+    let items = names
+        .into_iter()
+        .skip(query.page_offset)
+        .take(query.settings.page_size as usize)
+        .map(|i| i.0.into_owned())
+        .collect();
+
+    Some(NameResult { items, total_count })
+}
+
+fn japanese_search<'a>(
+    generation: &'a Generation,
+    query: &Query,
+) -> SearchTask<'a, native::Engine> {
+    SearchTask::<native::Engine>::new(generation, &query.query)
         .threshold(0.05f32)
         .offset(query.page_offset)
         .limit(query.settings.page_size as usize)
 }
 
-fn foreign_search(query: &Query) -> SearchTask<foreign::Engine> {
-    SearchTask::<foreign::Engine>::new(&query.query)
+fn foreign_search<'a>(
+    generation: &'a Generation,
+    query: &Query,
+) -> SearchTask<'a, foreign::Engine> {
+    SearchTask::<foreign::Engine>::new(generation, &query.query)
         .threshold(0.05f32)
         .offset(query.page_offset)
         .limit(query.settings.page_size as usize)
@@ -54,11 +98,11 @@ fn handle_search<T: SearchEngine<Output = Name> + Send>(
 }
 
 /// Search by kanji reading
-fn search_kanji(query: &Query) -> Result<NameResult, Error> {
+fn search_kanji(generation: &Generation, query: &Query) -> Result<NameResult, Error> {
     let kanji_reading = query.form.as_kanji_reading().ok_or(Error::Unexpected)?;
 
     let query_str = kanji_reading.literal.to_string();
-    let mut task = SearchTask::<native::Engine>::new(&query_str)
+    let mut task = SearchTask::<native::Engine>::new(generation, &query_str)
         .limit(query.settings.page_size as usize)
         .offset(query.page_offset);
 
@@ -72,15 +116,15 @@ fn search_kanji(query: &Query) -> Result<NameResult, Error> {
         let kana = &name.kana;
         let readings = japanese::furigana::generate::retrieve_readings(
             &mut |i: String| {
-                let retrieve = resources::get().kanji();
+                let retrieve = generation.resources().kanji();
                 let kanji = retrieve.by_literal(i.chars().next()?)?;
                 if kanji.onyomi.is_none() && kanji.kunyomi.is_none() {
                     return None;
                 }
 
                 let kun = kanji
-                    .clone()
                     .kunyomi
+                    .clone()
                     .unwrap_or_default()
                     .into_iter()
                     .chain(kanji.natori.clone().unwrap_or_default().into_iter())
@@ -107,15 +151,15 @@ fn search_kanji(query: &Query) -> Result<NameResult, Error> {
 }
 
 /// Guesses the amount of results a search would return with given `query`
-pub fn guess_result(query: &Query) -> Option<Guess> {
+pub fn guess_result(generation: &Generation, query: &Query) -> Option<Guess> {
     if query.form.is_kanji_reading() {
         return None;
     }
 
     if query.query.is_japanese() {
-        japanese_search(query).estimate_result_count()
+        japanese_search(generation, query).estimate_result_count()
     } else {
-        foreign_search(query).estimate_result_count()
+        foreign_search(generation, query).estimate_result_count()
     }
     .ok()
 }
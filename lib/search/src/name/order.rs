@@ -145,3 +145,15 @@ impl<'a> ByKanji<'a> {
 fn str_to_char(s: &str) -> Option<char> {
     s.chars().next()
 }
+
+/// Order for regex-search results. Names with a shorter matching text are shown more on top
+pub(crate) fn regex_order(name: &Name, found_in: &str) -> usize {
+    let mut score: usize = 100;
+
+    // Prefer matches in the names japanese writing over its transcription
+    if name.transcription.to_lowercase() != found_in {
+        score += 20;
+    }
+
+    score.saturating_sub(utils::real_string_len(found_in) * 3)
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use itertools::Itertools;
 use localization::{language::Language, traits::Translatable, TranslationDict};
 use types::jotoba::names::Name;
@@ -5,7 +7,7 @@ use types::jotoba::names::Name;
 use crate::engine::result::SearchResult;
 
 pub struct NameResult {
-    pub items: Vec<&'static Name>,
+    pub items: Vec<Name>,
     pub total_count: u32,
 }
 
@@ -21,10 +23,10 @@ pub fn get_types_humanized(name: &Name, dict: &TranslationDict, lang: Language)
     }
 }
 
-impl From<SearchResult<&'static Name>> for NameResult {
+impl From<SearchResult<Cow<'_, Name>>> for NameResult {
     #[inline]
-    fn from(res: SearchResult<&'static Name>) -> Self {
-        let items: Vec<_> = res.items.into_iter().map(|i| i.item).collect();
+    fn from(res: SearchResult<Cow<'_, Name>>) -> Self {
+        let items: Vec<_> = res.items.into_iter().map(|i| i.item.into_owned()).collect();
         NameResult {
             total_count: res.total_items as u32,
             items,
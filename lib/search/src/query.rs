@@ -3,7 +3,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::{query_parser, regex_query::RegexSQuery};
+use crate::{query_expression::Expression, query_parser, regex_query::RegexSQuery};
 
 use itertools::Itertools;
 use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
@@ -11,7 +11,10 @@ use types::jotoba::{
     kanji,
     languages::Language,
     search::QueryType,
-    words::{misc::Misc, part_of_speech::PosSimple},
+    words::{
+        dialect::Dialect, field::Field, foreign_language::ForeignLanguage, misc::Misc,
+        parse_tag_value, part_of_speech::PosSimple,
+    },
 };
 
 const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.add(b'/');
@@ -33,6 +36,11 @@ pub struct Query {
     pub language_override: Option<Language>,
     /// Whether to use the user query only or modify it if necessary
     pub use_original: bool,
+    /// The query converted into kana if it could be romanized japanese
+    pub romaji_kana: Option<String>,
+    /// Boolean and phrase operators used in the query. `query` only holds the terms to search
+    /// for if set
+    pub expression: Option<Expression>,
 }
 
 /// In-cookie saved personalized settings
@@ -47,6 +55,7 @@ pub struct UserSettings {
     pub kanji_page_size: u32,
     pub show_example_sentences: bool,
     pub sentence_furigana: bool,
+    pub show_romaji: bool,
 }
 
 impl PartialEq for UserSettings {
@@ -77,6 +86,7 @@ impl Default for UserSettings {
             kanji_page_size: 4,
             show_example_sentences: true,
             sentence_furigana: true,
+            show_romaji: false,
         }
     }
 }
@@ -87,12 +97,34 @@ pub enum Tag {
     SearchType(SearchTypeTag),
     PartOfSpeech(PosSimple),
     Misc(Misc),
+    Field(Field),
+    Dialect(Dialect),
+    Loan(ForeignLanguage),
+    KanjiRange(KanjiRangeTag),
     Jlpt(u8),
     GenkiLesson(u8),
     Hidden,
     IrregularIruEru,
 }
 
+/// Tag filtering kanji by a numeric property like `#strokes:5-7`, `#grade:2` or `#freq<500`
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct KanjiRangeTag {
+    pub property: KanjiProperty,
+    /// Smallest allowed value (inclusive)
+    pub min: u16,
+    /// Biggest allowed value (inclusive)
+    pub max: u16,
+}
+
+/// Numeric kanji properties which can be filtered by
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub enum KanjiProperty {
+    Strokes,
+    Grade,
+    Frequency,
+}
+
 /// Hashtag based search tags
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub enum SearchTypeTag {
@@ -176,6 +208,14 @@ impl Tag {
                 }
                 _ => (),
             }
+
+            if let Some(tag) = Self::parse_sense_tag(tag) {
+                return Some(tag);
+            }
+
+            if let Some(tag) = KanjiRangeTag::parse(tag) {
+                return Some(Tag::KanjiRange(tag));
+            }
         }
 
         #[allow(irrefutable_let_patterns)]
@@ -214,6 +254,18 @@ impl Tag {
         (nr >= 3 && nr <= 23).then(|| Tag::GenkiLesson(nr))
     }
 
+    /// Parses field, dialect and loanword tags like `#field:medicine`, `#dialect:ksb` or
+    /// `#loan:ger`
+    fn parse_sense_tag(s: &str) -> Option<Tag> {
+        let (kind, value) = s.split_once(':')?;
+        match kind {
+            "field" => parse_tag_value(value).map(Tag::Field),
+            "dialect" => parse_tag_value(value).map(Tag::Dialect),
+            "loan" | "gairaigo" => parse_tag_value(value).map(Tag::Loan),
+            _ => None,
+        }
+    }
+
     /// Parse only search type
     fn parse_search_type(s: &str) -> Option<Tag> {
         Some(match s[1..].to_lowercase().as_str() {
@@ -229,7 +281,38 @@ impl Tag {
     /// Returns true if the tag is allowed to be used without a query
     #[inline]
     pub fn is_empty_allowed(&self) -> bool {
-        self.is_jlpt() || self.is_genki_lesson() || self.is_irregular_iru_eru()
+        self.is_jlpt()
+            || self.is_genki_lesson()
+            || self.is_irregular_iru_eru()
+            || self.is_sense_tag()
+            || self.is_kanji_range()
+    }
+
+    /// Returns `true` if the tag is [`KanjiRange`].
+    ///
+    /// [`KanjiRange`]: Tag::KanjiRange
+    #[inline]
+    pub fn is_kanji_range(&self) -> bool {
+        matches!(self, Self::KanjiRange(..))
+    }
+
+    #[inline]
+    pub fn as_kanji_range(&self) -> Option<&KanjiRangeTag> {
+        if let Self::KanjiRange(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the tag is a [`Field`], [`Dialect`] or [`Loan`] tag.
+    ///
+    /// [`Field`]: Tag::Field
+    /// [`Dialect`]: Tag::Dialect
+    /// [`Loan`]: Tag::Loan
+    #[inline]
+    pub fn is_sense_tag(&self) -> bool {
+        matches!(self, Self::Field(..) | Self::Dialect(..) | Self::Loan(..))
     }
 
     /// Returns `true` if the tag is [`SearchType`].
@@ -319,6 +402,52 @@ impl Tag {
     }
 }
 
+impl KanjiRangeTag {
+    /// Parses a kanji range tag without its leading '#'. Supported operators are `:` and `=` for
+    /// a single value or a range (eg. `5-7`), `<`, `<=`, `>` and `>=`
+    fn parse(s: &str) -> Option<Self> {
+        let op_pos = s.find(|c| matches!(c, ':' | '=' | '<' | '>'))?;
+
+        let property = match &s[..op_pos] {
+            "stroke" | "strokes" => KanjiProperty::Strokes,
+            "grade" => KanjiProperty::Grade,
+            "freq" | "frequency" => KanjiProperty::Frequency,
+            _ => return None,
+        };
+
+        let value = &s[op_pos..];
+        let (min, max) = if let Some(v) = value.strip_prefix("<=") {
+            (0, v.parse().ok()?)
+        } else if let Some(v) = value.strip_prefix(">=") {
+            (v.parse().ok()?, u16::MAX)
+        } else if let Some(v) = value.strip_prefix('<') {
+            (0, v.parse::<u16>().ok()?.checked_sub(1)?)
+        } else if let Some(v) = value.strip_prefix('>') {
+            (v.parse::<u16>().ok()?.checked_add(1)?, u16::MAX)
+        } else {
+            // `:` or `=`
+            match value[1..].split_once('-') {
+                Some((from, to)) => {
+                    let (from, to): (u16, u16) = (from.parse().ok()?, to.parse().ok()?);
+                    (from.min(to), from.max(to))
+                }
+                None => {
+                    let v = value[1..].parse().ok()?;
+                    (v, v)
+                }
+            }
+        };
+
+        Some(Self { property, min, max })
+    }
+
+    /// Returns `true` if `value` is within the range of the tag
+    #[inline]
+    pub fn contains(&self, value: u16) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
 impl Query {
     #[inline]
     pub fn is_valid(&self) -> bool {
@@ -349,6 +478,18 @@ impl Query {
         self.tags.iter().filter_map(|i| i.as_misc())
     }
 
+    /// Returns an iterator over all field, dialect and loanword tags
+    #[inline]
+    pub fn get_sense_tags(&self) -> impl Iterator<Item = &Tag> + '_ {
+        self.tags.iter().filter(|i| i.is_sense_tag())
+    }
+
+    /// Returns an iterator over all kanji range tags
+    #[inline]
+    pub fn get_kanji_range_tags(&self) -> impl Iterator<Item = &KanjiRangeTag> + '_ {
+        self.tags.iter().filter_map(|i| i.as_kanji_range())
+    }
+
     /// Returns the result offset by a given page
     #[inline]
     pub fn page_offset(&self, page_size: usize) -> usize {
@@ -392,15 +533,30 @@ impl Query {
         self.language_override.unwrap_or(self.settings.user_lang)
     }
 
+    /// Returns the alternatives of an OR expression which have to be searched for separately.
+    /// Returns an empty list if there are none
+    pub fn alternative_queries(&self) -> Vec<&str> {
+        match &self.expression {
+            Some(expression) if expression.has_alternatives() => expression.search_terms(),
+            _ => vec![],
+        }
+    }
+
     /// Returns a `RegexSQuery` if the query contains a valid regex
     pub fn as_regex_query(&self) -> Option<RegexSQuery> {
-        // Only japanese regex support (for now)
-        if self.language != QueryLang::Japanese {
-            return None;
+        // returns `None` if no regex given, so we don't need to check for that here
+        if self.language == QueryLang::Japanese {
+            RegexSQuery::new(&self.query)
+        } else {
+            self.as_foreign_regex_query()
         }
+    }
 
-        // returns `None` if no regex given, so we don't need to check for that here
-        RegexSQuery::new(&self.query)
+    /// Returns a `RegexSQuery` to search in glosses or transcriptions if the query contains a
+    /// wildcard. `+` and a trailing `?` are treated as literal characters
+    pub fn as_foreign_regex_query(&self) -> Option<RegexSQuery> {
+        // Glosses and transcriptions are indexed in lowercase
+        RegexSQuery::new_foreign(&self.query.to_lowercase())
     }
 }
 
@@ -418,4 +574,50 @@ mod test {
         assert_eq!(Tag::parse_genki_tag("#genki3"), Some(Tag::GenkiLesson(3)));
         assert_eq!(Tag::parse_genki_tag("#genki23"), Some(Tag::GenkiLesson(23)));
     }
+
+    #[test]
+    fn test_parse_sense_tag_parsing() {
+        assert_eq!(
+            Tag::parse_from_str("#field:medicine"),
+            Some(Tag::Field(Field::Medicine))
+        );
+        assert_eq!(
+            Tag::parse_from_str("#field:med"),
+            Some(Tag::Field(Field::Medicine))
+        );
+        assert_eq!(
+            Tag::parse_from_str("#dialect:kansai"),
+            Some(Tag::Dialect(Dialect::Kansai))
+        );
+        assert_eq!(
+            Tag::parse_from_str("#loan:ger"),
+            Some(Tag::Loan(ForeignLanguage::German))
+        );
+        assert_eq!(Tag::parse_from_str("#field:unknown"), None);
+    }
+
+    #[test]
+    fn test_parse_kanji_range_tag_parsing() {
+        let range =
+            |property, min, max| Some(Tag::KanjiRange(KanjiRangeTag { property, min, max }));
+
+        assert_eq!(
+            Tag::parse_from_str("#strokes:5-7"),
+            range(KanjiProperty::Strokes, 5, 7)
+        );
+        assert_eq!(
+            Tag::parse_from_str("#grade:2"),
+            range(KanjiProperty::Grade, 2, 2)
+        );
+        assert_eq!(
+            Tag::parse_from_str("#freq<500"),
+            range(KanjiProperty::Frequency, 0, 499)
+        );
+        assert_eq!(
+            Tag::parse_from_str("#freq>=500"),
+            range(KanjiProperty::Frequency, 500, u16::MAX)
+        );
+        assert_eq!(Tag::parse_from_str("#freq<0"), None);
+        assert_eq!(Tag::parse_from_str("#strokes:a"), None);
+    }
 }
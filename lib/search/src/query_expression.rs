@@ -0,0 +1,341 @@
+//! Boolean and phrase operators within search queries
+//!
+//! # Example
+//!
+//! "gloss:\"to eat\" -reading:たべる" => Words meaning "to eat" which aren't read as たべる
+//!
+//! # Supported syntax
+//! `-term` - Excludes results matching `term`
+//! `"some phrase"` - Matches the phrase as a whole and in the given order
+//! `a OR b` - Matches results containing `a` or `b`. Binds stronger than the implicit AND between
+//! terms, so `a OR b c` means "(a or b) and c"
+//! `reading:term` - Matches `term` within the kana readings only
+//! `kanji:term` - Matches `term` within the kanji readings only
+//! `gloss:term` - Matches `term` within the translations only
+//!
+//! # Note
+//! A query only gets an expression assigned if it uses at least one of the operators above.
+//! Quoting the whole query is handled as `use_original` instead
+
+use japanese::JapaneseExt;
+
+/// A parsed query expression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expression {
+    Term(Term),
+    Not(Box<Expression>),
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+}
+
+/// A single term of an expression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Term {
+    pub field: Field,
+    pub text: String,
+    pub phrase: bool,
+}
+
+/// The part of a result a term has to be found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    Any,
+    Reading,
+    Kanji,
+    Gloss,
+}
+
+/// Field prefixes a term can have
+const FIELD_PREFIXES: &[(&str, Field)] = &[
+    ("reading:", Field::Reading),
+    ("kanji:", Field::Kanji),
+    ("gloss:", Field::Gloss),
+];
+
+/// A token of a query
+#[derive(Debug, PartialEq)]
+enum Token {
+    Or,
+    Term { term: Term, negated: bool },
+}
+
+impl Token {
+    /// Returns `true` if the token makes use of any operator
+    fn is_operator(&self) -> bool {
+        match self {
+            Token::Or => true,
+            Token::Term { term, negated } => *negated || term.phrase || term.field != Field::Any,
+        }
+    }
+}
+
+impl Expression {
+    /// Parses an expression from `query`. Returns `None` if the query doesn't contain any
+    /// operator or no term at all
+    pub fn parse(query: &str) -> Option<Self> {
+        let tokens = tokenize(query);
+        if !tokens.iter().any(|i| i.is_operator()) {
+            return None;
+        }
+
+        // Terms joined by OR
+        let mut groups: Vec<Vec<Expression>> = vec![];
+        let mut pending_or = false;
+
+        for token in tokens {
+            let (term, negated) = match token {
+                Token::Or => {
+                    pending_or = !groups.is_empty();
+                    continue;
+                }
+                Token::Term { term, negated } => (term, negated),
+            };
+
+            let mut expr = Expression::Term(term);
+            if negated {
+                expr = Expression::Not(Box::new(expr));
+            }
+
+            match groups.last_mut() {
+                Some(group) if pending_or => group.push(expr),
+                _ => groups.push(vec![expr]),
+            }
+            pending_or = false;
+        }
+
+        let mut exprs: Vec<_> = groups
+            .into_iter()
+            .map(|mut group| {
+                if group.len() == 1 {
+                    group.remove(0)
+                } else {
+                    Expression::Or(group)
+                }
+            })
+            .collect();
+
+        match exprs.len() {
+            0 => None,
+            1 => Some(exprs.remove(0)),
+            _ => Some(Expression::And(exprs)),
+        }
+    }
+
+    /// Returns `true` if the expression matches. `term_matches` has to decide whether a single
+    /// term matches
+    pub fn matches<F: Fn(&Term) -> bool>(&self, term_matches: &F) -> bool {
+        match self {
+            Expression::Term(term) => term_matches(term),
+            Expression::Not(expr) => !expr.matches(term_matches),
+            Expression::And(exprs) => exprs.iter().all(|i| i.matches(term_matches)),
+            Expression::Or(exprs) => exprs.iter().any(|i| i.matches(term_matches)),
+        }
+    }
+
+    /// Returns the texts of all terms which aren't excluded and therefore have to be searched for
+    pub fn search_terms(&self) -> Vec<&str> {
+        let mut out = vec![];
+        self.collect_search_terms(&mut out);
+        out
+    }
+
+    /// Returns `true` if the expression contains alternatives which have to be searched
+    /// separately
+    pub fn has_alternatives(&self) -> bool {
+        match self {
+            Expression::Term(_) | Expression::Not(_) => false,
+            Expression::And(exprs) => exprs.iter().any(|i| i.has_alternatives()),
+            Expression::Or(_) => true,
+        }
+    }
+
+    fn collect_search_terms<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Expression::Term(term) => out.push(&term.text),
+            Expression::Not(_) => (),
+            Expression::And(exprs) | Expression::Or(exprs) => {
+                for expr in exprs {
+                    expr.collect_search_terms(out);
+                }
+            }
+        }
+    }
+}
+
+impl Term {
+    /// Returns `true` if `text` contains the term. Non japanese terms have to match whole words
+    /// and case gets ignored
+    pub fn found_in(&self, text: &str) -> bool {
+        if self.text.has_japanese() {
+            text.contains(&self.text)
+        } else {
+            contains_words(text, &self.text)
+        }
+    }
+}
+
+/// Returns `true` if all words of `term` are contained in `text` in the same order and next to
+/// each other. Case gets ignored
+pub fn contains_words(text: &str, term: &str) -> bool {
+    let text_words = split_words(text);
+    let term_words = split_words(term);
+
+    if term_words.is_empty() {
+        return false;
+    }
+
+    text_words
+        .windows(term_words.len())
+        .any(|window| window == term_words.as_slice())
+}
+
+/// Splits `text` into lowercase words
+fn split_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|i| !i.is_empty())
+        .map(|i| i.to_lowercase())
+        .collect()
+}
+
+/// Splits a query into its tokens
+fn tokenize(query: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = query.trim_start();
+
+    while !rest.is_empty() {
+        let (token, new_rest) = next_token(rest);
+        if let Some(token) = token {
+            tokens.push(token);
+        }
+        rest = new_rest.trim_start();
+    }
+
+    tokens
+}
+
+/// Parses the token at the beginning of `query`. Returns the token (if valid) and the remaining
+/// query
+fn next_token(query: &str) -> (Option<Token>, &str) {
+    let mut rest = query;
+
+    let negated = rest.len() > 1 && rest.starts_with('-') && !starts_with_whitespace(&rest[1..]);
+    if negated {
+        rest = &rest[1..];
+    }
+
+    let mut field = Field::Any;
+    for (prefix, prefix_field) in FIELD_PREFIXES {
+        if rest.starts_with(prefix) && !starts_with_whitespace(&rest[prefix.len()..]) {
+            field = *prefix_field;
+            rest = &rest[prefix.len()..];
+            break;
+        }
+    }
+
+    let (text, phrase, rest) = if let Some(quoted) = rest.strip_prefix('"') {
+        let end = quoted.find('"').unwrap_or(quoted.len());
+        let rest = quoted.get(end + 1..).unwrap_or("");
+        (quoted[..end].trim(), true, rest)
+    } else {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        (&rest[..end], false, &rest[end..])
+    };
+
+    if text.is_empty() {
+        return (None, rest);
+    }
+
+    if text == "OR" && !negated && !phrase && field == Field::Any {
+        return (Some(Token::Or), rest);
+    }
+
+    let term = Term {
+        field,
+        text: text.to_string(),
+        phrase,
+    };
+
+    (Some(Token::Term { term, negated }), rest)
+}
+
+#[inline]
+fn starts_with_whitespace(s: &str) -> bool {
+    s.chars().next().map(char::is_whitespace).unwrap_or(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn term(field: Field, text: &str, phrase: bool) -> Expression {
+        Expression::Term(Term {
+            field,
+            text: text.to_string(),
+            phrase,
+        })
+    }
+
+    #[test]
+    fn test_no_operators() {
+        assert_eq!(Expression::parse("dog"), None);
+        assert_eq!(Expression::parse("big dog"), None);
+        assert_eq!(Expression::parse("e-mail"), None);
+        assert_eq!(Expression::parse("- dog"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Expression::parse("dog -cat"),
+            Some(Expression::And(vec![
+                term(Field::Any, "dog", false),
+                Expression::Not(Box::new(term(Field::Any, "cat", false))),
+            ]))
+        );
+
+        assert_eq!(
+            Expression::parse("dog OR cat \"to eat\""),
+            Some(Expression::And(vec![
+                Expression::Or(vec![
+                    term(Field::Any, "dog", false),
+                    term(Field::Any, "cat", false)
+                ]),
+                term(Field::Any, "to eat", true),
+            ]))
+        );
+
+        assert_eq!(
+            Expression::parse("gloss:\"to eat\" -reading:たべる"),
+            Some(Expression::And(vec![
+                term(Field::Gloss, "to eat", true),
+                Expression::Not(Box::new(term(Field::Reading, "たべる", false))),
+            ]))
+        );
+
+        assert_eq!(
+            Expression::parse("kanji:食"),
+            Some(term(Field::Kanji, "食", false))
+        );
+    }
+
+    #[test]
+    fn test_search_terms() {
+        let expr = Expression::parse("dog OR cat -mouse").unwrap();
+        assert_eq!(expr.search_terms(), vec!["dog", "cat"]);
+        assert!(expr.has_alternatives());
+
+        let expr = Expression::parse("dog -mouse").unwrap();
+        assert_eq!(expr.search_terms(), vec!["dog"]);
+        assert!(!expr.has_alternatives());
+    }
+
+    #[test]
+    fn test_matches() {
+        let expr = Expression::parse("\"to eat\" -quickly").unwrap();
+        let matches = |text: &str| expr.matches(&|term: &Term| term.found_in(text));
+
+        assert!(matches("to eat (something)"));
+        assert!(!matches("to eat quickly"));
+        assert!(!matches("eat to"));
+    }
+}
@@ -8,7 +8,7 @@ use types::jotoba::{
     words::part_of_speech::PosSimple,
 };
 
-use crate::regex_query;
+use crate::{query_expression::Expression, regex_query};
 
 use super::query::{Form, Query, QueryLang, SearchTypeTag, Tag, UserSettings};
 
@@ -101,7 +101,13 @@ impl QueryParser {
     }
 
     /// Parses a user query into Query
-    pub fn parse(self) -> Option<Query> {
+    pub fn parse(mut self) -> Option<Query> {
+        // Only search for the terms of an expression
+        let expression = self.parse_expression();
+        if let Some(expression) = &expression {
+            self.query = expression.search_terms().join(" ");
+        }
+
         // Don't allow empty queries
         if self.query.is_empty() && !self.tags.iter().any(|i| i.is_empty_allowed()) {
             println!("empty");
@@ -109,9 +115,11 @@ impl QueryParser {
         }
 
         let parse_japanese = self.need_jp_parsing();
+        let language = parse_language(&self.query);
+        let romaji_kana = self.parse_romaji(language, expression.is_some());
 
         Some(Query {
-            language: parse_language(&self.query),
+            language,
             type_: self.parse_query_type(),
             form: self.parse_form(),
             tags: self.tags,
@@ -124,9 +132,34 @@ impl QueryParser {
             parse_japanese,
             use_original: self.use_original,
             language_override: self.language_override,
+            romaji_kana,
+            expression,
         })
     }
 
+    /// Parses boolean and phrase operators within the query
+    fn parse_expression(&self) -> Option<Expression> {
+        // Kanji reading queries may contain a '-' in the reading
+        if self.use_original || self.parse_kanji_reading().is_some() {
+            return None;
+        }
+
+        Expression::parse(&self.query)
+    }
+
+    /// Returns the query converted into kana if it could be romanized japanese
+    fn parse_romaji(&self, language: QueryLang, has_expression: bool) -> Option<String> {
+        if self.use_original
+            || has_expression
+            || !matches!(language, QueryLang::Foreign | QueryLang::Undetected)
+            || !japanese::guessing::could_be_romaji(&self.query)
+        {
+            return None;
+        }
+
+        japanese::romaji::to_hiragana(&self.query)
+    }
+
     fn need_jp_parsing(&self) -> bool {
         let mod_tags = self
             .tags
@@ -145,7 +178,10 @@ impl QueryParser {
 
     /// Parses the QueryType based on the user selection and tags
     fn parse_query_type(&self) -> QueryType {
-        if self.tags.contains(&Tag::SearchType(SearchTypeTag::Kanji)) {
+        // Kanji range tags can only be applied to kanji
+        if self.tags.contains(&Tag::SearchType(SearchTypeTag::Kanji))
+            || self.tags.iter().any(|i| i.is_kanji_range())
+        {
             QueryType::Kanji
         } else if self.tags.contains(&Tag::SearchType(SearchTypeTag::Word)) {
             QueryType::Words
@@ -1,6 +1,8 @@
+use crate::generation::Generation;
+
 /// Finds radicals by its meanings
-pub fn search(query: &str) -> Vec<char> {
-    crate::engine::radical::find(query)
+pub fn search(generation: &Generation, query: &str) -> Vec<char> {
+    crate::engine::radical::find(generation, query)
         .into_iter()
         .map(|j| j.literal)
         .collect()
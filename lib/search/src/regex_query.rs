@@ -3,11 +3,16 @@
 //! # Example
 //!
 //! "宇宙*行士" => "宇宙飛行士"
+//! "*ology" => "biology"
 //!
 //! # Supported syntax
 //! `*` - Allows 0-n other characters
 //! `?` - Allows 1 other characters
 //!
+//! Foreign queries don't support `+` since it's common in regular text (eg. "c++"). A `?` at the
+//! end of a foreign query is no wildcard either, so plain questions like "what?" don't get
+//! treated as regex query
+//!
 //! # Note
 //! All queries containing (custom)regex syntax will be handled as full-word matches. In other words if
 //! a query contains regex syntax, only full words will be matched. If words should also be open to
@@ -23,19 +28,37 @@ pub const REGEX_CHARS: &[char] = &['*', '?', '?'];
 pub struct RegexSQuery {
     query: String,
     regex: Regex,
+    /// `+` and a trailing `?` are no wildcards
+    foreign: bool,
 }
 
 impl RegexSQuery {
     /// Create a new regex query. Returns `None` if invalid or no regex given
+    #[inline]
     pub fn new(query: &str) -> Option<Self> {
+        Self::with_mode(query, false)
+    }
+
+    /// Create a new regex query for foreign text in which `+` and a trailing `?` aren't treated
+    /// as wildcard. Returns `None` if invalid or no wildcard given
+    #[inline]
+    pub fn new_foreign(query: &str) -> Option<Self> {
+        Self::with_mode(query, true)
+    }
+
+    fn with_mode(query: &str, foreign: bool) -> Option<Self> {
         let query = adjust_regex(query);
 
-        if !Self::is_regex(&query) {
+        if !Self::is_regex(&query, foreign) {
             return None;
         }
 
-        let regex = Regex::new(&Self::convert_regex(&query)).ok()?;
-        Some(RegexSQuery { query, regex })
+        let regex = Regex::new(&Self::convert_regex(&query, foreign)).ok()?;
+        Some(RegexSQuery {
+            query,
+            regex,
+            foreign,
+        })
     }
 
     /// Returns `true` if a word matches the regex query
@@ -47,36 +70,33 @@ impl RegexSQuery {
     /// Returns the query-information holding characters from the query. In other words those, who
     /// don't represent regex syntax
     pub fn get_chars(&self) -> Vec<char> {
-        let mut out = Vec::with_capacity(self.query.len());
-        for c in self.query.chars() {
-            if !REGEX_CHARS.contains(&c) {
-                out.push(c);
-            }
-        }
-        out
+        wildcards(&self.query, self.foreign)
+            .filter(|(_, wildcard)| !wildcard)
+            .map(|(c, _)| c)
+            .collect()
     }
 
     /// Returns a real regex expression which will be used to match words
-    fn convert_regex(query: &str) -> String {
+    fn convert_regex(query: &str, foreign: bool) -> String {
         let mut out = String::with_capacity(query.len() + 2);
         out.push('^');
-        out.push_str(
-            &query
-                .replace('*', ".*")
-                .replace('?', ".{1}")
-                .replace('+', ".{1}"),
-        );
-        if !out.ends_with('$') {
-            out.push('$');
+        for (c, wildcard) in wildcards(query, foreign) {
+            match c {
+                '*' if wildcard => out.push_str(".*"),
+                _ if wildcard => out.push_str(".{1}"),
+                // Glosses may contain characters with a special meaning in regex
+                _ => out.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+            }
         }
+        out.push('$');
         out
     }
 
     /// Returns `true` if query can be interpreted as regex query
     #[inline]
-    fn is_regex(query: &str) -> bool {
+    fn is_regex(query: &str, foreign: bool) -> bool {
         let query = adjust_regex(query);
-        query.contains('*') || query.contains('+') || query.contains('?')
+        wildcards(&query, foreign).any(|(_, wildcard)| wildcard)
     }
 
     /// Get a reference to the regex squery's query.
@@ -85,6 +105,20 @@ impl RegexSQuery {
     }
 }
 
+/// Returns all characters of `query` along with whether they are a wildcard
+fn wildcards(query: &str, foreign: bool) -> impl Iterator<Item = (char, bool)> + '_ {
+    let len = query.chars().count();
+    query.chars().enumerate().map(move |(pos, c)| {
+        let wildcard = match c {
+            '*' => true,
+            '+' => !foreign,
+            '?' => !foreign || pos + 1 < len,
+            _ => false,
+        };
+        (c, wildcard)
+    })
+}
+
 /// Adjusts the query to a consistent format
 #[inline]
 fn adjust_regex(query: &str) -> String {
@@ -93,3 +127,41 @@ fn adjust_regex(query: &str) -> String {
         .replace('＋', "+")
         .replace('？', "?")
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let query = RegexSQuery::new("*ology").unwrap();
+        assert!(query.matches("biology"));
+        assert!(!query.matches("biologys"));
+
+        let query = RegexSQuery::new("(to) ru?").unwrap();
+        assert!(query.matches("(to) run"));
+        assert!(!query.matches("to run"));
+
+        assert!(RegexSQuery::new("biology").is_none());
+    }
+
+    #[test]
+    fn test_foreign() {
+        assert!(RegexSQuery::new_foreign("what?").is_none());
+        assert!(RegexSQuery::new_foreign("c++").is_none());
+
+        let query = RegexSQuery::new_foreign("*ology?").unwrap();
+        assert!(query.matches("biology?"));
+        assert!(!query.matches("biologys"));
+        assert_eq!(query.get_chars().into_iter().collect::<String>(), "ology?");
+
+        let query = RegexSQuery::new_foreign("r?n").unwrap();
+        assert!(query.matches("run"));
+        assert!(!query.matches("rn"));
+        assert_eq!(query.get_chars().into_iter().collect::<String>(), "rn");
+
+        let query = RegexSQuery::new_foreign("c++ *").unwrap();
+        assert!(query.matches("c++ compiler"));
+        assert!(!query.matches("cxx compiler"));
+    }
+}
@@ -5,6 +5,8 @@ use types::jotoba::{
     sentences::Sentence,
 };
 
+use crate::generation::Generation;
+
 pub(crate) fn sentence_matches(sentence: &Sentence, reading: &Reading) -> bool {
     let lit = reading.get_lit_str();
 
@@ -43,8 +45,8 @@ pub(crate) fn sentence_matches(sentence: &Sentence, reading: &Reading) -> bool {
     false
 }
 
-pub(crate) fn get_reading(reading: &ReadingSearch) -> Option<Reading> {
-    let kanji_storage = resources::get().kanji();
+pub(crate) fn get_reading(generation: &Generation, reading: &ReadingSearch) -> Option<Reading> {
+    let kanji_storage = generation.resources().kanji();
     let kanji = kanji_storage.by_literal(reading.literal)?;
     let reading = kanji.find_reading(&reading.reading)?;
     Some(reading)
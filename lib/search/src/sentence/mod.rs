@@ -9,18 +9,21 @@ use self::result::{Item, SentenceResult};
 use super::query::Query;
 use crate::{
     engine::{guess::Guess, sentences::foreign, sentences::native, SearchEngine, SearchTask},
+    generation::Generation,
     query::{Form, QueryLang, Tag},
+    query_expression::{Field, Term},
 };
 use error::Error;
+use japanese::JapaneseExt;
 use types::jotoba::{languages::Language, sentences::Sentence};
 
 /// Searches for sentences
-pub fn search(query: &Query) -> Result<SentenceResult, Error> {
+pub fn search(generation: &Generation, query: &Query) -> Result<SentenceResult, Error> {
     let start = Instant::now();
 
     let res = match query.form {
-        Form::TagOnly => tag_only::search(query)?,
-        _ => normal_search(query)?,
+        Form::TagOnly => tag_only::search(generation, query)?,
+        _ => normal_search(generation, query)?,
     };
 
     println!("Sentence search took: {:?}", start.elapsed());
@@ -28,45 +31,65 @@ pub fn search(query: &Query) -> Result<SentenceResult, Error> {
     Ok(res)
 }
 
-fn normal_search(query: &Query) -> Result<SentenceResult, Error> {
+fn normal_search(generation: &Generation, query: &Query) -> Result<SentenceResult, Error> {
     let res = if query.language == QueryLang::Japanese {
         let query_str = jp_reading(query);
-        get_result(jp_search(query, &query_str), query)
+        get_result(jp_search(generation, query, &query_str), query)
     } else {
-        get_result(foreign_search(query), query)
+        get_result(foreign_search(generation, query), query)
     }?;
 
     Ok(res)
 }
 
-fn foreign_search(query: &Query) -> SearchTask<foreign::Engine> {
+fn foreign_search<'a>(
+    generation: &'a Generation,
+    query: &Query,
+) -> SearchTask<'a, foreign::Engine> {
     let query_str = &query.query;
 
-    let mut search_task =
-        SearchTask::<foreign::Engine>::with_language(query_str, query.settings.user_lang)
-            .limit(query.settings.page_size as usize)
-            .offset(query.page_offset)
-            .threshold(0.0);
+    let mut search_task = SearchTask::<foreign::Engine>::with_language(
+        generation,
+        query_str,
+        query.settings.user_lang,
+    )
+    .limit(query.settings.page_size as usize)
+    .offset(query.page_offset)
+    .threshold(0.0);
 
     if query.settings.show_english && query.settings.user_lang != Language::English {
         search_task.add_language_query(&query.query, Language::English)
     }
 
-    lang_filter(query, &mut search_task);
+    // Search for all alternatives of an OR expression
+    for alternative in query.alternative_queries() {
+        search_task.add_language_query(alternative, query.settings.user_lang);
+    }
+
+    lang_filter(generation, query, &mut search_task);
     sort_fn(query, query_str.to_string(), &mut search_task, false);
 
     search_task
 }
 
-fn jp_search<'a>(query: &Query, query_str: &'a str) -> SearchTask<'a, native::Engine> {
+fn jp_search<'a>(
+    generation: &'a Generation,
+    query: &Query,
+    query_str: &str,
+) -> SearchTask<'a, native::Engine> {
     println!("query: {}", query_str);
 
-    let mut search_task = SearchTask::<native::Engine>::new(&query_str)
+    let mut search_task = SearchTask::<native::Engine>::new(generation, query_str)
         .limit(query.settings.page_size as usize)
         .offset(query.page_offset)
         .threshold(0.0);
 
-    lang_filter(query, &mut search_task);
+    // Search for all alternatives of an OR expression
+    for alternative in query.alternative_queries() {
+        search_task.add_query(alternative);
+    }
+
+    lang_filter(generation, query, &mut search_task);
     sort_fn(query, query_str.to_string(), &mut search_task, true);
 
     search_task
@@ -97,6 +120,7 @@ fn sort_fn<T: SearchEngine<Output = Sentence> + Send>(
 
 /// Sets a SearchTasks language filter
 fn lang_filter<T: SearchEngine<Output = Sentence> + Send>(
+    generation: &Generation,
     query: &Query,
     search_task: &mut SearchTask<T>,
 ) {
@@ -106,7 +130,9 @@ fn lang_filter<T: SearchEngine<Output = Sentence> + Send>(
     let kanji_reading = query
         .form
         .as_kanji_reading()
-        .and_then(|i| kanji::get_reading(i));
+        .and_then(|i| kanji::get_reading(generation, i));
+
+    let expression = query.expression.clone();
 
     search_task.set_result_filter(move |sentence| {
         let lang_filter = sentence.has_translation(lang)
@@ -117,6 +143,14 @@ fn lang_filter<T: SearchEngine<Output = Sentence> + Send>(
             return false;
         }
 
+        if let Some(expression) = &expression {
+            let term_matches =
+                |term: &Term| sentence_matches_term(sentence, term, lang, show_english);
+            if !expression.matches(&term_matches) {
+                return false;
+            }
+        }
+
         if let Some(reading) = &kanji_reading {
             return kanji::sentence_matches(sentence, &reading);
         }
@@ -125,6 +159,37 @@ fn lang_filter<T: SearchEngine<Output = Sentence> + Send>(
     })
 }
 
+/// Returns `true` if `term` of a query expression can be found in `sentence`
+fn sentence_matches_term(
+    sentence: &Sentence,
+    term: &Term,
+    lang: Language,
+    show_english: bool,
+) -> bool {
+    let in_reading = || {
+        sentence
+            .get_kana()
+            .to_hiragana()
+            .contains(&term.text.to_hiragana())
+    };
+
+    let in_translation = || {
+        sentence
+            .translations
+            .iter()
+            .filter(|i| i.language == lang || (show_english && i.language == Language::English))
+            .any(|i| term.found_in(&i.text))
+    };
+
+    match term.field {
+        Field::Reading => in_reading(),
+        Field::Kanji => term.found_in(&sentence.japanese),
+        Field::Gloss => in_translation(),
+        Field::Any if term.text.has_japanese() => term.found_in(&sentence.japanese) || in_reading(),
+        Field::Any => in_translation(),
+    }
+}
+
 fn get_result<T: SearchEngine<Output = Sentence> + Send>(
     search: SearchTask<T>,
     query: &Query,
@@ -134,7 +199,7 @@ fn get_result<T: SearchEngine<Output = Sentence> + Send>(
     let len = found.len();
     let items = found
         .item_iter()
-        .filter_map(|i| map_sentence_to_item(i, lang, query))
+        .filter_map(|i| map_sentence_to_item(&i, lang, query))
         .collect::<Vec<_>>();
 
     let hidden = query.has_tag(Tag::Hidden);
@@ -148,12 +213,12 @@ fn map_sentence_to_item(sentence: &Sentence, lang: Language, query: &Query) -> O
 }
 
 /// Guesses the amount of results a search would return with given `query`
-pub fn guess_result(query: &Query) -> Option<Guess> {
+pub fn guess_result(generation: &Generation, query: &Query) -> Option<Guess> {
     if query.language == QueryLang::Japanese {
         let query_str = jp_reading(query);
-        jp_search(query, &query_str).estimate_result_count()
+        jp_search(generation, query, &query_str).estimate_result_count()
     } else {
-        foreign_search(query).estimate_result_count()
+        foreign_search(generation, query).estimate_result_count()
     }
     .ok()
 }
@@ -29,6 +29,13 @@ impl Sentence {
         furigana::from_str(&self.furigana)
     }
 
+    /// Returns the Hepburn romanization of the sentence
+    #[inline]
+    pub fn get_romaji(&self) -> String {
+        let kana: String = self.furigana_pairs().map(|i| i.kana).collect();
+        japanese::romaji::to_romaji(&kana)
+    }
+
     #[inline]
     pub fn get_english(&self) -> Option<&str> {
         if self.eng == "-" {
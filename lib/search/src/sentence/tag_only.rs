@@ -1,11 +1,14 @@
 use error::Error;
 use types::jotoba::languages::Language;
 
-use crate::query::{Query, Tag};
+use crate::{
+    generation::Generation,
+    query::{Query, Tag},
+};
 
 use super::result::SentenceResult;
 
-pub(super) fn search(query: &Query) -> Result<SentenceResult, Error> {
+pub(super) fn search(generation: &Generation, query: &Query) -> Result<SentenceResult, Error> {
     let filter_tag = query
         .tags
         .iter()
@@ -14,16 +17,16 @@ pub(super) fn search(query: &Query) -> Result<SentenceResult, Error> {
         .ok_or(Error::Unexpected)?;
 
     if let Tag::Jlpt(jlpt) = filter_tag {
-        return jlpt_search(query, *jlpt);
+        return jlpt_search(generation, query, *jlpt);
     } else {
         return Ok(SentenceResult::default());
     }
 }
 
-fn jlpt_search(query: &Query, jlpt: u8) -> Result<SentenceResult, Error> {
+fn jlpt_search(generation: &Generation, query: &Query, jlpt: u8) -> Result<SentenceResult, Error> {
     assert!(jlpt > 0 && jlpt < 6);
 
-    let resources = resources::get();
+    let resources = generation.resources();
 
     let senences = resources
         .sentence_jlpt(jlpt)
@@ -31,6 +34,19 @@ fn jlpt_search(query: &Query, jlpt: u8) -> Result<SentenceResult, Error> {
             sentence.has_translation(query.settings.user_lang)
                 && (sentence.has_translation(Language::English) && query.settings.show_english)
         })
+        .filter(|sentence| {
+            let lang = query.settings.user_lang;
+            let show_english = query.settings.show_english;
+            query
+                .expression
+                .as_ref()
+                .map(|expression| {
+                    expression.matches(&|term| {
+                        super::sentence_matches_term(sentence, term, lang, show_english)
+                    })
+                })
+                .unwrap_or(true)
+        })
         .take(10000)
         .collect::<Vec<_>>();
 
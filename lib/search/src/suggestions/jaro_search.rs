@@ -13,6 +13,7 @@ pub(crate) struct Search<'a, T: TextStore> {
     text_store: &'a T,
     last_pos: usize,
     len_limit: usize,
+    shorter_limit: usize,
     eudex_hash: eudex::Hash,
     query_len: usize,
 }
@@ -24,11 +25,18 @@ impl<'a, T: TextStore> Search<'a, T> {
             text_store,
             last_pos: 0,
             len_limit,
+            shorter_limit: 0,
             eudex_hash: eudex::Hash::new(query),
             query_len: query.len(),
         }
     }
 
+    /// Also match items which are up to `limit` bytes shorter than the query
+    pub(crate) fn shorter_limit(mut self, limit: usize) -> Self {
+        self.shorter_limit = limit;
+        self
+    }
+
     /// Returns an iterator over each result. Calling `search` without using the result does
     /// nothing
     pub(crate) fn search(mut self) -> impl Iterator<Item = &'a T::Item> {
@@ -58,7 +66,9 @@ impl<'a, T: TextStore> Search<'a, T> {
         let item_text = item.get_text();
 
         // Filter out impossible/unlike matches
-        if self.query_len > item_text.len() || self.query_len + self.len_limit < item_text.len() {
+        if self.query_len > item_text.len() + self.shorter_limit
+            || self.query_len + self.len_limit < item_text.len()
+        {
             return None;
         }
 
@@ -124,6 +124,18 @@ impl<T: TextStore> TextSearch<T> {
         self.jaro_search(query, len_limit).search()
     }
 
+    /// Returns an iterator over all items which are similar to `query` and at most `len_limit`
+    /// bytes longer or shorter than it
+    pub fn find_jaro_similar<'a>(
+        &'a self,
+        query: &'a str,
+        len_limit: usize,
+    ) -> impl Iterator<Item = &'a T::Item> {
+        self.jaro_search(query, len_limit)
+            .shorter_limit(len_limit)
+            .search()
+    }
+
     pub async fn find_jaro_async<'a>(
         &'a self,
         query: &'a str,
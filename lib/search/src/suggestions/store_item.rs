@@ -1,3 +1,5 @@
+use resources::models::suggestions::foreign_words::ForeignSuggestion;
+
 /// An item which can be stored within [`TextStore`]
 pub trait Item {
     fn get_text(&self) -> &str;
@@ -28,3 +30,17 @@ impl Item for &str {
         self
     }
 }
+
+impl Item for ForeignSuggestion {
+    fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    fn get_hash(&self) -> eudex::Hash {
+        self.hash
+    }
+
+    fn ord(&self) -> usize {
+        self.occurrences as usize
+    }
+}
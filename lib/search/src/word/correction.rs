@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+
+use strsim::jaro_winkler;
+use types::jotoba::languages::Language;
+
+use crate::generation::Generation;
+
+/// Max amount of corrections to propose
+const MAX_CORRECTIONS: usize = 3;
+
+/// Min similarity of a term to be proposed as correction
+const MIN_SIMILARITY: f64 = 0.85;
+
+/// Max difference in length between the query and a proposed term
+const MAX_LEN_DIFF: usize = 2;
+
+/// Returns the known terms of `language` which are the most similar to `query`. Returns an empty
+/// vector if no suggestions are available for `language`
+pub(crate) fn find(generation: &Generation, query: &str, language: Language) -> Vec<String> {
+    let terms = match generation.correction_terms(language) {
+        Some(terms) => terms,
+        None => return vec![],
+    };
+
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let mut found: Vec<(f64, u32, String)> = terms
+        .find_jaro_similar(&query, MAX_LEN_DIFF)
+        .filter_map(|i| {
+            let text = i.text.to_lowercase();
+            let similarity = jaro_winkler(&text, &query);
+            (similarity >= MIN_SIMILARITY && text != query).then(|| (similarity, i.occurrences, text))
+        })
+        .collect();
+
+    found.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(Ordering::Equal)
+            .then(b.1.cmp(&a.1))
+    });
+
+    let mut corrections: Vec<String> = Vec::with_capacity(MAX_CORRECTIONS);
+    for (_, _, text) in found {
+        if !corrections.contains(&text) {
+            corrections.push(text);
+        }
+        if corrections.len() == MAX_CORRECTIONS {
+            break;
+        }
+    }
+
+    corrections
+}
@@ -1,6 +1,9 @@
+use std::borrow::Cow;
+
 use super::{super::query::Query, ResultData, Search};
 use crate::{
     engine::{words::native, SearchTask},
+    generation::Generation,
     word::order,
 };
 
@@ -20,7 +23,7 @@ pub(super) fn by_reading(search: &Search<'_>) -> Result<ResultData, Error> {
         .as_kanji_reading()
         .ok_or(Error::Undefined)?;
 
-    let kanji_storage = resources::get().kanji();
+    let kanji_storage = search.generation.resources().kanji();
 
     let kanji = kanji_storage
         .by_literal(reading.literal)
@@ -32,8 +35,13 @@ pub(super) fn by_reading(search: &Search<'_>) -> Result<ResultData, Error> {
     }
     let reading_type = reading_type.unwrap();
 
-    let (words, count) =
-        words_with_kanji_reading(kanji, reading_type, &reading.reading, search.query)?;
+    let (words, count) = words_with_kanji_reading(
+        search.generation,
+        &kanji,
+        reading_type,
+        &reading.reading,
+        search.query,
+    )?;
 
     Ok(ResultData {
         count,
@@ -43,6 +51,7 @@ pub(super) fn by_reading(search: &Search<'_>) -> Result<ResultData, Error> {
 }
 
 fn words_with_kanji_reading(
+    generation: &Generation,
     kanji: &Kanji,
     _rt: ReadingType,
     reading: &str,
@@ -50,7 +59,7 @@ fn words_with_kanji_reading(
 ) -> Result<(Vec<Word>, usize), Error> {
     let query_str = kanji.literal.to_string();
 
-    let mut search_task = SearchTask::<native::Engine>::new(&query_str)
+    let mut search_task = SearchTask::<native::Engine>::new(generation, &query_str)
         .threshold(0.1)
         .limit(query.settings.page_size as usize)
         .offset(query.page_offset);
@@ -70,7 +79,7 @@ fn words_with_kanji_reading(
 
             let readings = japanese::furigana::generate::retrieve_readings(
                 &mut |i: String| {
-                    let retrieve = resources::get().kanji();
+                    let retrieve = generation.resources().kanji();
                     let kanji = retrieve.by_literal(i.chars().next()?)?;
                     if kanji.onyomi.is_none() && kanji.kunyomi.is_none() {
                         return None;
@@ -111,7 +120,7 @@ fn words_with_kanji_reading(
 
     let res = search_task.find()?;
     let len = res.len();
-    let mut words = res.item_iter().cloned().collect::<Vec<_>>();
+    let mut words = res.item_iter().map(Cow::into_owned).collect::<Vec<_>>();
 
     super::filter_languages(
         words.iter_mut(),
@@ -132,13 +141,14 @@ fn alternative_reading_search(search: &Search<'_>) -> Result<ResultData, Error>
             query: kanji::literal_kun_reading(&reading.reading),
             ..search.query.to_owned()
         },
+        generation: search.generation,
     }
     .do_word_search()
 }
 
 /// Load word assigned kanji
-pub fn load_word_kanji_info(words: &[Word]) -> Vec<Kanji> {
-    let kanji_resources = resources::get().kanji();
+pub fn load_word_kanji_info(generation: &Generation, words: &[Word]) -> Vec<Kanji> {
+    let kanji_resources = generation.resources().kanji();
 
     let kanji_literals = words
         .iter()
@@ -149,7 +159,7 @@ pub fn load_word_kanji_info(words: &[Word]) -> Vec<Kanji> {
         .flatten()
         .map(|i| i.chars().collect::<Vec<_>>())
         .flatten()
-        .filter_map(|i| kanji_resources.by_literal(i).cloned())
+        .filter_map(|i| kanji_resources.by_literal(i).map(Cow::into_owned))
         .unique_by(|i| i.literal)
         .take(10)
         .collect::<Vec<_>>();
@@ -1,10 +1,11 @@
+mod correction;
 pub mod kanji;
 pub mod order;
 mod regex;
 pub mod result;
 pub mod tag_only;
 
-use std::time::Instant;
+use std::{borrow::Cow, time::Instant};
 
 use crate::{
     engine::{
@@ -14,11 +15,14 @@ use crate::{
         words::{foreign, native},
         SearchTask,
     },
+    generation::Generation,
     query::Form,
+    query_expression::{Field, Term},
+    regex_query::RegexSQuery,
 };
 
 use self::result::{InflectionInformation, WordResult};
-use super::query::{Query, QueryLang};
+use super::query::{Query, QueryLang, Tag};
 use error::Error;
 use itertools::Itertools;
 use japanese::JapaneseExt;
@@ -33,15 +37,19 @@ use types::jotoba::{
 };
 use utils::{real_string_len, to_option};
 
+/// Foreign searches with less results than this get spelling corrections proposed
+const CORRECTION_RESULT_THRESHOLD: usize = 5;
+
 pub struct Search<'a> {
     query: &'a Query,
+    generation: &'a Generation,
 }
 
-/// Search among all data based on the input query
+/// Search among all data of `generation` based on the input query
 #[inline]
-pub fn search(query: &Query) -> Result<WordResult, Error> {
+pub fn search(generation: &Generation, query: &Query) -> Result<WordResult, Error> {
     let start = Instant::now();
-    let res = Search { query }.do_search();
+    let res = Search { query, generation }.do_search();
     println!("Search took {:?}", start.elapsed());
     res
 }
@@ -54,6 +62,8 @@ pub(crate) struct ResultData {
     pub(crate) sentence_index: usize,
     pub(crate) sentence_parts: Option<sentence_reader::Sentence>,
     pub(crate) searched_query: String,
+    pub(crate) romaji_kana: Option<String>,
+    pub(crate) corrections: Vec<String>,
 }
 
 impl<'a> Search<'a> {
@@ -67,7 +77,7 @@ impl<'a> Search<'a> {
 
         let words = search_result.words;
 
-        let kanji_results = kanji::load_word_kanji_info(&words);
+        let kanji_results = kanji::load_word_kanji_info(self.generation, &words);
 
         let res = WordResult {
             contains_kanji: !kanji_results.is_empty(),
@@ -77,6 +87,8 @@ impl<'a> Search<'a> {
             sentence_parts: search_result.sentence_parts,
             sentence_index: search_result.sentence_index,
             searched_query: search_result.searched_query,
+            romaji_kana: search_result.romaji_kana,
+            corrections: search_result.corrections,
         };
         Ok(res)
     }
@@ -105,6 +117,8 @@ impl<'a> Search<'a> {
             sentence_parts,
             sentence_index: self.query.word_index,
             searched_query: native_word_res.searched_query,
+            romaji_kana: gloss_word_res.romaji_kana,
+            corrections: gloss_word_res.corrections,
         })
     }
 
@@ -124,7 +138,7 @@ impl<'a> Search<'a> {
 
         if let ParseResult::Sentence(sentence) = parse_res {
             // Don't show sentence reader for words that are in DB
-            let in_db = SearchTask::<native::Engine>::new(query_str).has_term();
+            let in_db = SearchTask::<native::Engine>::new(self.generation, query_str).has_term();
             if in_db {
                 return (query_str.to_string(), None, None);
             }
@@ -144,13 +158,13 @@ impl<'a> Search<'a> {
 
     /// Returns a `SearchTask` for the current query. This will be used to find all words for
     /// the search
-    fn native_search_task<'b>(
+    fn native_search_task(
         &self,
-        query: &'b str,
+        query: &str,
         original_query: &str,
         sentence: bool,
-    ) -> SearchTask<'b, native::Engine> {
-        let mut search_task: SearchTask<native::Engine> = SearchTask::new(&query)
+    ) -> SearchTask<'a, native::Engine> {
+        let mut search_task: SearchTask<native::Engine> = SearchTask::new(self.generation, query)
             .limit(self.query.settings.page_size as usize)
             .offset(self.query.page_offset)
             .threshold(0.04f32);
@@ -174,7 +188,7 @@ impl<'a> Search<'a> {
         query_str: &str,
     ) -> Result<
         (
-            SearchResult<&'static Word>,
+            SearchResult<Cow<'a, Word>>,
             Option<InflectionInformation>,
             Option<sentence_reader::Sentence>,
             String,
@@ -191,7 +205,7 @@ impl<'a> Search<'a> {
             if let Some(regex_query) = self.query.as_regex_query() {
                 let limit = self.query.settings.page_size;
                 let offset = self.query.page_offset;
-                let res = regex::search(regex_query, limit, offset)?;
+                let res = regex::search(self.generation, regex_query, limit, offset)?;
                 if !res.is_empty() {
                     return Ok((res, None, None, query_str.to_string()));
                 }
@@ -218,13 +232,18 @@ impl<'a> Search<'a> {
             search_task.add_query(&self.query.query);
         }
 
+        // Search for all alternatives of an OR expression
+        for alternative in self.query.alternative_queries() {
+            search_task.add_query(alternative);
+        }
+
         let res = search_task.find()?;
 
         // Put furigana to sentence
         if let Some(sentence) = &mut sentence {
             for part in sentence.iter_mut() {
                 let p = part.clone();
-                part.set_furigana(|inp| furigana_by_reading(inp, &p))
+                part.set_furigana(|inp| furigana_by_reading(self.generation, inp, &p))
             }
         }
 
@@ -252,7 +271,7 @@ impl<'a> Search<'a> {
 
         let count = res.len();
 
-        let mut wordresults = res.item_iter().cloned().collect::<Vec<_>>();
+        let mut wordresults = res.item_iter().map(Cow::into_owned).collect::<Vec<_>>();
 
         filter_languages(
             wordresults.iter_mut(),
@@ -273,11 +292,11 @@ impl<'a> Search<'a> {
 
     /// Returns a `SearchTask` for the current query. This will be used to find all words for
     /// the search
-    fn gloss_search_task(&self) -> SearchTask<foreign::Engine> {
+    fn gloss_search_task(&self) -> SearchTask<'a, foreign::Engine> {
         let used_lang = self.query.get_lang_with_override();
 
         let mut search_task: SearchTask<foreign::Engine> =
-            SearchTask::with_language(&self.query.query, used_lang)
+            SearchTask::with_language(self.generation, &self.query.query, used_lang)
                 .limit(self.query.settings.page_size as usize)
                 .offset(self.query.page_offset)
                 .threshold(0.3f32);
@@ -291,6 +310,11 @@ impl<'a> Search<'a> {
             search_task.add_language_query(&self.query.query, Language::English);
         }
 
+        // Search for all alternatives of an OR expression
+        for alternative in self.query.alternative_queries() {
+            search_task.add_language_query(alternative, used_lang);
+        }
+
         // Set user defined filter
         let pos_filter = to_option(self.query.get_part_of_speech_tags().copied().collect());
         let q_cloned = self.query.clone();
@@ -313,6 +337,17 @@ impl<'a> Search<'a> {
             return Ok(ResultData::default());
         }
 
+        // Try regex search
+        if let Some(regex_query) = self.query.as_foreign_regex_query() {
+            // prevent heavy queries
+            if regex_query.get_chars().len() >= 2 {
+                let res = self.gloss_regex_search(&regex_query);
+                if !res.is_empty() {
+                    return Ok(self.gloss_regex_results(res));
+                }
+            }
+        }
+
         let mut search_task = self.gloss_search_task();
 
         let could_be_romaji = japanese::guessing::could_be_romaji(&self.query.query);
@@ -323,36 +358,55 @@ impl<'a> Search<'a> {
 
         // Do the search
         let mut res = search_task.find()?;
-        let count = res.len();
 
         let mut infl_info = None;
         let mut sentence = None;
         let mut searched_query = self.query.query.clone();
-        if !self.query.use_original
-            && count < 50
-            && could_be_romaji
-            && !SearchTask::<foreign::Engine>::with_language(
-                &self.query.query,
-                self.query.get_lang_with_override(),
-            )
-            .has_term()
-        {
-            let hg_query = utils::format_romaji_nn(&self.query.query).to_hiragana();
-            let hg_search = self.native_search(&hg_query);
-            if let Ok((native_res, inflection_info, sent, sq)) = hg_search {
-                infl_info = inflection_info;
-                sentence = sent;
-                searched_query = sq;
-                res.merge(native_res);
+        let mut romaji_kana = None;
+
+        // Search romanized japanese queries in the native index too, unless the query is a term
+        // of the searched language like "house" or "sake"
+        if let Some(kana_query) = &self.query.romaji_kana {
+            if !self.query.use_original
+                && res.len() < 50
+                && !SearchTask::<foreign::Engine>::with_language(
+                    self.generation,
+                    &self.query.query,
+                    self.query.get_lang_with_override(),
+                )
+                .has_term()
+            {
+                if let Ok((native_res, inflection_info, sent, sq)) = self.native_search(kana_query)
+                {
+                    if !native_res.is_empty() {
+                        infl_info = inflection_info;
+                        sentence = sent;
+                        searched_query = sq;
+                        romaji_kana = Some(kana_query.clone());
+                        res.merge(native_res);
+                    }
+                }
             }
         }
 
+        let count = res.len();
+
         // If there aren't any results, check if there is another language
         if res.len() == 0 {
-            return self.check_other_lang();
+            let mut other_lang = self.check_other_lang()?;
+            if other_lang.words.is_empty() && other_lang.corrections.is_empty() {
+                other_lang.corrections = self.corrections();
+            }
+            return Ok(other_lang);
         }
 
-        let mut wordresults = res.item_iter().cloned().collect::<Vec<_>>();
+        let corrections = if count < CORRECTION_RESULT_THRESHOLD {
+            self.corrections()
+        } else {
+            vec![]
+        };
+
+        let mut wordresults = res.item_iter().map(Cow::into_owned).collect::<Vec<_>>();
 
         filter_languages(
             wordresults.iter_mut(),
@@ -367,20 +421,73 @@ impl<'a> Search<'a> {
             sentence_parts: sentence,
             sentence_index: self.query.word_index,
             searched_query,
-            ..Default::default()
+            romaji_kana,
+            corrections,
         })
     }
 
+    /// Searches for words with a gloss matching the wildcard query `regex_query`
+    fn gloss_regex_search(&self, regex_query: &RegexSQuery) -> SearchResult<Cow<'a, Word>> {
+        let pos_filter = to_option(self.query.get_part_of_speech_tags().copied().collect());
+
+        regex::search_gloss(
+            self.generation,
+            regex_query,
+            self.query.get_lang_with_override(),
+            self.query.settings.show_english,
+            |word| Self::word_filter(self.query, word, &pos_filter),
+            self.query.settings.page_size,
+            self.query.page_offset,
+        )
+    }
+
+    /// Converts the results of a gloss regex search into `ResultData`
+    fn gloss_regex_results(&self, res: SearchResult<Cow<Word>>) -> ResultData {
+        let count = res.len();
+        let mut wordresults = res.item_iter().map(Cow::into_owned).collect::<Vec<_>>();
+
+        filter_languages(
+            wordresults.iter_mut(),
+            self.query.get_lang_with_override(),
+            self.query.settings.show_english,
+        );
+
+        ResultData {
+            count,
+            words: wordresults,
+            searched_query: self.query.query.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns spelling corrections of the query in the language it has been searched in
+    fn corrections(&self) -> Vec<String> {
+        if self.query.use_original {
+            return vec![];
+        }
+
+        correction::find(
+            self.generation,
+            &self.query.query,
+            self.query.get_lang_with_override(),
+        )
+    }
+
     fn check_other_lang(&self) -> Result<ResultData, Error> {
-        let guessed_langs = engine::words::foreign::guess_language(&self.query.query)
-            .into_iter()
-            .filter(|i| *i != self.query.get_lang_with_override())
-            .collect::<Vec<_>>();
+        let guessed_langs =
+            engine::words::foreign::guess_language(self.generation, &self.query.query)
+                .into_iter()
+                .filter(|i| *i != self.query.get_lang_with_override())
+                .collect::<Vec<_>>();
 
         if guessed_langs.len() == 1 {
             let mut new_query = self.query.clone();
             new_query.language_override = Some(guessed_langs[0]);
-            return Search { query: &new_query }.gloss_results();
+            let search = Search {
+                query: &new_query,
+                generation: self.generation,
+            };
+            return search.gloss_results();
         }
 
         Ok(ResultData::default())
@@ -427,15 +534,92 @@ impl<'a> Search<'a> {
             }
         }
 
+        // Apply field, dialect and loanword filter
+        for tag in query.get_sense_tags() {
+            let matches = match tag {
+                Tag::Field(field) => word.has_field(*field),
+                Tag::Dialect(dialect) => word.has_dialect(*dialect),
+                Tag::Loan(language) => word.is_loan_from(*language),
+                _ => true,
+            };
+
+            if !matches {
+                return false;
+            }
+        }
+
+        // Apply boolean and phrase operators
+        if let Some(expression) = &query.expression {
+            if !expression.matches(&|term| Self::word_matches_term(query, word, term)) {
+                return false;
+            }
+        }
+
         true
     }
+
+    /// Returns `true` if `term` of a query expression can be found in `word`
+    fn word_matches_term(query: &Query, word: &Word, term: &Term) -> bool {
+        let in_reading = || {
+            let term_kana = term.text.to_hiragana();
+            word.reading_iter(true)
+                .filter(|i| !i.kanji)
+                .any(|i| i.reading.to_hiragana().contains(&term_kana))
+        };
+
+        let in_kanji = || {
+            word.reading_iter(false)
+                .filter(|i| i.kanji)
+                .any(|i| term.found_in(&i.reading))
+        };
+
+        let in_gloss = || {
+            let lang = query.get_lang_with_override();
+            let show_english = query.settings.show_english;
+            word.senses
+                .iter()
+                .filter(|i| i.language == lang || (show_english && i.language == Language::English))
+                .flat_map(|i| i.glosses.iter())
+                .any(|i| term.found_in(&i.gloss))
+        };
+
+        match term.field {
+            Field::Reading => in_reading(),
+            Field::Kanji => in_kanji(),
+            Field::Gloss => in_gloss(),
+            Field::Any if term.text.has_japanese() => in_reading() || in_kanji(),
+            Field::Any => in_gloss(),
+        }
+    }
+}
+
+/// Returns the dictionary entry which fits the sentence part `part` best
+pub fn find_by_part<'a>(
+    generation: &'a Generation,
+    part: &sentence_reader::Part,
+) -> Option<Cow<'a, Word>> {
+    best_match_for_part(generation, &part.get_normalized(), part)
 }
 
 /// Returns furigana of the given `morpheme` if available
-fn furigana_by_reading(morpheme: &str, part: &sentence_reader::Part) -> Option<String> {
-    let word_storage = resources::get().words();
+pub fn furigana_by_reading(
+    generation: &Generation,
+    morpheme: &str,
+    part: &sentence_reader::Part,
+) -> Option<String> {
+    best_match_for_part(generation, morpheme, part)?
+        .furigana
+        .as_ref()
+        .cloned()
+}
 
-    let mut st = SearchTask::<native::Engine>::new(morpheme)
+/// Searches for `morpheme` and returns the word which fits best to `part`
+fn best_match_for_part<'a>(
+    generation: &'a Generation,
+    morpheme: &str,
+    part: &sentence_reader::Part,
+) -> Option<Cow<'a, Word>> {
+    let mut st = SearchTask::<native::Engine>::new(generation, morpheme)
         .threshold(0.7)
         .limit(10);
 
@@ -463,12 +647,7 @@ fn furigana_by_reading(morpheme: &str, part: &sentence_reader::Part) -> Option<S
         score
     });
 
-    let found = st.find().ok()?;
-    if found.is_empty() {
-        return None;
-    }
-    let word = word_storage.by_sequence(found[0].item.sequence as u32)?;
-    word.furigana.as_ref().cloned()
+    st.find().ok()?.item_iter().next()
 }
 
 pub fn wc_to_simple_pos(wc: &WordClass) -> Option<PosSimple> {
@@ -487,16 +666,16 @@ pub fn wc_to_simple_pos(wc: &WordClass) -> Option<PosSimple> {
     })
 }
 
-pub fn guess_inp_language(query: &Query) -> Vec<Language> {
-    engine::words::foreign::guess_language(&query.query)
+pub fn guess_inp_language(generation: &Generation, query: &Query) -> Vec<Language> {
+    engine::words::foreign::guess_language(generation, &query.query)
         .into_iter()
         .filter(|i| *i != query.get_lang_with_override())
         .collect()
 }
 
 /// Guesses the amount of results a search would return with given `query`
-pub fn guess_result(query: &Query) -> Option<Guess> {
-    let search = Search { query };
+pub fn guess_result(generation: &Generation, query: &Query) -> Option<Guess> {
+    let search = Search { query, generation };
 
     if query.language == QueryLang::Japanese {
         guess_native(search)
@@ -40,6 +40,24 @@ pub fn regex_order(word: &Word, found_in: &str, _query: &RegexSQuery) -> usize {
     score
 }
 
+/// Order for regex-search results found by a words gloss
+pub fn gloss_regex_order(word: &Word, found_in: &str, _query: &RegexSQuery) -> usize {
+    let mut score: usize = 100;
+
+    if word.is_common() {
+        score += 30;
+    }
+
+    if let Some(jlpt) = word.get_jlpt_lvl() {
+        score += 10 + (jlpt * 2) as usize;
+    }
+
+    // Show words with shorter matching glosses more on top
+    score = score.saturating_sub(real_string_len(found_in));
+
+    score
+}
+
 /// Search order for words searched by japanese meaning/kanji/reading
 pub fn japanese_search_order(
     word: &Word,
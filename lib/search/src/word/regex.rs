@@ -1,22 +1,75 @@
+use std::{borrow::Cow, collections::HashSet};
+
 use error::Error;
-use types::jotoba::words::Word;
+use types::jotoba::{languages::Language, words::Word};
 
-use crate::engine::words::native::regex as regex_engine;
-use crate::{engine::result::SearchResult, regex_query::RegexSQuery};
+use crate::engine::words::{foreign::regex as gloss_regex_engine, native::regex as regex_engine};
+use crate::{engine::result::SearchResult, generation::Generation, regex_query::RegexSQuery};
 
-use super::order::regex_order;
+use super::order::{gloss_regex_order, regex_order};
 
 /// Searches the given `query` using regex search
-pub fn search(
+pub fn search<'a>(
+    generation: &'a Generation,
     query: RegexSQuery,
     limit: u32,
     offset: usize,
-) -> Result<SearchResult<&'static Word>, Error> {
-    let mut words = regex_engine::search(&query)?
+) -> Result<SearchResult<Cow<'a, Word>>, Error> {
+    let words = regex_engine::search(generation, &query)?
         .into_iter()
-        .map(|(word, src)| (word, regex_order(word, src, &query)))
+        .map(|(word, src)| {
+            let score = regex_order(&word, src, &query);
+            (word, score)
+        })
         .collect::<Vec<_>>();
 
+    Ok(select_page(words, limit, offset))
+}
+
+/// Searches for words having a gloss in `language` which matches `query`. English glosses are
+/// searched as well if `show_english` is `true`. Only words for which `filter` returns `true`
+/// are returned
+pub fn search_gloss<'a, F: Fn(&Word) -> bool>(
+    generation: &'a Generation,
+    query: &RegexSQuery,
+    language: Language,
+    show_english: bool,
+    filter: F,
+    limit: u32,
+    offset: usize,
+) -> SearchResult<Cow<'a, Word>> {
+    let mut found = gloss_regex_engine::search(generation, query, language);
+    if show_english && language != Language::English {
+        found.extend(gloss_regex_engine::search(
+            generation,
+            query,
+            Language::English,
+        ));
+    }
+
+    let mut words = found
+        .into_iter()
+        .filter(|(word, _)| filter(word))
+        .map(|(word, src)| {
+            let score = gloss_regex_order(&word, src, query);
+            (word, score)
+        })
+        .collect::<Vec<_>>();
+
+    // Words found in multiple languages should only be shown once with their best score
+    words.sort_by(|a, b| a.1.cmp(&b.1).reverse());
+    let mut seen = HashSet::with_capacity(words.len());
+    words.retain(|(word, _)| seen.insert(word.sequence));
+
+    select_page(words, limit, offset)
+}
+
+/// Orders `words` by their score and selects the ones to display
+fn select_page(
+    mut words: Vec<(Cow<Word>, usize)>,
+    limit: u32,
+    offset: usize,
+) -> SearchResult<Cow<Word>> {
     let len = words.len();
 
     // Already sort them here so we can take only those to display
@@ -29,5 +82,5 @@ pub fn search(
         .take(limit as usize)
         .collect::<Vec<_>>();
 
-    Ok(SearchResult::from_items_ordered(words, len))
+    SearchResult::from_items_ordered(words, len)
 }
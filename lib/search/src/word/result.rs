@@ -10,6 +10,10 @@ pub struct WordResult {
     pub sentence_parts: Option<sentence_reader::Sentence>,
     pub sentence_index: usize,
     pub searched_query: String,
+    /// Kana of a romanized japanese query which has been searched for too
+    pub romaji_kana: Option<String>,
+    /// Similar terms to the query if it found nothing or only a few results
+    pub corrections: Vec<String>,
 }
 
 impl WordResult {
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use error::Error;
 use types::jotoba::words::filter_languages;
 use utils::to_option;
@@ -17,17 +19,19 @@ pub(super) fn search(search: &Search<'_>) -> Result<ResultData, Error> {
     match filter_tag.unwrap() {
         Tag::Jlpt(jlpt) => return jlpt_search(search, *jlpt),
         Tag::IrregularIruEru => return irreg_godan(search),
+        Tag::Field(..) | Tag::Dialect(..) | Tag::Loan(..) => return sense_tag_search(search),
         _ => return Ok(ResultData::default()),
     }
 }
 
 fn irreg_godan(search: &Search<'_>) -> Result<ResultData, Error> {
-    let mut words = resources::get()
-        .words()
+    let word_storage = search.generation.resources().words();
+
+    let mut words = word_storage
         .irregular_ichidan()
         .skip(search.query.page_offset)
         .take(search.query.settings.page_size as usize)
-        .cloned()
+        .map(Cow::into_owned)
         .collect::<Vec<_>>();
 
     filter_languages(
@@ -36,7 +40,7 @@ fn irreg_godan(search: &Search<'_>) -> Result<ResultData, Error> {
         search.query.settings.show_english,
     );
 
-    let count = resources::get().words().irregular_ichidan_len();
+    let count = word_storage.irregular_ichidan_len();
 
     Ok(ResultData {
         count,
@@ -50,12 +54,12 @@ fn jlpt_search(search: &Search<'_>, jlpt: u8) -> Result<ResultData, Error> {
 
     let pos_filter = to_option(search.query.get_part_of_speech_tags().copied().collect());
 
-    let resources = resources::get();
-
-    let mut wordresults = resources
+    let mut wordresults = search
+        .generation
+        .resources()
         .word_jlpt(jlpt)
         .filter(|word| Search::word_filter(&search.query, word, &pos_filter))
-        .cloned()
+        .map(Cow::into_owned)
         .collect::<Vec<_>>();
 
     filter_languages(
@@ -80,3 +84,57 @@ fn jlpt_search(search: &Search<'_>, jlpt: u8) -> Result<ResultData, Error> {
         ..Default::default()
     })
 }
+
+/// Search for all words matching the field, dialect and loanword tags of the query. Only the words
+/// of the tag with the fewest words get loaded, all tags get applied in `Search::word_filter`
+fn sense_tag_search(search: &Search<'_>) -> Result<ResultData, Error> {
+    let pos_filter = to_option(search.query.get_part_of_speech_tags().copied().collect());
+
+    let word_storage = search.generation.resources().words();
+
+    let seq_ids = search
+        .query
+        .get_sense_tags()
+        .map(|tag| match tag {
+            Tag::Field(field) => word_storage.by_field(*field),
+            Tag::Dialect(dialect) => word_storage.by_dialect(*dialect),
+            Tag::Loan(language) => word_storage.by_loan_language(*language),
+            _ => &[],
+        })
+        .min_by_key(|i| i.len())
+        .unwrap_or_default();
+
+    let mut wordresults = seq_ids
+        .iter()
+        .filter_map(|seq_id| word_storage.by_sequence(*seq_id))
+        .filter(|word| Search::word_filter(&search.query, word, &pos_filter))
+        .map(Cow::into_owned)
+        .collect::<Vec<_>>();
+
+    filter_languages(
+        wordresults.iter_mut(),
+        search.query.settings.user_lang,
+        search.query.settings.show_english,
+    );
+
+    // Show common words first
+    wordresults.sort_by(|a, b| {
+        b.is_common()
+            .cmp(&a.is_common())
+            .then_with(|| a.get_reading().reading.cmp(&b.get_reading().reading))
+    });
+
+    let count = wordresults.len();
+
+    let wordresults = wordresults
+        .into_iter()
+        .skip(search.query.page_offset)
+        .take(search.query.settings.page_size as usize)
+        .collect();
+
+    Ok(ResultData {
+        count,
+        words: wordresults,
+        ..Default::default()
+    })
+}
@@ -5,6 +5,7 @@ use self::{
     rule_set::RuleSet,
 };
 
+pub mod parse;
 pub mod rule;
 pub mod rule_set;
 
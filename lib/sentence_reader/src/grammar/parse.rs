@@ -0,0 +1,171 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{rule::Rule, rule_set::RuleSet};
+
+/// Errors which can occur while loading grammar rules
+#[derive(Debug)]
+pub enum Error {
+    /// Invalid syntax in the given line
+    Syntax(usize),
+    /// Use of an undefined variable in the given line
+    UnknownVariable(usize, String),
+    /// A rule defined a second time in the given line
+    DuplicateRule(usize, String),
+    /// Destination rules which are used but never defined, paired with the rules using them
+    Unreachable(Vec<(String, String)>),
+    /// Rules are already in use and can't be replaced anymore
+    AlreadyLoaded,
+    Io(std::io::Error),
+}
+
+/// Parses a set of rules from `src` and checks it for completeness. Each line contains either a
+/// rule, a variable or a comment:
+///
+/// ```text
+/// # Comment
+/// $endings = た | ない
+/// V -> $endings | ます
+/// た ->
+/// ```
+///
+/// A rule consists of its name and all rules that are allowed to follow it, separated by `|`.
+/// Variables hold a list of rule names and get expanded when used within a rule.
+pub fn parse(src: &str) -> Result<RuleSet, Error> {
+    let mut variables: HashMap<&str, Vec<String>> = HashMap::new();
+    let mut rule_set = RuleSet::new(vec![]);
+
+    for (line_nr, line) in src.lines().enumerate() {
+        let line_nr = line_nr + 1;
+
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(variable) = line.strip_prefix('$') {
+            let (name, values) = variable.split_once('=').ok_or(Error::Syntax(line_nr))?;
+            let name = parse_name(name, line_nr)?;
+            let values = parse_list(values, &variables, line_nr)?;
+            variables.insert(name, values);
+            continue;
+        }
+
+        let (name, rhs) = line.split_once("->").ok_or(Error::Syntax(line_nr))?;
+        let name = parse_name(name, line_nr)?;
+        let rhs = parse_list(rhs, &variables, line_nr)?;
+
+        if !rule_set.add(Rule::new(name, rhs)) {
+            return Err(Error::DuplicateRule(line_nr, name.to_string()));
+        }
+    }
+
+    rule_set.check()?;
+    Ok(rule_set)
+}
+
+/// Parses a single rule or variable name
+fn parse_name(name: &str, line_nr: usize) -> Result<&str, Error> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) || name.contains('|') {
+        return Err(Error::Syntax(line_nr));
+    }
+    Ok(name)
+}
+
+/// Parses a `|` separated list of rule names and expands all variables in it
+fn parse_list(
+    list: &str,
+    variables: &HashMap<&str, Vec<String>>,
+    line_nr: usize,
+) -> Result<Vec<String>, Error> {
+    if list.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut out = vec![];
+
+    for item in list.split('|') {
+        let item = parse_name(item, line_nr)?;
+
+        match item.strip_prefix('$') {
+            Some(variable) => {
+                let values = variables
+                    .get(variable)
+                    .ok_or_else(|| Error::UnknownVariable(line_nr, variable.to_string()))?;
+                out.extend(values.iter().cloned());
+            }
+            None => out.push(item.to_string()),
+        }
+    }
+
+    Ok(out)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Syntax(line) => write!(f, "invalid syntax in line {line}"),
+            Error::UnknownVariable(line, name) => {
+                write!(f, "unknown variable ${name} in line {line}")
+            }
+            Error::DuplicateRule(line, name) => {
+                write!(f, "rule {name} defined again in line {line}")
+            }
+            Error::Unreachable(rules) => {
+                write!(f, "unreachable destination rules:")?;
+                for (rule, dst) in rules {
+                    write!(f, " {rule} -> {dst};")?;
+                }
+                Ok(())
+            }
+            Error::AlreadyLoaded => write!(f, "grammar rules are already in use"),
+            Error::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let src = "
+            # Comment
+            $end = た | ない # trailing comment
+            V -> $end | ます
+            ます -> た
+            た ->
+            ない -> $end
+        ";
+
+        let rules = parse(src).unwrap();
+        assert_eq!(rules.get_rule("V").unwrap().rhs(), &["た", "ない", "ます"]);
+        assert_eq!(rules.get_rule("ない").unwrap().rhs(), &["た", "ない"]);
+        assert!(rules.get_rule("た").unwrap().rhs().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse("V ます"), Err(Error::Syntax(1))));
+        assert!(matches!(parse("V -> た ||"), Err(Error::Syntax(1))));
+        assert!(matches!(parse("\nV -> $x"), Err(Error::UnknownVariable(2, _))));
+        assert!(matches!(parse("た ->\nた ->"), Err(Error::DuplicateRule(2, _))));
+
+        match parse("V -> た | ない\nた ->") {
+            Err(Error::Unreachable(rules)) => {
+                assert_eq!(rules, vec![("V".to_string(), "ない".to_string())])
+            }
+            _ => panic!("expected unreachable rules"),
+        }
+    }
+}
@@ -1,21 +1,31 @@
-use super::rule::Rule;
+use super::{parse::Error, rule::Rule};
 use std::{collections::HashMap, fmt::Debug};
 
 pub const ALL_WILDCARD: &str = "*";
 
 #[derive(Clone)]
 pub struct RuleSet {
-    rules: HashMap<&'static str, Rule>,
+    rules: HashMap<String, Rule>,
 }
 
 impl RuleSet {
     /// Creates a new set of rules
-    pub fn new(rules: &[Rule]) -> Self {
-        let rules = rules
-            .iter()
-            .map(|i| (i.name(), *i))
-            .collect::<HashMap<_, _>>();
-        Self { rules }
+    pub fn new(rules: Vec<Rule>) -> Self {
+        let mut rule_set = Self {
+            rules: HashMap::with_capacity(rules.len()),
+        };
+
+        for rule in rules {
+            rule_set.add(rule);
+        }
+
+        rule_set
+    }
+
+    /// Parses a set of rules from `src`. See `parse::parse` for the format
+    #[inline]
+    pub fn parse(src: &str) -> Result<Self, Error> {
+        super::parse::parse(src)
     }
 
     /// Adds a Rule to the RuleSet
@@ -29,7 +39,7 @@ impl RuleSet {
             self.add_all_wildcard();
         }
 
-        self.rules.insert(rule.name(), rule);
+        self.rules.insert(rule.name().to_string(), rule);
         true
     }
 
@@ -38,21 +48,26 @@ impl RuleSet {
         self.rules.contains_key(name)
     }
 
-    /// Returns `true` if the RuleSet is complete
-    pub fn check(&self) -> bool {
-        // check that all used dst rules are reachable
-        for (_, rule) in self.rules.iter() {
-            for rhs in rule.rhs() {
-                if *rhs == ALL_WILDCARD {
-                    continue;
-                }
-                if !self.rules.contains_key(rhs) {
-                    return false;
-                }
-            }
+    /// Checks whether the RuleSet is complete. Returns `Error::Unreachable` with all used dst
+    /// rules which aren't defined
+    pub fn check(&self) -> Result<(), Error> {
+        let mut unreachable = self
+            .rules
+            .values()
+            .flat_map(|rule| {
+                rule.rhs()
+                    .iter()
+                    .filter(|rhs| *rhs != ALL_WILDCARD && !self.has_rule(rhs))
+                    .map(move |rhs| (rule.name().to_string(), rhs.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        if unreachable.is_empty() {
+            return Ok(());
         }
 
-        true
+        unreachable.sort();
+        Err(Error::Unreachable(unreachable))
     }
 
     /// Returns a rule with `name` or None when no such rule exists in RuleSet
@@ -67,21 +82,17 @@ impl RuleSet {
         }
 
         // add dummy rule that allows any production
-        self.rules
-            .insert(ALL_WILDCARD, Rule::new(ALL_WILDCARD, &[]));
+        self.rules.insert(
+            ALL_WILDCARD.to_string(),
+            Rule::new(ALL_WILDCARD, vec![]),
+        );
     }
 }
 
 impl Debug for RuleSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (name, rule) in &self.rules {
-            let mut dst = String::new();
-            for (pos, d) in rule.rhs().iter().enumerate() {
-                if pos > 0 {
-                    dst.push_str(" | ");
-                }
-                dst.push_str(*d);
-            }
+            let dst = rule.rhs().join(" | ");
             if dst.is_empty() {
                 continue;
             }
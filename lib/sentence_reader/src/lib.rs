@@ -2,11 +2,17 @@ mod analyzer;
 mod grammar;
 pub mod output;
 mod sentence;
+#[cfg(test)]
+mod test;
 
+use std::{fs, path::Path};
+
+use grammar::rule_set::RuleSet;
 use once_cell::sync::Lazy;
 use output::ParseResult;
 use sentence::SentenceAnalyzer;
 
+pub use grammar::parse::Error as GrammarError;
 pub use igo_unidic;
 
 pub use output::Sentence;
@@ -20,6 +26,25 @@ pub const NL_PARSER_PATH: &str = "./unidic-mecab";
 pub static JA_NL_PARSER: Lazy<igo_unidic::Parser> =
     Lazy::new(|| igo_unidic::Parser::new(NL_PARSER_PATH).unwrap());
 
+/// Name of the file containing the rules to merge morphemes into words
+pub const SENTENCE_RULES_FILE: &str = "sentence.rules";
+
+/// Name of the file containing the rules to group inflections
+pub const INFLECTION_RULES_FILE: &str = "inflection.rules";
+
+/// Loads the grammar rules from the `SENTENCE_RULES_FILE` and `INFLECTION_RULES_FILE` within
+/// `dir` and uses them instead of the built-in ones. This has to be called before any text gets
+/// parsed
+pub fn load_grammar<P: AsRef<Path>>(dir: P) -> Result<(), GrammarError> {
+    let dir = dir.as_ref();
+
+    let sentence_rules = RuleSet::parse(&fs::read_to_string(dir.join(SENTENCE_RULES_FILE))?)?;
+    let inflection_rules = RuleSet::parse(&fs::read_to_string(dir.join(INFLECTION_RULES_FILE))?)?;
+
+    analyzer::set_rules(sentence_rules)?;
+    sentence::inflection::set_rules(inflection_rules)
+}
+
 /// Parser for sentence
 pub struct Parser<'input> {
     sentence_analyzer: SentenceAnalyzer<'input>,
@@ -27,11 +52,15 @@ pub struct Parser<'input> {
 
 impl<'input> Parser<'input> {
     /// Creates a new InputTextParser
+    #[inline]
     pub fn new(original: &'input str) -> Self {
-        let sentence_analyzer = SentenceAnalyzer::new(
-            analyzer::get_grammar_analyzer(),
-            JA_NL_PARSER.parse(original),
-        );
+        Self::with_nl_parser(original, &JA_NL_PARSER)
+    }
+
+    /// Creates a new InputTextParser which uses `nl_parser` instead of the global `JA_NL_PARSER`
+    pub fn with_nl_parser(original: &'input str, nl_parser: &'static igo_unidic::Parser) -> Self {
+        let sentence_analyzer =
+            SentenceAnalyzer::new(analyzer::get_grammar_analyzer(), nl_parser.parse(original));
 
         Self { sentence_analyzer }
     }
@@ -53,4 +82,9 @@ impl<'input> Parser<'input> {
         let sentence = Sentence::new(sent_parse);
         ParseResult::Sentence(sentence)
     }
+
+    /// Returns all parts of the input without interpreting them as sentence or single word
+    pub fn parts(&self) -> Vec<Part> {
+        self.sentence_analyzer.analyze::<Part>()
+    }
 }
@@ -1,8 +1,8 @@
-use super::FromMorphemes;
-use crate::grammar::{rule::Rule, rule_set::RuleSet, Analyzer};
+use super::{aux_lexeme, FromMorphemes};
+use crate::grammar::{parse::Error, rule_set::RuleSet, Analyzer};
 use crate::sentence::SentenceAnalyzer;
 use igo_unidic::Morpheme;
-use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Inflection {
@@ -22,12 +22,29 @@ pub enum Inflection {
     TeAru,
     TeMiru,
     Tara,
+    TeOku,
+    TeKuru,
+    TeIku,
+    TeAgeru,
+    TeKureru,
+    TeMorau,
+    /// ~なければならない
+    Must,
+    /// ~ば conditional
+    Ba,
+    /// ~そうだ
+    Sou,
+    Rashii,
+    Volitional,
+    /// ~ようとする
+    YouToSuru,
+    Sugiru,
 }
 
 impl<'b> FromMorphemes<'static, 'b> for Inflection {
     /// Parses an inflection from given morpheme(s)
     fn from(parts: Vec<igo_unidic::Morpheme<'static, 'b>>, _pos: usize) -> Option<Self> {
-        let lexemes = parts.iter().map(|i| i.lexeme).collect::<Vec<_>>();
+        let lexemes = parts.iter().map(|i| aux_lexeme(i.lexeme)).collect::<Vec<_>>();
 
         if lexemes.is_empty() {
             None
@@ -47,6 +64,14 @@ impl<'b> FromMorphemes<'static, 'b> for Inflection {
                 "たい" => Inflection::Tai,
                 "てる" => Inflection::TeIru,
                 //"てる" => Inflection::TeIru,
+                "とく" | "どく" => Inflection::TeOku,
+                // Contracted ておく like in 食べとく
+                "おく" if parts[0].surface.starts_with(['と', 'ど']) => Inflection::TeOku,
+                "ば" => Inflection::Ba,
+                "そう" => Inflection::Sou,
+                "らしい" => Inflection::Rashii,
+                "う" | "よう" => Inflection::Volitional,
+                "すぎる" => Inflection::Sugiru,
                 _ => return None,
             })
         } else {
@@ -54,9 +79,18 @@ impl<'b> FromMorphemes<'static, 'b> for Inflection {
                 &["て", "いる"] => Inflection::TeIru,
                 &["て", "ある"] => Inflection::TeAru,
                 &["て", "みる"] => Inflection::TeMiru,
+                &["て", "おく"] => Inflection::TeOku,
+                &["て", "くる"] => Inflection::TeKuru,
+                &["て", "いく"] => Inflection::TeIku,
+                &["て", "あげる"] => Inflection::TeAgeru,
+                &["て", "くれる"] => Inflection::TeKureru,
+                &["て", "もらう"] => Inflection::TeMorau,
                 &["さ", "せる"] => Inflection::Causative,
                 // Fake する; The tokenizer tokenizes the さ of される as a form of する
                 &["する", "れる"] => Inflection::CausativePassive,
+                &["ば", "なる", "ない"] => Inflection::Must,
+                &["そう", "だ"] | &["そう", "です"] => Inflection::Sou,
+                &["う", "と", "する"] | &["よう", "と", "する"] => Inflection::YouToSuru,
                 _ => return None,
             })
         }
@@ -64,24 +98,39 @@ impl<'b> FromMorphemes<'static, 'b> for Inflection {
 }
 
 pub(crate) fn parse_inflections(morph: &[Morpheme<'static, '_>]) -> Vec<Inflection> {
-    SentenceAnalyzer::new(&INFLECTION_RULES, morph.to_vec()).analyze::<Inflection>()
-}
+    let mut inflections =
+        SentenceAnalyzer::new(get_analyzer(), morph.to_vec()).analyze::<Inflection>();
 
-static INFLECTION_RULES: Lazy<Analyzer> = Lazy::new(|| Analyzer::new(get_rules()));
+    // The ない of なければならない is part of `Must` already
+    while let Some(pos) = inflections
+        .windows(2)
+        .position(|i| i == [Inflection::Negative, Inflection::Must])
+    {
+        inflections.remove(pos);
+    }
 
-/// Returns a set of rules for japanese text analyzing
-fn get_rules() -> RuleSet {
-    let mut rules = Vec::with_capacity(6);
+    inflections
+}
 
-    rules.push(Rule::new("いる", &[]));
-    rules.push(Rule::new("ある", &[]));
-    rules.push(Rule::new("てみる", &[]));
-    rules.push(Rule::new("れる", &[]));
+/// Built-in rules for grouping inflections
+const INFLECTION_RULES_SRC: &str = include_str!("../../grammar/inflection.rules");
 
-    rules.push(Rule::new("て", &["いる", "ある", "てみる"]));
-    rules.push(Rule::new("さ", &["れる"]));
+static INFLECTION_RULES: OnceCell<Analyzer> = OnceCell::new();
+
+/// Returns the analyzer used to group inflections. Uses the built-in rules if no other rules
+/// were set before
+fn get_analyzer() -> &'static Analyzer {
+    INFLECTION_RULES.get_or_init(|| {
+        let rules = RuleSet::parse(INFLECTION_RULES_SRC).expect("Invalid built-in inflection rules");
+        Analyzer::new(rules)
+    })
+}
 
-    RuleSet::new(&rules)
+/// Sets the rules used to group inflections. Fails if rules are already in use
+pub(crate) fn set_rules(rules: RuleSet) -> Result<(), Error> {
+    INFLECTION_RULES
+        .set(Analyzer::new(rules))
+        .map_err(|_| Error::AlreadyLoaded)
 }
 
 #[cfg(feature = "jotoba_intern")]
@@ -104,6 +153,19 @@ impl localization::traits::Translatable for Inflection {
             Inflection::TeAru => "TeAru",
             Inflection::TeMiru => "TeMiru",
             Inflection::Tara=> "Tara",
+            Inflection::TeOku => "TeOku",
+            Inflection::TeKuru => "TeKuru",
+            Inflection::TeIku => "TeIku",
+            Inflection::TeAgeru => "TeAgeru",
+            Inflection::TeKureru => "TeKureru",
+            Inflection::TeMorau => "TeMorau",
+            Inflection::Must => "Must",
+            Inflection::Ba => "Ba",
+            Inflection::Sou => "Sou",
+            Inflection::Rashii => "Rashii",
+            Inflection::Volitional => "Volitional",
+            Inflection::YouToSuru => "YouToSuru",
+            Inflection::Sugiru => "Sugiru",
         }
     }
 
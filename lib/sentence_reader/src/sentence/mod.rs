@@ -111,11 +111,102 @@ impl<'input> SentenceAnalyzer<'input> {
     }
 }
 
+/// Returns the kana form of auxiliary verbs which might be written in kanji, so they can be
+/// compared independently of their notation
+pub(crate) fn aux_lexeme(lexeme: &str) -> &str {
+    match lexeme {
+        "置く" => "おく",
+        "来る" => "くる",
+        "行く" => "いく",
+        "上げる" => "あげる",
+        "呉れる" => "くれる",
+        "貰う" => "もらう",
+        "成る" => "なる",
+        "過ぎる" => "すぎる",
+        "為る" => "する",
+        "居る" => "いる",
+        "有る" => "ある",
+        "見る" => "みる",
+        _ => lexeme,
+    }
+}
+
+/// Maps morphemes which only have a grammatical meaning if they follow another morpheme, like
+/// auxiliary verbs of the て-form. `lexeme` has to be normalized with `aux_lexeme`
+pub(crate) fn map_aux_to_rule(
+    surface: &str,
+    lexeme: &str,
+    is_particle: bool,
+) -> Option<&'static str> {
+    // Contracted ておく (食べとく, 読んどく)
+    if lexeme == "とく"
+        || lexeme == "どく"
+        || (lexeme == "おく" && surface.starts_with(['と', 'ど']))
+    {
+        return Some("とく");
+    }
+
+    for aux in ["おく", "くる", "いく", "あげる", "くれる", "もらう", "すぎる"] {
+        if lexeme == aux {
+            return Some(aux);
+        }
+    }
+
+    // ならない of なければならない
+    if lexeme == "なる" {
+        return Some("なる");
+    }
+
+    // と and する of ようとする
+    if surface == "と" && is_particle {
+        return Some("と");
+    }
+
+    // The さ of される is handled by `map_morph_to_rule`
+    if lexeme == "する" && surface != "さ" {
+        return Some("する");
+    }
+
+    if lexeme == "だ" {
+        return Some("だ");
+    }
+
+    None
+}
+
 pub(crate) fn map_morph_to_rule(pos: usize, morph: &Morpheme<'_, '_>) -> Option<&'static str> {
     if morph.surface == "じゃ" {
         return Some("じゃ");
     }
 
+    let lexeme = aux_lexeme(morph.lexeme);
+
+    // Verbs attached to the て-form. If pos == 0, they're used as regular verbs
+    if pos > 0 {
+        let is_particle = matches!(morph.word_class, WordClass::Particle(_));
+        if let Some(rule) = map_aux_to_rule(morph.surface, lexeme, is_particle) {
+            return Some(rule);
+        }
+    }
+
+    if lexeme == "ば" && matches!(morph.word_class, WordClass::Particle(_)) {
+        return Some("ば");
+    }
+
+    if lexeme == "らしい" {
+        return Some("らしい");
+    }
+
+    // Conjecture そう; Not the adverb
+    if lexeme == "そう" && !matches!(morph.word_class, WordClass::Adverb) {
+        return Some("そう");
+    }
+
+    // Volitional. よう as noun is used for ようだ
+    if (lexeme == "う" || lexeme == "よう") && !matches!(morph.word_class, WordClass::Noun(_)) {
+        return Some("よう");
+    }
+
     if morph.lexeme == "ない" {
         return Some("ない");
     }
@@ -132,7 +223,7 @@ pub(crate) fn map_morph_to_rule(pos: usize, morph: &Morpheme<'_, '_>) -> Option<
         return Some("てる");
     }
 
-    if morph.surface == "て" {
+    if morph.surface == "て" || (morph.surface == "で" && morph.lexeme == "て") {
         return Some("て");
     }
 
@@ -0,0 +1,165 @@
+use std::{env, path::Path};
+
+use igo_unidic::Parser;
+use once_cell::sync::Lazy;
+
+use crate::{
+    analyzer::get_grammar_analyzer,
+    grammar::rule_set::RuleSet,
+    sentence::{aux_lexeme, map_aux_to_rule, part::Part, SentenceAnalyzer},
+    Inflection,
+};
+
+/// Path of the unidic dictionary relative to the crate
+const DICT_PATH: &str = "../../unidic-mecab";
+
+/// Environment variable to set a different unidic path. If set, tests requiring unidic fail
+/// instead of being skipped if the dictionary can't be found
+const DICT_PATH_VAR: &str = "JOTOBA_UNIDIC";
+
+static PARSER: Lazy<Option<Parser>> = Lazy::new(|| {
+    let path = env::var(DICT_PATH_VAR).unwrap_or_else(|_| DICT_PATH.to_string());
+    Path::new(&path)
+        .exists()
+        .then(|| Parser::new(&path).unwrap())
+});
+
+/// Real sentences with a word they contain and its expected inflections
+const CORPUS: &[(&str, &str, &[Inflection])] = &[
+    ("宿題をやっておく。", "やっておく", &[Inflection::TeOku]),
+    (
+        "ケーキを買ってくるね。",
+        "買ってくる",
+        &[Inflection::TeKuru],
+    ),
+    ("鳥が南へ飛んでいく。", "飛んでいく", &[Inflection::TeIku]),
+    (
+        "友達に本を貸してあげた。",
+        "貸してあげた",
+        &[Inflection::TeAgeru, Inflection::Past],
+    ),
+    (
+        "母がお弁当を作ってくれた。",
+        "作ってくれた",
+        &[Inflection::TeKureru, Inflection::Past],
+    ),
+    (
+        "先生に漢字を教えてもらいました。",
+        "教えてもらいました",
+        &[Inflection::TeMorau, Inflection::Polite, Inflection::Past],
+    ),
+    (
+        "明日までに行かなければならない。",
+        "行かなければならない",
+        &[Inflection::Must],
+    ),
+    (
+        "早く寝なければ遅刻する。",
+        "寝なければ",
+        &[Inflection::Negative, Inflection::Ba],
+    ),
+    ("安ければ買います。", "安ければ", &[Inflection::Ba]),
+    ("今にも雨が降りそうだ。", "降りそうだ", &[Inflection::Sou]),
+    (
+        "彼は今日来ないらしい。",
+        "来ないらしい",
+        &[Inflection::Negative, Inflection::Rashii],
+    ),
+    (
+        "ドアを開けようとした。",
+        "開けようとした",
+        &[Inflection::YouToSuru, Inflection::Past],
+    ),
+    (
+        "昨日はケーキを食べすぎた。",
+        "食べすぎた",
+        &[Inflection::Sugiru, Inflection::Past],
+    ),
+    ("一緒に行こう。", "行こう", &[Inflection::Volitional]),
+    ("窓が開けてある。", "開けてある", &[Inflection::TeAru]),
+    ("本を読んでいる。", "読んでいる", &[Inflection::TeIru]),
+];
+
+/// Returns the unidic parser or `None` if the test should be skipped since unidic isn't available
+fn parser() -> Option<&'static Parser> {
+    let parser = PARSER.as_ref();
+
+    if parser.is_none() {
+        if env::var_os(DICT_PATH_VAR).is_some() {
+            panic!("unidic not found at the path set in {DICT_PATH_VAR}");
+        }
+        eprintln!("unidic not found at {DICT_PATH}, set {DICT_PATH_VAR} to run this test");
+    }
+
+    parser
+}
+
+fn parse(parser: &Parser, text: &str) -> Vec<Part> {
+    SentenceAnalyzer::new(get_grammar_analyzer(), parser.parse(text)).analyze()
+}
+
+#[test]
+fn test_builtin_rules() {
+    for src in [
+        include_str!("../grammar/sentence.rules"),
+        include_str!("../grammar/inflection.rules"),
+    ] {
+        if let Err(err) = RuleSet::parse(src) {
+            panic!("{err}");
+        }
+    }
+}
+
+#[test]
+fn test_aux_to_rule() {
+    let map =
+        |surface, lexeme, is_particle| map_aux_to_rule(surface, aux_lexeme(lexeme), is_particle);
+
+    // ようとする
+    assert_eq!(map("と", "と", true), Some("と"));
+    assert_eq!(map("と", "と", false), None);
+    assert_eq!(map("し", "為る", false), Some("する"));
+    assert_eq!(map("する", "する", false), Some("する"));
+    // The さ of される
+    assert_eq!(map("さ", "為る", false), None);
+
+    // そうだ
+    assert_eq!(map("だ", "だ", false), Some("だ"));
+
+    assert_eq!(map("とい", "置く", false), Some("とく"));
+    assert_eq!(map("おい", "置く", false), Some("おく"));
+    assert_eq!(map("なら", "成る", false), Some("なる"));
+    assert_eq!(map("本", "本", false), None);
+}
+
+#[test]
+fn test_aux_chains() {
+    let analyzer = get_grammar_analyzer();
+    assert!(analyzer.check_full(&["V", "よう", "と", "する", "た"]));
+    assert!(analyzer.check_full(&["V", "そう", "だ"]));
+    assert!(analyzer.check_full(&["V", "ない", "ば", "なる", "ない"]));
+    assert!(analyzer.check_full(&["V", "て", "もらう", "ます", "た"]));
+    assert!(!analyzer.check_full(&["V", "と", "する"]));
+}
+
+/// Needs the unidic dictionary at `DICT_PATH` or the path set in `JOTOBA_UNIDIC`
+#[test]
+fn test_corpus() {
+    let parser = match parser() {
+        Some(parser) => parser,
+        None => return,
+    };
+
+    for (sentence, word, inflections) in CORPUS {
+        let parts = parse(parser, sentence);
+
+        let text: String = parts.iter().map(|i| i.get_inflected()).collect();
+        assert_eq!(text, *sentence);
+
+        let part = parts
+            .iter()
+            .find(|i| i.get_inflected() == *word)
+            .unwrap_or_else(|| panic!("{word} not found as single part in {sentence}"));
+        assert_eq!(part.inflections(), *inflections, "{sentence}");
+    }
+}
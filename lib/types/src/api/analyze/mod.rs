@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Request struct for the analyze endpoint
+#[derive(Deserialize)]
+pub struct Request {
+    /// Japanese text to analyze. Can contain multiple sentences
+    pub text: String,
+}
+
+/// Response struct for the analyze endpoint
+#[derive(Serialize, Deserialize, Default)]
+pub struct Response {
+    pub sentences: Vec<Sentence>,
+}
+
+/// A single analyzed sentence of the input text
+#[derive(Serialize, Deserialize, Default)]
+pub struct Sentence {
+    /// The sentence as it appears in the input text
+    pub text: String,
+    pub parts: Vec<Part>,
+}
+
+/// A single word of a sentence including all of its inflections
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Part {
+    /// The word as written in the sentence
+    pub surface: String,
+    /// Dictionary form of the word
+    pub lemma: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_class: Option<String>,
+    /// Detected inflections in the order they're applied
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inflections: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub furigana: Option<String>,
+    /// Sequence id of the best matching dictionary entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u32>,
+}
@@ -1,9 +1,48 @@
 use serde::{Deserialize, Serialize};
 
+use super::analyze::Part;
+
 /// Scan endpoint response
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     pub text: String,
+    /// All recognized lines in reading order
+    pub lines: Vec<Line>,
+}
+
+/// A single line of recognized text
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Line {
+    pub text: String,
+    /// Mean confidence of all words in the line (0-100). Only set if the OCR backend provides it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// Only set if the OCR backend provides it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<Word>,
+    /// Parts of the line, analyzed by the sentence reader. Only set if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parts: Option<Vec<Part>>,
+}
+
+/// A single recognized word
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Word {
+    pub text: String,
+    /// Confidence of the recognition (0-100)
+    pub confidence: f32,
+    pub bbox: BoundingBox,
+}
+
+/// Bounding box in pixels, relative to the top left corner of the image
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BoundingBox {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
 }
 
 /// Scan endpoint request
@@ -13,6 +52,14 @@ pub struct Request {
     /// as fail
     #[serde(default = "default_conf_threshold")]
     pub threshold: i32,
+
+    /// Scan for vertical text instead of horizontal text
+    #[serde(default)]
+    pub vertical: bool,
+
+    /// Analyze each line with the sentence reader
+    #[serde(default)]
+    pub analyze: bool,
 }
 
 /// Default mit threshold value for detection confidence
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::search::PageInfo;
+
+/// Request struct for kanji_by_component endpoint
+#[derive(Deserialize)]
+pub struct Request {
+    pub component: char,
+
+    /// Page to return. Starts at 1
+    #[serde(default)]
+    pub page: Option<u32>,
+
+    /// Amount of kanji per page. Gets limited by the server
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+/// Response struct for kanji_by_component endpoint
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub kanji: Vec<char>,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+}
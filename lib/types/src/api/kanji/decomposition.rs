@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::jotoba::kanji::Decomposition;
+
+/// Response struct for kanji decomposition endpoint. Each node contains the components it is
+/// built of
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub literal: char,
+    pub components: Vec<Response>,
+}
+
+impl From<&Decomposition> for Response {
+    fn from(decomposition: &Decomposition) -> Self {
+        Self {
+            literal: decomposition.literal,
+            components: decomposition.components.iter().map(|i| i.into()).collect(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Request struct for kanji_by_handwriting endpoint
+#[derive(Deserialize)]
+pub struct Request {
+    /// Drawn strokes in stroke order. Each stroke is a list of [x, y] points
+    pub strokes: Vec<Vec<[f32; 2]>>,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+/// Response struct for kanji_by_handwriting endpoint
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub candidates: Vec<Candidate>,
+}
+
+/// A single recognized kanji
+#[derive(Serialize, Deserialize)]
+pub struct Candidate {
+    pub literal: char,
+    pub stroke_count: u8,
+    /// Similarity to the drawn kanji (0-1)
+    pub score: f32,
+}
+
+/// Default amount of candidates to return
+#[inline]
+fn default_limit() -> usize {
+    10
+}
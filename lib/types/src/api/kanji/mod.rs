@@ -0,0 +1,4 @@
+pub mod by_component;
+pub mod decomposition;
+pub mod handwriting;
+pub mod strokes;
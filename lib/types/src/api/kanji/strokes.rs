@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Response struct for the kanji strokes endpoint
+#[derive(Serialize, Deserialize)]
+pub struct Response {
+    pub literal: char,
+    /// SVG path data of each stroke in stroke order. Paths are based on a 109x109 viewbox
+    pub strokes: Vec<String>,
+}
@@ -1,5 +1,8 @@
+pub mod analyze;
 pub mod completions;
 pub mod image;
+pub mod kanji;
 pub mod news;
+pub mod pitch;
 pub mod radical;
 pub mod search;
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Query parameters of the pitch endpoint for arbitrary kana
+#[derive(Deserialize)]
+pub struct Request {
+    pub kana: String,
+    /// Position of the mora after which the pitch drops. 0 represents a heiban pattern
+    pub drop: u8,
+}
+
+/// Query parameters of the pitch endpoint for dictionary words
+#[derive(Deserialize)]
+pub struct WordRequest {
+    /// Render all accent variants of the word instead of the first one only
+    #[serde(default)]
+    pub all: bool,
+}
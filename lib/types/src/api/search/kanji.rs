@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use super::PageInfo;
+
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     pub kanji: Vec<Kanji>,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -3,7 +3,7 @@ pub mod name;
 pub mod sentence;
 pub mod word;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::jotoba::languages::Language;
 
@@ -18,4 +18,40 @@ pub struct SearchRequest {
 
     #[serde(default)]
     pub no_english: bool,
+
+    /// Page to return. Starts at 1
+    #[serde(default)]
+    pub page: Option<u32>,
+
+    /// Amount of items per page. Gets limited by the server
+    #[serde(default)]
+    pub page_size: Option<u32>,
+}
+
+/// Pagination information of a search API response
+#[derive(Serialize, Deserialize, Default, Clone, Copy)]
+pub struct PageInfo {
+    /// Total amount of results
+    pub total: u32,
+    /// Whether there are results on the next page
+    pub has_more: bool,
+}
+
+impl PageInfo {
+    /// Creates a new `PageInfo` for `page` (starting at 1) of a result with `total` items
+    #[inline]
+    pub fn new(total: u32, page: u32, page_size: u32) -> Self {
+        let has_more = page.max(1).saturating_mul(page_size) < total;
+        Self { total, has_more }
+    }
+}
+
+/// Query parameters of the direct lookup endpoints
+#[derive(Deserialize, Default)]
+pub struct LookupRequest {
+    #[serde(default)]
+    pub language: Language,
+
+    #[serde(default)]
+    pub no_english: bool,
 }
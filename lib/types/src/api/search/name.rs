@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use super::PageInfo;
 use crate::jotoba::names::name_type::NameType;
 
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     names: Vec<Name>,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -13,6 +16,9 @@ pub struct Name {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kanji: Option<String>,
     pub transcription: String,
+    /// Hepburn romanization of the kana reading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub romaji: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name_type: Option<Vec<NameType>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,6 +32,10 @@ impl From<&crate::jotoba::names::Name> for Name {
             kana: name.kana.clone(),
             kanji: name.kanji.clone(),
             transcription: name.transcription.clone(),
+            #[cfg(feature = "jotoba_intern")]
+            romaji: Some(japanese::romaji::to_romaji(&name.kana)),
+            #[cfg(not(feature = "jotoba_intern"))]
+            romaji: None,
             name_type: name.name_type.clone(),
             xref: name.xref.clone(),
         }
@@ -36,6 +46,9 @@ impl From<Vec<&crate::jotoba::names::Name>> for Response {
     #[inline]
     fn from(name: Vec<&crate::jotoba::names::Name>) -> Self {
         let names: Vec<Name> = name.into_iter().map(Name::from).collect();
-        Self { names }
+        Self {
+            names,
+            page_info: PageInfo::default(),
+        }
     }
 }
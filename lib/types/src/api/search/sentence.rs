@@ -1,16 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+use super::PageInfo;
 use crate::jotoba::languages::Language;
 
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     sentences: Vec<Sentence>,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct Sentence {
     pub content: String,
     pub furigana: String,
+    /// Hepburn romanization of the sentence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub romaji: Option<String>,
     pub translation: String,
     pub language: Language,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,6 +26,9 @@ pub struct Sentence {
 impl From<Vec<Sentence>> for Response {
     #[inline]
     fn from(sentences: Vec<Sentence>) -> Self {
-        Self { sentences }
+        Self {
+            sentences,
+            page_info: PageInfo::default(),
+        }
     }
 }
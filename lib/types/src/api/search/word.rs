@@ -1,8 +1,11 @@
 use crate::{
-    api::search::kanji::Kanji,
+    api::search::{kanji::Kanji, PageInfo},
     jotoba::{
         languages::Language,
-        words::{dialect::Dialect, field::Field, misc::Misc, part_of_speech::PartOfSpeech},
+        words::{
+            dialect::Dialect, field::Field, inflection::Inflections, misc::Misc,
+            part_of_speech::PartOfSpeech,
+        },
     },
 };
 
@@ -13,6 +16,11 @@ use serde::{Deserialize, Serialize};
 pub struct Response {
     kanji: Vec<Kanji>,
     words: Vec<Word>,
+    /// Similar terms to the query if it found nothing or only a few results
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub corrections: Vec<String>,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
 }
 
 /// Represents a single Word result with 1 (main) Japanese reading and n glosses
@@ -27,6 +35,9 @@ pub struct Word {
     audio: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pitch: Option<Vec<PitchItem>>,
+    /// Conjugation table of verbs and adjectives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inflections: Option<Inflections>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -42,6 +53,9 @@ pub struct Reading {
     kanji: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     furigana: Option<String>,
+    /// Hepburn romanization of the kana reading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    romaji: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -94,6 +108,7 @@ impl From<&crate::jotoba::words::Word> for Word {
         let kanji = word.reading.kanji.as_ref().map(|i| i.reading.clone());
         let kana = word.reading.kana.clone().reading;
         let furigana = word.furigana.clone();
+        let romaji = Some(japanese::romaji::to_romaji(&kana));
 
         let senses = word.senses.iter().map(|i| Sense::from(i)).collect();
 
@@ -108,6 +123,7 @@ impl From<&crate::jotoba::words::Word> for Word {
                 kanji,
                 kana,
                 furigana,
+                romaji,
             },
             senses,
             alt_readings: None,
@@ -116,6 +132,7 @@ impl From<&crate::jotoba::words::Word> for Word {
                 .as_ref()
                 .map(|i| format!("/audio/{}", i)),
             pitch,
+            inflections: word.get_inflections(),
         }
     }
 }
@@ -137,7 +154,12 @@ impl
         let kanji = convert_kanji(wres.1);
         let words = convert_words(wres.0);
 
-        Self { kanji, words }
+        Self {
+            kanji,
+            words,
+            corrections: vec![],
+            page_info: PageInfo::default(),
+        }
     }
 }
 
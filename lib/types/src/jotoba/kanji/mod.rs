@@ -38,6 +38,13 @@ pub struct DetailedRadical {
     pub translations: Option<Vec<String>>,
 }
 
+/// A node of a kanji decomposition tree holding the components `literal` is built of
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Decomposition {
+    pub literal: char,
+    pub components: Vec<Decomposition>,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SearchRadicalInfo {
     pub literal: char,
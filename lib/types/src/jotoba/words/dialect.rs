@@ -4,9 +4,9 @@ use std::fmt::Display;
 use localization::{language::Language, traits::Translatable, TranslationDict};
 
 use serde::{Deserialize, Serialize};
-use strum_macros::EnumString;
+use strum_macros::{EnumIter, EnumString};
 
-#[derive(Debug, PartialEq, Clone, Copy, EnumString, Serialize, Deserialize, Hash)]
+#[derive(Debug, PartialEq, Clone, Copy, EnumIter, EnumString, Serialize, Deserialize, Hash)]
 #[repr(u8)]
 pub enum Dialect {
     #[strum(serialize = "bra")]
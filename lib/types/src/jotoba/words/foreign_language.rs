@@ -2,9 +2,11 @@
 use localization::traits::Translatable;
 
 use serde::{Deserialize, Serialize};
-use strum_macros::{AsRefStr, EnumString};
+use strum_macros::{AsRefStr, EnumIter, EnumString};
 
-#[derive(Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, Serialize, Deserialize, Hash)]
+#[derive(
+    Debug, PartialEq, Clone, Copy, AsRefStr, EnumIter, EnumString, Serialize, Deserialize, Hash,
+)]
 #[repr(u8)]
 pub enum ForeignLanguage {
     #[strum(serialize = "eng")]
@@ -1,11 +1,21 @@
-use super::part_of_speech::{self, IrregularVerb, PartOfSpeech};
+use super::part_of_speech::{self, AdjectiveType, IrregularVerb, PartOfSpeech};
 
 use super::Word;
 
 use jp_inflections::{Verb, VerbType, WordForm};
+use serde::{Deserialize, Serialize};
+
+/// Conjugation table of a word
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Inflections {
+    Verb(VerbInflections),
+    Adjective(AdjectiveInflections),
+}
 
 /// A set of different inflections which will be displayed for vebs
-pub struct Inflections {
+#[derive(Serialize, Deserialize)]
+pub struct VerbInflections {
     pub present: InflectionPair,
     pub present_polite: InflectionPair,
 
@@ -20,18 +30,112 @@ pub struct Inflections {
 
     pub causative_passive: InflectionPair,
     pub imperative: InflectionPair,
+
+    /// ~ば conditional
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditional_ba: Option<InflectionPair>,
+    /// ~たら conditional
+    pub conditional_tara: InflectionPair,
+    pub conditional_tara_polite: InflectionPair,
+
+    /// ~よう/~おう. The negative form is built using ~まい
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volitional: Option<InflectionPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volitional_polite: Option<InflectionPair>,
+
+    /// ~たい desiderative
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tai: Option<InflectionPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tai_polite: Option<InflectionPair>,
+
+    /// ~ながら (while doing)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nagara: Option<String>,
+    /// ~ず (without doing)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zu: Option<String>,
+}
+
+/// A set of different inflections which will be displayed for i- and na-adjectives
+#[derive(Serialize, Deserialize)]
+pub struct AdjectiveInflections {
+    pub present: InflectionPair,
+    pub present_polite: InflectionPair,
+
+    pub past: InflectionPair,
+    pub past_polite: InflectionPair,
+
+    pub te_form: InflectionPair,
+    pub adverbial: InflectionPair,
+
+    /// ~ければ for i-adjectives and ~なら for na-adjectives
+    pub conditional: InflectionPair,
+    /// ~そう (seems to be)
+    pub sou: InflectionPair,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct InflectionPair {
     pub positive: String,
     pub negative: String,
 }
 
-/// Returns the inflections of `word` if its a verb
+impl InflectionPair {
+    #[inline]
+    fn new(positive: String, negative: String) -> Self {
+        Self { positive, negative }
+    }
+}
+
+/// Returns the inflections of `word` if its a verb or an adjective
 pub(super) fn of_word(word: &Word) -> Option<Inflections> {
+    if let Some(verb) = of_verb(word) {
+        return Some(Inflections::Verb(verb));
+    }
+
+    of_adjective(word).map(Inflections::Adjective)
+}
+
+/// Returns the inflections of `word` if its a verb
+fn of_verb(word: &Word) -> Option<VerbInflections> {
     let verb = get_jp_verb(word)?;
-    let build = || -> Result<Inflections, jp_inflections::error::Error> {
-        Ok(Inflections {
+    let is_godan = !word.get_pos().any(|i| i.is_ichidan()) && word.get_pos().any(|i| i.is_godan());
+    let is_kuru = is_kuru(word);
+
+    // Forms which jp_inflections doesn't provide get derived from the following ones. Each of
+    // them is optional so a form that can't be built doesn't hide the whole table
+    let dict = verb
+        .dictionary(WordForm::Short)
+        .ok()
+        .map(|i| i.get_reading());
+    let negative = verb.negative(WordForm::Short).ok().map(|i| i.get_reading());
+    let masu = verb
+        .dictionary(WordForm::Long)
+        .ok()
+        .map(|i| i.get_reading());
+    let masu_stem = masu.as_deref().and_then(|i| i.strip_suffix("ます"));
+
+    let conditional_ba = dict
+        .as_deref()
+        .zip(negative.as_deref())
+        .and_then(|(dict, negative)| conditional_ba(dict, negative));
+
+    let volitional = dict
+        .as_deref()
+        .and_then(|dict| volitional(dict, masu_stem, is_godan, is_kuru));
+
+    let volitional_polite = masu_stem.zip(masu.as_deref()).map(|(masu_stem, masu)| {
+        InflectionPair::new(format!("{}ましょう", masu_stem), format!("{}まい", masu))
+    });
+
+    let zu = dict
+        .as_deref()
+        .and_then(|dict| zu(&word.reading.kana.reading, dict, negative.as_deref()));
+
+    let build = || -> Result<VerbInflections, jp_inflections::error::Error> {
+        Ok(VerbInflections {
             present: InflectionPair {
                 positive: verb.dictionary(WordForm::Short)?.get_reading(),
                 negative: verb.negative(WordForm::Short)?.get_reading(),
@@ -73,6 +177,31 @@ pub(super) fn of_word(word: &Word) -> Option<Inflections> {
                 positive: verb.imperative()?.get_reading(),
                 negative: verb.imperative_negative()?.get_reading(),
             },
+            conditional_ba,
+            conditional_tara: InflectionPair {
+                positive: format!("{}ら", verb.past(WordForm::Short)?.get_reading()),
+                negative: format!("{}ら", verb.negative_past(WordForm::Short)?.get_reading()),
+            },
+            conditional_tara_polite: InflectionPair {
+                positive: format!("{}ら", verb.past(WordForm::Long)?.get_reading()),
+                negative: format!("{}ら", verb.negative_past(WordForm::Long)?.get_reading()),
+            },
+            volitional,
+            volitional_polite,
+            tai: masu_stem.map(|masu_stem| {
+                InflectionPair::new(
+                    format!("{}たい", masu_stem),
+                    format!("{}たくない", masu_stem),
+                )
+            }),
+            tai_polite: masu_stem.map(|masu_stem| {
+                InflectionPair::new(
+                    format!("{}たいです", masu_stem),
+                    format!("{}たくないです", masu_stem),
+                )
+            }),
+            nagara: masu_stem.map(|masu_stem| format!("{}ながら", masu_stem)),
+            zu,
         })
     }()
     .ok()?;
@@ -80,6 +209,50 @@ pub(super) fn of_word(word: &Word) -> Option<Inflections> {
     Some(build)
 }
 
+/// Builds the ~ば conditional out of the dictionary form `dict` and the short negative form
+fn conditional_ba(dict: &str, negative: &str) -> Option<InflectionPair> {
+    Some(InflectionPair::new(
+        format!("{}ば", shift_u_row(dict, Row::E)?),
+        format!("{}ければ", negative.strip_suffix('い')?),
+    ))
+}
+
+/// Builds the volitional form out of the dictionary form `dict` and the masu stem. The stem of
+/// くる changes to こ (こよう) which isn't the masu stem き
+fn volitional(
+    dict: &str,
+    masu_stem: Option<&str>,
+    is_godan: bool,
+    is_kuru: bool,
+) -> Option<InflectionPair> {
+    let positive = if is_kuru {
+        match dict.strip_suffix("くる") {
+            Some(prefix) => format!("{}こよう", prefix),
+            None => format!("{}よう", dict.strip_suffix('る')?),
+        }
+    } else if is_godan {
+        format!("{}う", shift_u_row(dict, Row::O)?)
+    } else {
+        format!("{}よう", masu_stem?)
+    };
+
+    Some(InflectionPair::new(positive, format!("{}まい", dict)))
+}
+
+/// Builds the ~ず form out of the dictionary form `dict` and the short negative form. する becomes
+/// せず and ある, whose negative form is just ない, becomes あらず
+fn zu(kana: &str, dict: &str, negative: Option<&str>) -> Option<String> {
+    if let Some(prefix) = dict.strip_suffix("する") {
+        return Some(format!("{}せず", prefix));
+    }
+
+    if kana == "ある" {
+        return Some(format!("{}らず", dict.strip_suffix('る')?));
+    }
+
+    Some(format!("{}ず", negative?.strip_suffix("ない")?))
+}
+
 /// Returns a jp_inflections::Verb if [`self`] is a verb
 fn get_jp_verb(word: &Word) -> Option<Verb> {
     let is_suru = word.get_pos().any(|i| match i {
@@ -97,7 +270,7 @@ fn get_jp_verb(word: &Word) -> Option<Verb> {
         VerbType::Ichidan
     } else if word.get_pos().any(|i| i.is_godan()) {
         VerbType::Godan
-    } else if is_suru {
+    } else if is_suru || is_kuru(word) {
         VerbType::Exception
     } else {
         return None;
@@ -114,3 +287,208 @@ fn get_jp_verb(word: &Word) -> Option<Verb> {
     // Check if [`verb`] really is a valid verb in dictionary form
     verb.word.is_verb().then(|| verb)
 }
+
+/// Returns `true` if `word` is くる or a compound of it
+#[inline]
+fn is_kuru(word: &Word) -> bool {
+    word.get_pos()
+        .any(|i| matches!(i, PartOfSpeech::Verb(part_of_speech::VerbType::Kuru)))
+}
+
+/// Rows of the kana table a verb ending can be shifted to
+#[derive(Clone, Copy)]
+enum Row {
+    E,
+    O,
+}
+
+/// Verb endings of the u-row with their e- and o-row counterparts
+const U_ROW: &[(char, char, char)] = &[
+    ('う', 'え', 'お'),
+    ('く', 'け', 'こ'),
+    ('ぐ', 'げ', 'ご'),
+    ('す', 'せ', 'そ'),
+    ('つ', 'て', 'と'),
+    ('ぬ', 'ね', 'の'),
+    ('ぶ', 'べ', 'ぼ'),
+    ('む', 'め', 'も'),
+    ('る', 'れ', 'ろ'),
+];
+
+/// Replaces the last kana of `dict` with its counterpart in `row`, eg. 行く => 行け for `Row::E`.
+/// Returns `None` if `dict` doesn't end with a kana of the u-row
+fn shift_u_row(dict: &str, row: Row) -> Option<String> {
+    let last = dict.chars().last()?;
+    let (_, e, o) = U_ROW.iter().find(|i| i.0 == last)?;
+
+    let mut out = dict[..dict.len() - last.len_utf8()].to_string();
+    out.push(match row {
+        Row::E => *e,
+        Row::O => *o,
+    });
+    Some(out)
+}
+
+/// Returns the inflections of `word` if its an i- or na-adjective
+fn of_adjective(word: &Word) -> Option<AdjectiveInflections> {
+    let adj_type = word
+        .get_pos()
+        .filter_map(|i| match i {
+            PartOfSpeech::Adjective(t) => Some(*t),
+            _ => None,
+        })
+        .find(|i| {
+            matches!(
+                i,
+                AdjectiveType::Keiyoushi | AdjectiveType::KeiyoushiYoiIi | AdjectiveType::Na
+            )
+        })?;
+
+    let reading = &word.get_reading().reading;
+
+    match adj_type {
+        AdjectiveType::Na => na_adjective(reading),
+        AdjectiveType::KeiyoushiYoiIi => {
+            // いい conjugates based on よい
+            let stem = match reading.strip_suffix("いい") {
+                Some(prefix) => format!("{}よ", prefix),
+                None => reading.strip_suffix('い')?.to_string(),
+            };
+            Some(i_adjective(reading, &stem, true))
+        }
+        _ => {
+            let stem = reading.strip_suffix('い')?;
+            let irregular_sou = reading == "ない" || reading == "無い";
+            Some(i_adjective(reading, stem, irregular_sou))
+        }
+    }
+}
+
+/// Builds the inflections of an i-adjective with the dictionary form `dict` and the stem `stem`.
+/// If `irregular_sou` is `true`, ~そう is built with an additional さ like in よさそう
+fn i_adjective(dict: &str, stem: &str, irregular_sou: bool) -> AdjectiveInflections {
+    let form = |suffix: &str| format!("{}{}", stem, suffix);
+
+    let sou = if irregular_sou {
+        form("さそう")
+    } else {
+        form("そう")
+    };
+
+    AdjectiveInflections {
+        present: InflectionPair::new(dict.to_string(), form("くない")),
+        present_polite: InflectionPair::new(format!("{}です", dict), form("くないです")),
+        past: InflectionPair::new(form("かった"), form("くなかった")),
+        past_polite: InflectionPair::new(form("かったです"), form("くなかったです")),
+        te_form: InflectionPair::new(form("くて"), form("くなくて")),
+        adverbial: InflectionPair::new(form("く"), form("くなく")),
+        conditional: InflectionPair::new(form("ければ"), form("くなければ")),
+        sou: InflectionPair::new(sou, form("くなさそう")),
+    }
+}
+
+/// Builds the inflections of the na-adjective `dict`
+fn na_adjective(dict: &str) -> Option<AdjectiveInflections> {
+    if dict.is_empty() {
+        return None;
+    }
+
+    let form = |suffix: &str| format!("{}{}", dict, suffix);
+
+    Some(AdjectiveInflections {
+        present: InflectionPair::new(form("だ"), form("じゃない")),
+        present_polite: InflectionPair::new(form("です"), form("じゃありません")),
+        past: InflectionPair::new(form("だった"), form("じゃなかった")),
+        past_polite: InflectionPair::new(form("でした"), form("じゃありませんでした")),
+        te_form: InflectionPair::new(form("で"), form("じゃなくて")),
+        adverbial: InflectionPair::new(form("に"), form("じゃなく")),
+        conditional: InflectionPair::new(form("なら"), form("じゃなければ")),
+        sou: InflectionPair::new(form("そう"), form("じゃなさそう")),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shift_u_row() {
+        assert_eq!(shift_u_row("行く", Row::E).as_deref(), Some("行け"));
+        assert_eq!(shift_u_row("食べる", Row::E).as_deref(), Some("食べれ"));
+        assert_eq!(shift_u_row("話す", Row::O).as_deref(), Some("話そ"));
+        assert_eq!(shift_u_row("高い", Row::O), None);
+    }
+
+    #[test]
+    fn test_volitional() {
+        let positive = |dict, masu_stem, is_godan, is_kuru| {
+            volitional(dict, Some(masu_stem), is_godan, is_kuru).map(|i| i.positive)
+        };
+
+        assert_eq!(
+            positive("くる", "き", false, true).as_deref(),
+            Some("こよう")
+        );
+        assert_eq!(
+            positive("来る", "来", false, true).as_deref(),
+            Some("来よう")
+        );
+        assert_eq!(
+            positive("やってくる", "やってき", false, true).as_deref(),
+            Some("やってこよう")
+        );
+        assert_eq!(
+            positive("食べる", "食べ", false, false).as_deref(),
+            Some("食べよう")
+        );
+        assert_eq!(
+            positive("行く", "行き", true, false).as_deref(),
+            Some("行こう")
+        );
+        assert_eq!(
+            volitional("くる", None, false, true).unwrap().negative,
+            "くるまい"
+        );
+    }
+
+    #[test]
+    fn test_zu() {
+        assert_eq!(
+            zu("たべる", "食べる", Some("食べない")).as_deref(),
+            Some("食べず")
+        );
+        assert_eq!(
+            zu("べんきょうする", "勉強する", None).as_deref(),
+            Some("勉強せず")
+        );
+        assert_eq!(zu("ある", "有る", Some("ない")).as_deref(), Some("有らず"));
+        assert_eq!(zu("ある", "ある", None).as_deref(), Some("あらず"));
+        assert_eq!(zu("いく", "行く", None), None);
+    }
+
+    #[test]
+    fn test_i_adjective() {
+        let infl = i_adjective("高い", "高", false);
+        assert_eq!(infl.present.negative, "高くない");
+        assert_eq!(infl.past.positive, "高かった");
+        assert_eq!(infl.conditional.negative, "高くなければ");
+        assert_eq!(infl.sou.positive, "高そう");
+    }
+
+    #[test]
+    fn test_ii_adjective() {
+        let infl = i_adjective("かっこいい", "かっこよ", true);
+        assert_eq!(infl.present.positive, "かっこいい");
+        assert_eq!(infl.present.negative, "かっこよくない");
+        assert_eq!(infl.te_form.positive, "かっこよくて");
+        assert_eq!(infl.sou.positive, "かっこよさそう");
+    }
+
+    #[test]
+    fn test_na_adjective() {
+        let infl = na_adjective("静か").unwrap();
+        assert_eq!(infl.present.positive, "静かだ");
+        assert_eq!(infl.adverbial.positive, "静かに");
+        assert_eq!(infl.past_polite.negative, "静かじゃありませんでした");
+    }
+}
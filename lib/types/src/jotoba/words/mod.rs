@@ -23,6 +23,9 @@ use japanese::{
 use serde::{Deserialize, Serialize};
 
 use self::{
+    dialect::Dialect,
+    field::Field,
+    foreign_language::ForeignLanguage,
     inflection::Inflections,
     misc::Misc,
     part_of_speech::{PartOfSpeech, PosSimple},
@@ -137,7 +140,7 @@ impl Word {
             .count() as u8
     }
 
-    /// Returns an [`Inflections`] value if [`self`] is a valid verb
+    /// Returns an [`Inflections`] value if [`self`] is a valid verb or adjective
     #[inline]
     pub fn get_inflections(&self) -> Option<Inflections> {
         inflection::of_word(self)
@@ -156,6 +159,27 @@ impl Word {
         self.senses.iter().filter_map(|i| i.misc).any(|i| i == misc)
     }
 
+    /// Returns `true` if word has a sense used in the given `field`
+    #[inline]
+    pub fn has_field(&self, field: Field) -> bool {
+        self.senses.iter().filter_map(|i| i.field).any(|i| i == field)
+    }
+
+    /// Returns `true` if word has a sense used in the given `dialect`
+    #[inline]
+    pub fn has_dialect(&self, dialect: Dialect) -> bool {
+        self.senses.iter().filter_map(|i| i.dialect).any(|i| i == dialect)
+    }
+
+    /// Returns `true` if word is a loanword (gairaigo) from `language`
+    #[inline]
+    pub fn is_loan_from(&self, language: ForeignLanguage) -> bool {
+        self.senses
+            .iter()
+            .filter_map(|i| i.gairaigo.as_ref())
+            .any(|i| i.language == language)
+    }
+
     /// Returns `true` if word has at least one of the provided part of speech
     #[inline]
     pub fn has_pos(&self, pos_filter: &[PosSimple]) -> bool {
@@ -380,6 +404,28 @@ pub fn filter_languages<'a, I: 'a + Iterator<Item = &'a mut Word>>(
     }
 }
 
+/// Parses a search tag value which is either the JMdict abbreviation (eg. "med") or the name (eg.
+/// "medicine") of a `T`. Case and non alphanumeric characters of names are ignored
+#[cfg(feature = "jotoba_intern")]
+pub fn parse_tag_value<T>(value: &str) -> Option<T>
+where
+    T: std::str::FromStr + strum::IntoEnumIterator + localization::traits::Translatable,
+{
+    if let Ok(parsed) = T::from_str(value) {
+        return Some(parsed);
+    }
+
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(|c| c.to_lowercase())
+            .collect::<String>()
+    };
+
+    let value = normalize(value);
+    T::iter().find(|i| normalize(i.get_id()) == value)
+}
+
 /// Returns `None` if the vec is empty or Some(Vec<T>) if not
 #[inline]
 fn to_option<T>(vec: Vec<T>) -> Option<Vec<T>> {
@@ -1,5 +1,6 @@
 pub mod binary_search;
 pub mod korean;
+pub mod swap_cell;
 
 use itertools::Itertools;
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
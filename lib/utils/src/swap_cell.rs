@@ -0,0 +1,83 @@
+use std::sync::{Arc, RwLock};
+
+/// A thread-safe cell holding a value which can be replaced at runtime. Readers get an `Arc` to
+/// the current value, so replacing it never affects readers which are still using the previous
+/// value. A replaced value gets dropped as soon as its last reader is done.
+pub struct SwapCell<T> {
+    current: RwLock<Option<Arc<T>>>,
+}
+
+impl<T> SwapCell<T> {
+    /// Creates a new empty cell
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            current: RwLock::new(None),
+        }
+    }
+
+    /// Replaces the current value of the cell with `value`
+    #[inline]
+    pub fn set(&self, value: T) {
+        let value = Arc::new(value);
+        // Swap while holding the lock but drop the previous value afterwards so readers don't
+        // have to wait for it
+        let _previous = self.write().replace(value);
+    }
+
+    /// Returns `true` if the cell holds a value
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        self.read().is_some()
+    }
+
+    /// Returns the current value of the cell or `None` if it wasn't set yet
+    #[inline]
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.read().clone()
+    }
+
+    #[inline]
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Option<Arc<T>>> {
+        // The lock only guards a pointer swap which can't panic, so poisoning can be ignored
+        self.current.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    #[inline]
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Option<Arc<T>>> {
+        self.current.write().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<T> Default for SwapCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_swap() {
+        let cell: SwapCell<String> = SwapCell::new();
+        assert!(cell.get().is_none());
+        assert!(!cell.is_set());
+
+        cell.set(String::from("first"));
+        let first = cell.get().unwrap();
+        assert_eq!(first.as_str(), "first");
+
+        // Readers of the previous value aren't affected by replacing it
+        cell.set(String::from("second"));
+        assert_eq!(first.as_str(), "first");
+        assert_eq!(cell.get().unwrap().as_str(), "second");
+
+        // The previous value gets dropped once its last reader is done
+        let weak = Arc::downgrade(&first);
+        drop(first);
+        assert!(weak.upgrade().is_none());
+    }
+}